@@ -0,0 +1,52 @@
+//! Compares hashing large byte points against hashing their items one at a time
+//!
+//! Run with `cargo run --release --example hash_bytes`
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::hint::black_box;
+use std::time::Instant;
+
+use point_nd::PointND;
+
+const ROUNDS: u32 = 10_000;
+
+fn main() {
+    let mut arr = [0u8; 4096];
+    for (i, item) in arr.iter_mut().enumerate() {
+        *item = (i % 251) as u8;
+    }
+    let p = PointND::from(arr);
+
+    // Hashes the length and then each item, as a derived impl would
+    let naive_hash = |p: &PointND<u8, 4096>| {
+        let mut state = DefaultHasher::new();
+        state.write_usize(p.len());
+        for item in p.iter() {
+            state.write_u8(*item);
+        }
+        state.finish()
+    };
+    let whole_hash = |p: &PointND<u8, 4096>| {
+        let mut state = DefaultHasher::new();
+        p.hash(&mut state);
+        state.finish()
+    };
+    assert_eq!(naive_hash(&p), whole_hash(&p));
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(naive_hash(black_box(&p)));
+    }
+    let naive_time = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(whole_hash(black_box(&p)));
+    }
+    let whole_time = start.elapsed();
+
+    println!("item by item: {:?}", naive_time);
+    println!("PointND hash: {:?}", whole_time);
+    println!("speedup:      {:.1}x", naive_time.as_secs_f64() / whole_time.as_secs_f64());
+}
@@ -0,0 +1,228 @@
+use core::ops::{Add, Deref, Sub};
+
+use crate::PointND;
+use crate::utils;
+
+/**
+A `PointND` representing a location, as opposed to a `Displacement` between locations.
+
+The arithmetic operators are only implemented where they make sense for locations, which
+prevents mistakes like adding two positions together:
+
+- `Position - Position = Displacement`
+- `Position + Displacement = Position`
+- `Position - Displacement = Position`
+
+As `Position` dereferences to a `PointND`, all of its read accessors are available.
+
+```
+# use point_nd::{Position, Displacement};
+let start = Position::from([1, 2]);
+let end = Position::from([4, 6]);
+
+let delta: Displacement<i32, 2> = end - start;
+assert_eq!(start + delta, end);
+```
+
+Positions cannot be added together:
+
+```compile_fail
+# use point_nd::Position;
+let a = Position::from([1, 2]);
+let b = Position::from([4, 6]);
+
+// ERROR: Cannot add Position to Position
+let c = a + b;
+```
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Position<T, const N: usize>(PointND<T, N>);
+
+/**
+A `PointND` representing a displacement (_a.k.a_ - offset) between two `Position`'s.
+
+The arithmetic operators are only implemented where they make sense for displacements:
+
+- `Displacement + Displacement = Displacement`
+- `Displacement - Displacement = Displacement`
+
+As `Displacement` dereferences to a `PointND`, all of its read accessors are available.
+
+```
+# use point_nd::Displacement;
+let a = Displacement::from([1, -1]);
+let b = Displacement::from([2, 3]);
+assert_eq!((a + b).into_arr(), [3, 2]);
+```
+
+Displacements cannot have positions added to them, only the other way around:
+
+```compile_fail
+# use point_nd::{Position, Displacement};
+let pos = Position::from([1, 2]);
+let delta = Displacement::from([4, 6]);
+
+// ERROR: Cannot add Position to Displacement
+let moved = delta + pos;
+```
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Displacement<T, const N: usize>(PointND<T, N>);
+
+// Adds or subtracts the respective items of two points
+fn zip_with<T, const N: usize>(a: PointND<T, N>, b: PointND<T, N>, f: fn(T, T) -> T, method_name: &str) -> PointND<T, N> {
+    PointND::from(utils::zip_with(a.into_arr(), b.into_arr(), f, method_name))
+}
+
+// Implements the methods and traits shared by both wrappers
+macro_rules! impl_wrapper {
+    ($($name:ident),*) => {$(
+
+        impl<T, const N: usize> $name<T, N> {
+
+            #[doc = concat!(" Returns a new `", stringify!($name), "` containing `point`")]
+            pub fn new(point: PointND<T, N>) -> Self {
+                $name(point)
+            }
+
+            /// Consumes `self`, returning the contained point
+            pub fn into_point(self) -> PointND<T, N> {
+                self.0
+            }
+
+            /// Consumes `self`, returning the array contained by the point
+            pub fn into_arr(self) -> [T; N] {
+                self.0.into_arr()
+            }
+
+        }
+
+        impl<T, const N: usize> Deref for $name<T, N> {
+
+            type Target = PointND<T, N>;
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+
+        }
+
+        impl<T, const N: usize> From<PointND<T, N>> for $name<T, N> {
+
+            fn from(point: PointND<T, N>) -> Self {
+                $name(point)
+            }
+
+        }
+
+        impl<T, const N: usize> From<[T; N]> for $name<T, N> {
+
+            fn from(array: [T; N]) -> Self {
+                $name(PointND::from(array))
+            }
+
+        }
+
+    )*};
+}
+
+impl_wrapper!(Position, Displacement);
+
+impl<T, const N: usize> Sub for Position<T, N>
+    where T: Sub<Output = T> {
+
+    type Output = Displacement<T, N>;
+    fn sub(self, other: Self) -> Self::Output {
+        Displacement(zip_with(self.0, other.0, T::sub, "sub"))
+    }
+
+}
+
+impl<T, const N: usize> Add<Displacement<T, N>> for Position<T, N>
+    where T: Add<Output = T> {
+
+    type Output = Position<T, N>;
+    fn add(self, other: Displacement<T, N>) -> Self::Output {
+        Position(zip_with(self.0, other.0, T::add, "add"))
+    }
+
+}
+
+impl<T, const N: usize> Sub<Displacement<T, N>> for Position<T, N>
+    where T: Sub<Output = T> {
+
+    type Output = Position<T, N>;
+    fn sub(self, other: Displacement<T, N>) -> Self::Output {
+        Position(zip_with(self.0, other.0, T::sub, "sub"))
+    }
+
+}
+
+impl<T, const N: usize> Add for Displacement<T, N>
+    where T: Add<Output = T> {
+
+    type Output = Displacement<T, N>;
+    fn add(self, other: Self) -> Self::Output {
+        Displacement(zip_with(self.0, other.0, T::add, "add"))
+    }
+
+}
+
+impl<T, const N: usize> Sub for Displacement<T, N>
+    where T: Sub<Output = T> {
+
+    type Output = Displacement<T, N>;
+    fn sub(self, other: Self) -> Self::Output {
+        Displacement(zip_with(self.0, other.0, T::sub, "sub"))
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_differ_by_displacements() {
+        let a = Position::from([1.0, 5.0, -2.0]);
+        let b = Position::from([4.0, 1.0, 0.5]);
+
+        let delta = b - a;
+        assert_eq!(delta, Displacement::from([3.0, -4.0, 2.5]));
+        assert_eq!(a + delta, b);
+        assert_eq!(b - delta, a);
+    }
+
+    #[test]
+    fn displacements_combine() {
+        let a = Displacement::from([1, 2]);
+        let b = Displacement::from([-3, 7]);
+
+        assert_eq!((a + b).into_arr(), [-2, 9]);
+        assert_eq!((a - b).into_arr(), [4, -5]);
+    }
+
+    #[test]
+    fn can_move_by_many_displacements() {
+        let steps = [[1, 0], [0, 1], [-1, 0], [0, -1]].map(Displacement::from);
+        let start = Position::from([3, 3]);
+
+        let end = steps.iter().copied().fold(start, |pos, step| pos + step);
+        assert_eq!(end, start);
+
+        let total = steps.into_iter().reduce(|a, b| a + b).unwrap();
+        assert_eq!(total.into_arr(), [0, 0]);
+    }
+
+    #[test]
+    fn can_access_point_methods() {
+        let p = Position::new(PointND::from([1, 2, 3]));
+        assert_eq!(p.dims(), 3);
+        assert_eq!(p[1], 2);
+        assert_eq!(p.into_point().into_arr(), [1, 2, 3]);
+
+        let d = Displacement::from([0.5, -0.5]);
+        assert_eq!(d.iter().sum::<f64>(), 0.0);
+    }
+
+}
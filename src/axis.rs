@@ -0,0 +1,228 @@
+use core::fmt;
+use core::ops::{Index, IndexMut};
+
+use crate::PointND;
+#[cfg(feature = "appliers")]
+use crate::utils::{ApplyDimsFn, PartialArray};
+
+///
+/// The first four axes of a `PointND`, for indexing points without bare numbers
+///
+/// Unlike numeric indexes, axes can be matched on exhaustively and stored in data structures
+/// (_e.g._ - a sort key configured at runtime)
+///
+/// ```
+/// # use point_nd::{PointND, Axis};
+/// let mut p = PointND::from([1, 2, 3]);
+/// p[Axis::Z] = 30;
+///
+/// assert_eq!(p[Axis::X], 1);
+/// assert_eq!(p.into_arr(), [1, 2, 30]);
+/// ```
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Axis {
+    /// The first dimension, at index 0
+    X,
+    /// The second dimension, at index 1
+    Y,
+    /// The third dimension, at index 2
+    Z,
+    /// The fourth dimension, at index 3
+    W,
+}
+
+impl Axis {
+
+    ///
+    /// Every axis, in the order of their dimensions
+    ///
+    /// ```
+    /// # use point_nd::{PointND, Axis};
+    /// let p = PointND::from([1, 2, 3, 4]);
+    ///
+    /// let mut sum = 0;
+    /// for axis in Axis::ALL {
+    ///     sum += p[axis];
+    /// }
+    /// assert_eq!(sum, 10);
+    /// ```
+    ///
+    pub const ALL: [Axis; 4] = [Axis::X, Axis::Y, Axis::Z, Axis::W];
+
+}
+
+impl From<Axis> for usize {
+
+    fn from(axis: Axis) -> Self {
+        axis as usize
+    }
+
+}
+
+impl fmt::Display for Axis {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Axis::X => "x",
+            Axis::Y => "y",
+            Axis::Z => "z",
+            Axis::W => "w",
+        };
+        f.write_str(label)
+    }
+
+}
+
+// Panics if the axis is beyond the dimensions of a point
+fn check_axis(axis: Axis, dims: usize) {
+    if usize::from(axis) >= dims {
+        panic!("Attempted to index axis {} (dimension {}) of a PointND with {} dimensions",
+               axis, usize::from(axis), dims);
+    }
+}
+
+///
+/// Indexes the item at the dimension of an `Axis`
+///
+/// # Panics
+///
+/// - If the axis is beyond the dimensions of the point (_e.g._ - `Axis::Z` on a 2D point)
+///
+impl<T, const N: usize> Index<Axis> for PointND<T, N> {
+
+    type Output = T;
+
+    fn index(&self, axis: Axis) -> &T {
+        check_axis(axis, N);
+        &self.arr()[usize::from(axis)]
+    }
+
+}
+
+///
+/// Mutably indexes the item at the dimension of an `Axis`
+///
+/// # Panics
+///
+/// - If the axis is beyond the dimensions of the point (_e.g._ - `Axis::Z` on a 2D point)
+///
+impl<T, const N: usize> IndexMut<Axis> for PointND<T, N> {
+
+    fn index_mut(&mut self, axis: Axis) -> &mut T {
+        check_axis(axis, N);
+        &mut self.arr_mut()[usize::from(axis)]
+    }
+
+}
+
+///
+/// Method for applying modifiers to the items of `PointND`'s at specific axes
+///
+/// # Enabled by features:
+///
+/// - `default`
+///
+/// - `appliers`
+///
+#[cfg(feature = "appliers")]
+impl<T, const N: usize> PointND<T, N> {
+
+    ///
+    /// Consumes `self` and calls the `modifier` on the items at the specified `axes` to
+    /// create a new `PointND` of the same length
+    ///
+    /// This is the same as `apply_dims()`, but is given axes rather than numeric dimensions
+    ///
+    /// ```
+    /// # use point_nd::{PointND, Axis};
+    /// let p = PointND
+    ///     ::from([0,1,2,3])
+    ///     .apply_axes(&[Axis::Y, Axis::W], |item| item * 10);
+    /// assert_eq!(p.into_arr(), [0, 10, 2, 30]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - If any of the `axes` are beyond the dimensions of the point
+    ///
+    pub fn apply_axes(self, axes: &[Axis], modifier: ApplyDimsFn<T>) -> Self {
+        for axis in axes {
+            check_axis(*axis, N);
+        }
+
+        let mut arr = PartialArray::<T, N>::new();
+        for (i, item) in self.into_arr().into_iter().enumerate() {
+            if axes.iter().any(|axis| usize::from(*axis) == i) {
+                arr.push(modifier(item));
+            } else {
+                arr.push(item);
+            }
+        }
+
+        PointND::from(arr.into_inner("apply_axes"))
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn axes_convert_to_dimensions() {
+        let dims: [usize; 4] = Axis::ALL.map(usize::from);
+        assert_eq!(dims, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn can_index_with_axes() {
+        let mut p = PointND::from([1.5, -2.0]);
+        assert_eq!((p[Axis::X], p[Axis::Y]), (1.5, -2.0));
+
+        p[Axis::Y] += 4.0;
+        assert_eq!(p.into_arr(), [1.5, 2.0]);
+
+        // Numeric indexing still works alongside axis indexing
+        assert_eq!(p[1], 2.0);
+    }
+
+    #[test]
+    fn all_axes_can_drive_loops() {
+        let mut p = PointND::from([1, 2, 3, 4]);
+        for axis in Axis::ALL.iter() {
+            p[*axis] *= 2;
+        }
+        assert_eq!(p.into_arr(), [2, 4, 6, 8]);
+    }
+
+    #[test]
+    #[should_panic(expected = "axis z (dimension 2) of a PointND with 2 dimensions")]
+    fn cannot_index_beyond_dimensions() {
+        let p = PointND::from([0, 1]);
+        let _z = p[Axis::Z];
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_mutably_index_beyond_dimensions() {
+        let mut p = PointND::<u8, 0>::from([]);
+        p[Axis::X] = 1;
+    }
+
+    #[test]
+    #[cfg(feature = "appliers")]
+    fn can_apply_axes() {
+        let p = PointND::from([1, 2, 3]).apply_axes(&[Axis::Z, Axis::X], |item| -item);
+        assert_eq!(p.into_arr(), [-1, 2, -3]);
+    }
+
+    #[test]
+    #[cfg(feature = "appliers")]
+    #[should_panic]
+    fn cannot_apply_axes_beyond_dimensions() {
+        PointND::from([1, 2, 3]).apply_axes(&[Axis::W], |item| -item);
+    }
+
+}
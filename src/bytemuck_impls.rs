@@ -0,0 +1,51 @@
+use bytemuck::{Pod, Zeroable};
+
+use crate::PointND;
+
+// SAFETY: PointND is repr(transparent) over [T; N], so has exactly the same layout as the
+//  array. An array of zeroable items is zeroable, so an all zero PointND is a valid value
+unsafe impl<T, const N: usize> Zeroable for PointND<T, N>
+    where T: Zeroable {}
+
+// SAFETY: PointND is repr(transparent) over [T; N], and arrays have no padding between or
+//  around their items. As every bit pattern of each Pod item is valid, every bit pattern of
+//  the whole point is valid too. PointND is Copy and 'static when T is Pod, and contains
+//  no pointers or interior mutability other than what T contains, which Pod rules out
+unsafe impl<T, const N: usize> Pod for PointND<T, N>
+    where T: Pod {}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_cast_points_to_bytes_and_back() {
+        let points = [PointND::from([1.0f32, -2.0]), PointND::from([0.5, 4.0])];
+
+        let bytes: &[u8] = bytemuck::cast_slice(&points);
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(&bytes[4..8], (-2.0f32).to_ne_bytes());
+
+        let back: &[PointND<f32, 2>] = bytemuck::cast_slice(bytes);
+        assert_eq!(back, points);
+    }
+
+    #[test]
+    fn can_cast_points_to_arrays() {
+        let points = [PointND::from([1.0f32, -2.0]), PointND::from([0.5, 4.0])];
+
+        let arrs: &[[f32; 2]] = bytemuck::cast_slice(&points);
+        assert_eq!(arrs, [[1.0, -2.0], [0.5, 4.0]]);
+
+        let flat: &[f32] = bytemuck::cast_slice(&points);
+        assert_eq!(flat, [1.0, -2.0, 0.5, 4.0]);
+    }
+
+    #[test]
+    fn can_create_zeroed_points() {
+        let p: PointND<u64, 40> = Zeroable::zeroed();
+        assert_eq!(p, PointND::zero());
+    }
+
+}
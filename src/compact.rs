@@ -0,0 +1,189 @@
+use crate::PointND;
+use crate::error::BufferTooSmallError;
+
+// Note to Developers:
+// - Items are formatted with itoa and ryu rather than core::fmt, which is much larger and slower
+//   on embedded targets
+// - ryu writes whole floats with a trailing ".0" (e.g. "2.0"). This is trimmed, as "2" still
+//   parses back into exactly the same float
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+///
+/// Trait for items which can be written by `PointND::write_compact()`
+///
+/// This is implemented for all primitive integers, `f32` and `f64`, and cannot be implemented
+/// for other types
+///
+/// # Enabled by features:
+///
+/// - `compact-fmt`
+///
+pub trait CompactItem: sealed::Sealed {
+
+    /// Calls `f` with the compact representation of `self`
+    #[doc(hidden)]
+    fn with_compact<R>(&self, f: impl FnOnce(&[u8]) -> R) -> R;
+
+}
+
+// Implements CompactItem for each integer type
+macro_rules! impl_compact_ints {
+    ($($t:ty),*) => {$(
+
+        impl sealed::Sealed for $t {}
+
+        impl CompactItem for $t {
+
+            fn with_compact<R>(&self, f: impl FnOnce(&[u8]) -> R) -> R {
+                let mut buf = itoa::Buffer::new();
+                f(buf.format(*self).as_bytes())
+            }
+
+        }
+
+    )*};
+}
+
+// Implements CompactItem for each float type
+macro_rules! impl_compact_floats {
+    ($($t:ty),*) => {$(
+
+        impl sealed::Sealed for $t {}
+
+        impl CompactItem for $t {
+
+            fn with_compact<R>(&self, f: impl FnOnce(&[u8]) -> R) -> R {
+                let mut buf = ryu::Buffer::new();
+                let s = buf.format(*self);
+                f(s.strip_suffix(".0").unwrap_or(s).as_bytes())
+            }
+
+        }
+
+    )*};
+}
+
+impl_compact_ints!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_compact_floats!(f32, f64);
+
+///
+/// Method for writing `PointND`'s into byte buffers without `core::fmt`
+///
+/// # Enabled by features:
+///
+/// - `compact-fmt`
+///
+impl<T, const N: usize> PointND<T, N>
+    where T: CompactItem {
+
+    ///
+    /// Writes the items of `self` into `buf` as comma separated values, returning the number
+    /// of bytes written
+    ///
+    /// Integers are written in full. Floats are written with the shortest representation
+    /// which parses back into exactly the same value, without a trailing `.0` for whole
+    /// numbers. The output can be parsed back into a point with `parse_csv()`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([1.5, 2.0, 3.25]);
+    ///
+    /// let mut buf = [0; 16];
+    /// let len = p.write_compact(&mut buf).unwrap();
+    /// assert_eq!(&buf[..len], b"1.5,2,3.25");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - `BufferTooSmallError` if `buf` is shorter than the output. The error contains the
+    ///   number of bytes needed, and the contents of `buf` are unspecified
+    ///
+    pub fn write_compact(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmallError> {
+        let mut len = 0;
+
+        for (i, item) in self.iter().enumerate() {
+            if i > 0 {
+                if let Some(byte) = buf.get_mut(len) {
+                    *byte = b',';
+                }
+                len += 1;
+            }
+            // Keeps counting past the end of buf so the error can state the length needed
+            len += item.with_compact(|bytes| {
+                if let Some(dest) = buf.get_mut(len..len + bytes.len()) {
+                    dest.copy_from_slice(bytes);
+                }
+                bytes.len()
+            });
+        }
+
+        if len > buf.len() {
+            return Err(BufferTooSmallError { required: len, found: buf.len() });
+        }
+        Ok(len)
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::str;
+
+    #[test]
+    fn can_write_into_exactly_sized_buffers() {
+        let p = PointND::from([1.5, 2.0, 3.25]);
+        let mut buf = [0; 10];
+        assert_eq!(p.write_compact(&mut buf), Ok(10));
+        assert_eq!(&buf, b"1.5,2,3.25");
+
+        let p = PointND::from([i64::MIN, 0, i64::MAX]);
+        let mut buf = [0; 42];
+        assert_eq!(p.write_compact(&mut buf), Ok(42));
+        assert_eq!(&buf, b"-9223372036854775808,0,9223372036854775807");
+    }
+
+    #[test]
+    fn cannot_write_into_too_small_buffers() {
+        let p = PointND::from([1.5, 2.0, 3.25]);
+        let mut buf = [0; 9];
+        assert_eq!(p.write_compact(&mut buf), Err(BufferTooSmallError { required: 10, found: 9 }));
+
+        let p = PointND::from([u128::MAX]);
+        assert_eq!(p.write_compact(&mut []), Err(BufferTooSmallError { required: 39, found: 0 }));
+    }
+
+    #[test]
+    fn can_write_zero_dimensional_points() {
+        let p = PointND::<u8, 0>::from([]);
+        assert_eq!(p.write_compact(&mut []), Ok(0));
+    }
+
+    #[test]
+    fn can_write_special_floats() {
+        let p = PointND::from([-0.0, f32::INFINITY, 1e20, 1e-7]);
+        let mut buf = [0; 32];
+        let len = p.write_compact(&mut buf).unwrap();
+        assert_eq!(&buf[..len], b"-0,inf,1e20,1e-7");
+    }
+
+    #[test]
+    fn can_round_trip_through_csv() {
+        let p = PointND::from([0.1, -2.0, 1.0 / 3.0, f64::MAX, f64::MIN_POSITIVE, 5e-324]);
+        let mut buf = [0; 128];
+        let len = p.write_compact(&mut buf).unwrap();
+
+        let csv = str::from_utf8(&buf[..len]).unwrap();
+        assert_eq!(PointND::<f64, 6>::parse_csv(csv), Ok(p));
+
+        let p = PointND::from([-128i8, 0, 127]);
+        let len = p.write_compact(&mut buf).unwrap();
+        let csv = str::from_utf8(&buf[..len]).unwrap();
+        assert_eq!(PointND::<i8, 3>::parse_csv(csv), Ok(p));
+    }
+
+}
@@ -0,0 +1,280 @@
+use core::fmt;
+
+///
+/// Error returned when parsing a `PointND` from a string fails
+///
+/// The generic `E` is the error returned when parsing a single item of the point fails
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParsePointError<E> {
+    /// The string contained a different number of items than the dimensions of the point
+    WrongDims { expected: usize, found: usize },
+    /// The item at `index` could not be parsed
+    InvalidItem { index: usize, error: E },
+}
+
+impl<E> fmt::Display for ParsePointError<E>
+    where E: fmt::Display {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePointError::WrongDims { expected, found } => write!(
+                f, "expected {} items when parsing PointND, found {}", expected, found
+            ),
+            ParsePointError::InvalidItem { index, error } => write!(
+                f, "could not parse item at index {} of PointND: {}", index, error
+            ),
+        }
+    }
+
+}
+
+impl<E> core::error::Error for ParsePointError<E>
+    where E: core::error::Error + 'static {
+
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParsePointError::WrongDims { .. } => None,
+            ParsePointError::InvalidItem { error, .. } => Some(error),
+        }
+    }
+
+}
+
+///
+/// Error returned when a `PointND` cannot be created from an iterator
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum FromIterError {
+    /// The iterator yielded `found` items, less than the `expected` dimensions of the point
+    TooFew { expected: usize, found: usize },
+    /// The iterator yielded more items than the `expected` dimensions of the point
+    TooMany { expected: usize },
+}
+
+impl fmt::Display for FromIterError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromIterError::TooFew { expected, found } => write!(
+                f, "expected {} items to create PointND from iterator, found {}", expected, found
+            ),
+            FromIterError::TooMany { expected } => write!(
+                f, "expected {} items to create PointND from iterator, found more", expected
+            ),
+        }
+    }
+
+}
+
+impl core::error::Error for FromIterError {}
+
+///
+/// Error returned when a `PointND` cannot be created from a slice of a different length
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct DimensionMismatchError {
+    /// The dimensions of the point
+    pub expected: usize,
+    /// The length of the slice given
+    pub found: usize,
+}
+
+impl fmt::Display for DimensionMismatchError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected {} items to create PointND from slice, found {}", self.expected, self.found)
+    }
+
+}
+
+impl core::error::Error for DimensionMismatchError {}
+
+///
+/// Error returned when creating a `NonZeroPoint` from a `PointND` with all items equal to zero
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct ZeroPointError;
+
+impl fmt::Display for ZeroPointError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected a PointND with a non-zero item, found only zeros")
+    }
+
+}
+
+impl core::error::Error for ZeroPointError {}
+
+///
+/// Error returned when creating a `FinitePoint` from a `PointND` containing a `NaN` or infinite item
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct NonFiniteError {
+    /// The index of the first item which was not finite
+    pub index: usize,
+}
+
+impl fmt::Display for NonFiniteError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected only finite items in PointND, found a non-finite item at index {}", self.index)
+    }
+
+}
+
+impl core::error::Error for NonFiniteError {}
+
+///
+/// Error returned when contracting a `PointND` to more dimensions than it has
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct ContractError {
+    /// The dimensions of the point being contracted
+    pub dims: usize,
+    /// The dimensions the point was to be contracted to
+    pub requested: usize,
+}
+
+impl fmt::Display for ContractError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected at most {} dimensions to contract PointND to, found {}", self.dims, self.requested)
+    }
+
+}
+
+impl core::error::Error for ContractError {}
+
+///
+/// Error returned when a buffer is too small to write a `PointND` into
+///
+/// # Enabled by features:
+///
+/// - `compact-fmt`
+///
+#[cfg(feature = "compact-fmt")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct BufferTooSmallError {
+    /// The number of bytes needed to write the point
+    pub required: usize,
+    /// The length of the buffer given
+    pub found: usize,
+}
+
+#[cfg(feature = "compact-fmt")]
+impl fmt::Display for BufferTooSmallError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected a buffer of at least {} bytes to write PointND, found {}", self.required, self.found)
+    }
+
+}
+
+#[cfg(feature = "compact-fmt")]
+impl core::error::Error for BufferTooSmallError {}
+
+///
+/// Error returned by the fallible (`try_*`) counterparts of the panicking methods of `PointND`
+///
+/// The more specific errors returned by some methods can be converted into this with `?`,
+/// so a single error type can be propagated from any of them
+///
+/// ```
+/// # use point_nd::{PointND, Error};
+/// fn build(items: &[i32]) -> Result<PointND<i32, 3>, Error> {
+///     let p = PointND::try_from_slice(items)?;
+///     p.try_with_dim(1, 10)
+/// }
+///
+/// assert_eq!(build(&[0, 0, 0]), Ok(PointND::from([0, 10, 0])));
+/// assert_eq!(build(&[0, 0]), Err(Error::DimensionMismatch { expected: 3, found: 2 }));
+/// ```
+///
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Error {
+    /// A slice or array contained a different number of items than the `expected` dimensions
+    DimensionMismatch { expected: usize, found: usize },
+    /// A dimension `index` was greater than or equal to the `dims` of the point
+    IndexOutOfBounds { index: usize, dims: usize },
+    /// More dimensions were `requested` from the point than the `dims` it has
+    TooManyDimensions { dims: usize, requested: usize },
+}
+
+impl fmt::Display for Error {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::DimensionMismatch { expected, found } => write!(
+                f, "expected {} items for PointND, found {}", expected, found
+            ),
+            Error::IndexOutOfBounds { index, dims } => write!(
+                f, "index {} is out of bounds for PointND of {} dimensions", index, dims
+            ),
+            Error::TooManyDimensions { dims, requested } => write!(
+                f, "requested {} dimensions from PointND of {} dimensions", requested, dims
+            ),
+        }
+    }
+
+}
+
+impl core::error::Error for Error {}
+
+impl From<DimensionMismatchError> for Error {
+
+    fn from(err: DimensionMismatchError) -> Self {
+        Error::DimensionMismatch { expected: err.expected, found: err.found }
+    }
+
+}
+
+impl From<ContractError> for Error {
+
+    fn from(err: ContractError) -> Self {
+        Error::TooManyDimensions { dims: err.dims, requested: err.requested }
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn display_messages_are_informative() {
+        let err = Error::DimensionMismatch { expected: 3, found: 5 };
+        assert_eq!(err.to_string(), "expected 3 items for PointND, found 5");
+
+        let err = Error::IndexOutOfBounds { index: 7, dims: 4 };
+        assert_eq!(err.to_string(), "index 7 is out of bounds for PointND of 4 dimensions");
+
+        let err = Error::TooManyDimensions { dims: 2, requested: 6 };
+        assert_eq!(err.to_string(), "requested 6 dimensions from PointND of 2 dimensions");
+    }
+
+    #[test]
+    fn can_convert_specific_errors() {
+        let err = Error::from(DimensionMismatchError { expected: 2, found: 1 });
+        assert!(matches!(err, Error::DimensionMismatch { expected: 2, found: 1 }));
+
+        let err = Error::from(ContractError { dims: 3, requested: 4 });
+        assert!(matches!(err, Error::TooManyDimensions { dims: 3, requested: 4 }));
+    }
+
+    #[test]
+    fn parse_errors_have_item_error_as_source() {
+        use core::error::Error as _;
+
+        let item_err = "x".parse::<i32>().unwrap_err();
+        let err = ParsePointError::InvalidItem { index: 1, error: item_err.clone() };
+        assert_eq!(err.source().unwrap().to_string(), item_err.to_string());
+
+        let err = ParsePointError::<core::num::ParseIntError>::WrongDims { expected: 2, found: 3 };
+        assert!(err.source().is_none());
+    }
+
+}
@@ -0,0 +1,1674 @@
+use crate::PointND;
+#[cfg(feature = "float-math")]
+use libm::Libm;
+#[cfg(feature = "rand")]
+use rand::Rng;
+#[cfg(all(feature = "float-math", feature = "num-traits"))]
+use num_traits::AsPrimitive;
+
+// Panics if a point of the given dimensions can't be converted to or from the given number of
+//  hyperspherical angles
+#[cfg(feature = "float-math")]
+fn check_hyperspherical_dims(dims: usize, angles: usize, method_name: &str) {
+    if dims < 2 {
+        panic!("Attempted to call {}() on a PointND of {} dimensions, which needs at least 2 dimensions",
+               method_name, dims);
+    }
+    if angles + 1 != dims {
+        panic!("Attempted to call {}() on a PointND of {} dimensions with {} angles, which needs {} angles",
+               method_name, dims, angles, dims - 1);
+    }
+}
+
+// Implements methods for points containing floating point numbers
+//
+// Kept in a macro as the square root (and other) functions provided by libm have
+//  to be called with a concrete float type
+macro_rules! impl_float_methods {
+    ($($t:ty),*) => {$(
+
+        ///
+        /// Methods for computing statistics of floating point `PointND`'s
+        ///
+        impl<const N: usize> PointND<$t, N> {
+
+            ///
+            /// Returns the mean of the items contained by `self`
+            ///
+            /// As the mean of zero items is undefined, zero dimensional points return `NaN`
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 4>::from([1.0, 2.0, 3.0, 6.0]);")]
+            /// assert_eq!(p.component_mean(), 3.0);
+            /// ```
+            ///
+            pub fn component_mean(&self) -> $t {
+                self.iter().sum::<$t>() / N as $t
+            }
+
+            ///
+            /// Returns the parameters at which a ray enters and exits an axis aligned box, or
+            /// `None` if the ray misses the box
+            ///
+            /// The ray starts at `origin` and points along `dir`, so a returned parameter `t`
+            /// corresponds to the point `origin + dir * t`. The box is bounded inclusively by
+            /// `box_min` and `box_max`, meaning rays which only graze a face are still counted
+            /// as hits
+            ///
+            /// If `origin` is inside the box, the entry parameter is `0.0`. Boxes lying entirely
+            /// behind the origin are counted as misses
+            ///
+            /// Zero components of `dir` (_i.e._ - rays parallel to an axis) are handled by
+            /// checking whether `origin` lies within the box along that axis, so no division
+            /// by zero takes place
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let origin = PointND::<", stringify!($t), ", 2>::from([0.0, 1.0]);")]
+            #[doc = concat!("let dir = PointND::<", stringify!($t), ", 2>::from([1.0, 0.0]);")]
+            #[doc = concat!("let min = PointND::<", stringify!($t), ", 2>::from([2.0, 0.0]);")]
+            #[doc = concat!("let max = PointND::<", stringify!($t), ", 2>::from([4.0, 2.0]);")]
+            ///
+            #[doc = concat!("let hit = PointND::<", stringify!($t), ", 2>::ray_box_intersect(&origin, &dir, &min, &max);")]
+            /// assert_eq!(hit, Some((2.0, 4.0)));
+            /// ```
+            ///
+            pub fn ray_box_intersect(
+                origin: &Self,
+                dir: &Self,
+                box_min: &Self,
+                box_max: &Self
+            ) -> Option<($t, $t)> {
+
+                let mut t_enter = <$t>::NEG_INFINITY;
+                let mut t_exit = <$t>::INFINITY;
+
+                for i in 0..N {
+                    let (o, d) = (origin[i], dir[i]);
+                    let (lo, hi) = (box_min[i], box_max[i]);
+
+                    if d == 0.0 {
+                        // Ray is parallel to this slab, so it either always or never lies within it
+                        if o < lo || o > hi {
+                            return None;
+                        }
+                        continue;
+                    }
+
+                    let mut t1 = (lo - o) / d;
+                    let mut t2 = (hi - o) / d;
+                    if t1 > t2 {
+                        core::mem::swap(&mut t1, &mut t2);
+                    }
+
+                    t_enter = t_enter.max(t1);
+                    t_exit = t_exit.min(t2);
+                    if t_enter > t_exit {
+                        return None;
+                    }
+                }
+
+                if t_exit < 0.0 {
+                    return None;
+                }
+                Some((t_enter.max(0.0), t_exit))
+            }
+
+        }
+
+        ///
+        /// Methods for moving floating point `PointND`'s between bases (_e.g._ - world and local frames)
+        ///
+        impl<const N: usize> PointND<$t, N> {
+
+            ///
+            /// Returns the coordinates of `self` in the frame made by `basis`
+            ///
+            /// This multiplies `self` by the matrix whose rows are the vectors of `basis`,
+            /// meaning each item of the returned point is the dot product of `self` and the
+            /// respective basis vector. No matrix is inverted
+            ///
+            /// For orthonormal bases (_i.e._ - unit length vectors at right angles to each
+            /// other) this gives the exact coordinates, and `from_basis()` reverses it. For other
+            /// bases this gives projections onto each vector rather than coordinates, so
+            /// `from_basis()` does **not** return the original point
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            /// // Local frame rotated 90 degrees counter-clockwise from the world frame
+            #[doc = concat!("let basis = [PointND::<", stringify!($t), ", 2>::from([0.0, 1.0]), PointND::from([-1.0, 0.0])];")]
+            ///
+            #[doc = concat!("let world = PointND::<", stringify!($t), ", 2>::from([2.0, 3.0]);")]
+            /// let local = world.change_basis(&basis);
+            /// assert_eq!(local.into_arr(), [3.0, -2.0]);
+            #[doc = concat!("assert_eq!(PointND::<", stringify!($t), ", 2>::from_basis(&local, &basis), world);")]
+            /// ```
+            ///
+            pub fn change_basis(&self, basis: &[Self; N]) -> Self {
+                PointND::from(basis.each_ref().map(|vector| self.dot(vector)))
+            }
+
+            ///
+            /// Returns the point reconstructed from the `coeffs` of each vector in `basis`
+            ///
+            /// This is the sum of each basis vector multiplied by its respective coefficient,
+            /// and reverses `change_basis()` for orthonormal bases
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let basis = [PointND::<", stringify!($t), ", 2>::from([1.0, 1.0]), PointND::from([0.0, 2.0])];")]
+            #[doc = concat!("let coeffs = PointND::<", stringify!($t), ", 2>::from([3.0, 0.5]);")]
+            ///
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from_basis(&coeffs, &basis);")]
+            /// assert_eq!(p.into_arr(), [3.0, 4.0]);
+            /// ```
+            ///
+            pub fn from_basis(coeffs: &Self, basis: &[Self; N]) -> Self {
+                let mut arr = [0.0; N];
+                for (coeff, vector) in coeffs.iter().zip(basis.iter()) {
+                    for (item, v) in arr.iter_mut().zip(vector.iter()) {
+                        *item += coeff * v;
+                    }
+                }
+                PointND::from(arr)
+            }
+
+        }
+
+        ///
+        /// Method for interpolating between floating point `PointND`'s
+        ///
+        impl<const N: usize> PointND<$t, N> {
+
+            ///
+            /// Returns an iterator over `steps` points evenly spaced along the line from `a` to
+            /// `b`, including both endpoints
+            ///
+            /// The first and last points yielded are exactly `a` and `b`. If `steps` is `1`, only
+            /// `a` is yielded, and if `steps` is `0`, nothing is yielded
+            ///
+            /// The returned iterator knows its exact length and can be iterated from either end
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let a = PointND::<", stringify!($t), ", 2>::from([0.0, 10.0]);")]
+            #[doc = concat!("let b = PointND::<", stringify!($t), ", 2>::from([1.0, 0.0]);")]
+            ///
+            #[doc = concat!("let samples = PointND::<", stringify!($t), ", 2>::sample_between(a, b, 5);")]
+            /// let points: Vec<_> = samples.map(|p| p.into_arr()).collect();
+            /// assert_eq!(points, [[0.0, 10.0], [0.25, 7.5], [0.5, 5.0], [0.75, 2.5], [1.0, 0.0]]);
+            /// ```
+            ///
+            pub fn sample_between(a: Self, b: Self, steps: usize)
+                -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+
+                let last = steps.saturating_sub(1).max(1) as $t;
+                (0..steps).map(move |i| {
+                    // Weighting both ends (rather than adding to a) yields exactly a and b at the ends
+                    let t = i as $t / last;
+                    PointND::from_fn(|j| a[j] * (1.0 - t) + b[j] * t)
+                })
+            }
+
+        }
+
+        ///
+        /// Methods for computing magnitudes and distances of floating point `PointND`'s
+        ///
+        /// # Enabled by features:
+        ///
+        /// - `float-math`
+        ///
+        #[cfg(feature = "float-math")]
+        impl<const N: usize> PointND<$t, N> {
+
+            ///
+            /// Returns the magnitude (_a.k.a_ - euclidean length) of `self`
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from([3.0, 4.0]);")]
+            /// assert_eq!(p.magnitude(), 5.0);
+            /// ```
+            ///
+            pub fn magnitude(&self) -> $t {
+                Libm::<$t>::sqrt(self.magnitude_squared())
+            }
+
+            ///
+            /// Consumes `self` and returns a new point with the same direction and a magnitude
+            /// of `1.0`
+            ///
+            /// Returns `None` if the magnitude of `self` is zero (including zero dimensional
+            /// points), as a zero vector has no direction
+            ///
+            /// Any `NaN` values in `self` will result in a point containing only `NaN` values
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from([3.0, 4.0]);")]
+            /// assert_eq!(p.normalize().unwrap().into_arr(), [0.6, 0.8]);
+            ///
+            #[doc = concat!("let zero = PointND::<", stringify!($t), ", 2>::fill(0.0);")]
+            /// assert_eq!(zero.normalize(), None);
+            /// ```
+            ///
+            pub fn normalize(self) -> Option<Self> {
+                let mag = self.magnitude();
+                if mag == 0.0 {
+                    return None;
+                }
+
+                let mut arr = self.into_arr();
+                for item in arr.iter_mut() {
+                    *item /= mag;
+                }
+                Some(PointND::from(arr))
+            }
+
+            ///
+            /// Returns the euclidean distance between `self` and `other`
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p1 = PointND::<", stringify!($t), ", 3>::from([1.0, 1.0, 1.0]);")]
+            #[doc = concat!("let p2 = PointND::<", stringify!($t), ", 3>::from([3.0, 3.0, 2.0]);")]
+            /// assert_eq!(p1.distance(&p2), 3.0);
+            /// ```
+            ///
+            pub fn distance(&self, other: &Self) -> $t {
+                let mut diff = self.clone().into_arr();
+                for (a, b) in diff.iter_mut().zip(other.iter()) {
+                    *a -= *b;
+                }
+                PointND::from(diff).magnitude()
+            }
+
+            ///
+            /// Returns the `Lp` (_a.k.a_ - minkowski) distance of order `p` between `self` and `other`
+            ///
+            /// An order of `1.0` gives the manhattan distance and an order of `2.0` gives the
+            /// euclidean distance. Orders below `1.0` do not give a true metric
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p1 = PointND::<", stringify!($t), ", 2>::from([0.0, 0.0]);")]
+            #[doc = concat!("let p2 = PointND::<", stringify!($t), ", 2>::from([3.0, -4.0]);")]
+            /// assert_eq!(p1.lp_distance(&p2, 1.0), 7.0);
+            /// assert_eq!(p1.lp_distance(&p2, 2.0), 5.0);
+            /// ```
+            ///
+            pub fn lp_distance(&self, other: &Self, p: $t) -> $t {
+                let sum: $t = self.iter()
+                    .zip(other.iter())
+                    .map(|(a, b)| Libm::<$t>::pow(Libm::<$t>::fabs(a - b), p))
+                    .sum();
+                Libm::<$t>::pow(sum, 1.0 / p)
+            }
+
+        }
+
+        ///
+        /// Methods for rounding the items of floating point `PointND`'s and snapping them to grids
+        ///
+        /// `NaN` and infinite items are returned unchanged by each method, except for `fract()`
+        /// which returns `NaN` for infinite items
+        ///
+        /// # Enabled by features:
+        ///
+        /// - `float-math`
+        ///
+        #[cfg(feature = "float-math")]
+        impl<const N: usize> PointND<$t, N> {
+
+            ///
+            /// Consumes `self` and returns a new point with each item rounded down to the nearest
+            /// integer (_i.e._ - towards negative infinity)
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 3>::from([1.5, -1.5, 2.0]);")]
+            /// assert_eq!(p.floor().into_arr(), [1.0, -2.0, 2.0]);
+            /// ```
+            ///
+            pub fn floor(self) -> Self {
+                PointND::from(self.into_arr().map(Libm::<$t>::floor))
+            }
+
+            ///
+            /// Consumes `self` and returns a new point with each item rounded up to the nearest
+            /// integer (_i.e._ - towards positive infinity)
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 3>::from([1.5, -1.5, 2.0]);")]
+            /// assert_eq!(p.ceil().into_arr(), [2.0, -1.0, 2.0]);
+            /// ```
+            ///
+            pub fn ceil(self) -> Self {
+                PointND::from(self.into_arr().map(Libm::<$t>::ceil))
+            }
+
+            ///
+            /// Consumes `self` and returns a new point with each item rounded to the nearest
+            /// integer, with halves rounded away from zero
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 3>::from([1.5, -1.5, 2.4]);")]
+            /// assert_eq!(p.round().into_arr(), [2.0, -2.0, 2.0]);
+            /// ```
+            ///
+            pub fn round(self) -> Self {
+                PointND::from(self.into_arr().map(Libm::<$t>::round))
+            }
+
+            ///
+            /// Consumes `self` and returns a new point with the fractional part of each item
+            /// removed (_i.e._ - rounded towards zero)
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 3>::from([1.5, -1.5, 2.0]);")]
+            /// assert_eq!(p.trunc().into_arr(), [1.0, -1.0, 2.0]);
+            /// ```
+            ///
+            pub fn trunc(self) -> Self {
+                PointND::from(self.into_arr().map(Libm::<$t>::trunc))
+            }
+
+            ///
+            /// Consumes `self` and returns a new point with only the fractional part of each item
+            ///
+            /// The fractional part has the same sign as the item, being the item minus the result
+            /// of `trunc()`
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 3>::from([1.5, -1.5, 2.0]);")]
+            /// assert_eq!(p.fract().into_arr(), [0.5, -0.5, 0.0]);
+            /// ```
+            ///
+            pub fn fract(self) -> Self {
+                PointND::from(self.into_arr().map(|item| item - Libm::<$t>::trunc(item)))
+            }
+
+            ///
+            /// Consumes `self` and returns a new point with each item rounded to the nearest
+            /// integer (see `round()`) and converted into the integer type `I`
+            ///
+            /// Conversion is done with the `as` operator (see `as_cast()`), so items outside the
+            /// bounds of `I` saturate and `NaN` items become zero
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 3>::from([1.5, -1.5, -0.4]);")]
+            /// let tile: PointND<i32, 3> = p.round_to_int();
+            /// assert_eq!(tile.into_arr(), [2, -2, 0]);
+            /// ```
+            ///
+            /// # Enabled by features:
+            ///
+            /// - `float-math`
+            ///
+            /// - `num-traits`
+            ///
+            #[cfg(feature = "num-traits")]
+            pub fn round_to_int<I>(self) -> PointND<I, N>
+                where $t: AsPrimitive<I>, I: Copy + 'static {
+                self.round().as_cast()
+            }
+
+            ///
+            /// Consumes `self` and returns a new point with each item snapped to the nearest
+            /// multiple of `cell_size` (_i.e._ - the nearest line of a grid)
+            ///
+            /// Items halfway between two lines are snapped away from zero, as with `round()`
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 3>::from([1.2, -1.2, 0.6]);")]
+            /// assert_eq!(p.snap_to_grid(0.5).into_arr(), [1.0, -1.0, 0.5]);
+            /// ```
+            ///
+            pub fn snap_to_grid(self, cell_size: $t) -> Self {
+                PointND::from(self.into_arr().map(|item| Libm::<$t>::round(item / cell_size) * cell_size))
+            }
+
+            ///
+            /// Consumes `self` and returns a new point with each item snapped to the nearest
+            /// multiple of the respective item of `cell_sizes`
+            ///
+            /// See `snap_to_grid()` for more info
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from([1.2, 7.0]);")]
+            /// assert_eq!(p.snap_to_grid_point(&PointND::from([0.5, 4.0])).into_arr(), [1.0, 8.0]);
+            /// ```
+            ///
+            pub fn snap_to_grid_point(self, cell_sizes: &Self) -> Self {
+                let mut arr = self.into_arr();
+                for (item, cell_size) in arr.iter_mut().zip(cell_sizes.iter()) {
+                    *item = Libm::<$t>::round(*item / cell_size) * cell_size;
+                }
+                PointND::from(arr)
+            }
+
+            ///
+            /// Consumes `self` and returns the integer coordinates of the grid cell containing it,
+            /// where each cell is `cell_size` wide along each axis
+            ///
+            /// Items are divided by `cell_size` and rounded down (see `floor()`), so the cell at
+            /// coordinate `0` contains items in `[0, cell_size)` and the cell at `-1` those in
+            /// `[-cell_size, 0)`. This keeps cells the same size on both sides of zero, unlike
+            /// converting with `as` which would put `-0.5` and `0.5` in the same cell
+            ///
+            /// Items are converted into `I` as with `round_to_int()`. Items exactly on a cell
+            /// boundary are subject to rounding errors in the division, unless `cell_size` is a
+            /// power of two
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 3>::from([0.5, -0.5, 2.0]);")]
+            /// let cell: PointND<i32, 3> = p.quantize(2.0);
+            /// assert_eq!(cell.into_arr(), [0, -1, 1]);
+            /// ```
+            ///
+            /// # Enabled by features:
+            ///
+            /// - `float-math`
+            ///
+            /// - `num-traits`
+            ///
+            #[cfg(feature = "num-traits")]
+            pub fn quantize<I>(self, cell_size: $t) -> PointND<I, N>
+                where $t: AsPrimitive<I>, I: Copy + 'static {
+                PointND::from(self.into_arr().map(|item| Libm::<$t>::floor(item / cell_size).as_()))
+            }
+
+            ///
+            /// Consumes `self` and returns the integer coordinates of the grid cell containing it,
+            /// where each cell is as wide as the respective item of `cell_sizes` along each axis
+            ///
+            /// See `quantize()` for more info
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from([5.0, -5.0]);")]
+            /// let cell: PointND<i64, 2> = p.quantize_point(&PointND::from([2.0, 10.0]));
+            /// assert_eq!(cell.into_arr(), [2, -1]);
+            /// ```
+            ///
+            /// # Enabled by features:
+            ///
+            /// - `float-math`
+            ///
+            /// - `num-traits`
+            ///
+            #[cfg(feature = "num-traits")]
+            pub fn quantize_point<I>(self, cell_sizes: &Self) -> PointND<I, N>
+                where $t: AsPrimitive<I>, I: Copy + 'static {
+                let mut arr = self.into_arr();
+                for (item, cell_size) in arr.iter_mut().zip(cell_sizes.iter()) {
+                    *item = Libm::<$t>::floor(*item / cell_size);
+                }
+                PointND::<$t, N>::from(arr).as_cast()
+            }
+
+        }
+
+        ///
+        /// Methods for wrapping floating point `PointND`'s containing angles (in radians)
+        ///
+        /// # Enabled by features:
+        ///
+        /// - `float-math`
+        ///
+        #[cfg(feature = "float-math")]
+        impl<const N: usize> PointND<$t, N> {
+
+            ///
+            /// Consumes `self` and returns a new point with each angle wrapped into the range `(-π, π]`
+            ///
+            /// Angles which are already within the range are unchanged, including `π` itself.
+            /// Wrapping is done with an exact remainder, so no precision is lost for angles far
+            /// outside the range
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            /// use core::f64::consts::PI;
+            ///
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 3>::from([0.5, 2.5 * PI as ", stringify!($t), ", -PI as ", stringify!($t), "]);")]
+            /// let p = p.normalize_angles();
+            ///
+            /// assert_eq!(p[0], 0.5);
+            #[doc = concat!("assert!((p[1] - 0.5 * PI as ", stringify!($t), ").abs() < 1e-6);")]
+            #[doc = concat!("assert_eq!(p[2], PI as ", stringify!($t), ");")]
+            /// ```
+            ///
+            pub fn normalize_angles(self) -> Self {
+                const PI: $t = core::f64::consts::PI as $t;
+                const TAU: $t = core::f64::consts::TAU as $t;
+
+                PointND::from(self.into_arr().map(|angle| {
+                    let wrapped = Libm::<$t>::fmod(angle, TAU);
+                    if wrapped > PI {
+                        wrapped - TAU
+                    } else if wrapped <= -PI {
+                        wrapped + TAU
+                    } else {
+                        wrapped
+                    }
+                }))
+            }
+
+            ///
+            /// Consumes `self` and returns a new point with each angle wrapped into the range `[0, 2π)`
+            ///
+            /// See `normalize_angles()` for more info
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            /// use core::f64::consts::PI;
+            ///
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from([-0.5 * PI as ", stringify!($t), ", 2.0 * PI as ", stringify!($t), "]);")]
+            /// let p = p.normalize_angles_positive();
+            ///
+            #[doc = concat!("assert!((p[0] - 1.5 * PI as ", stringify!($t), ").abs() < 1e-6);")]
+            /// assert_eq!(p[1], 0.0);
+            /// ```
+            ///
+            pub fn normalize_angles_positive(self) -> Self {
+                const TAU: $t = core::f64::consts::TAU as $t;
+
+                PointND::from(self.into_arr().map(|angle| {
+                    let wrapped = Libm::<$t>::fmod(angle, TAU);
+                    if wrapped >= 0.0 {
+                        wrapped
+                    } else {
+                        // Tiny negative angles may round up to 2π, which is outside the range
+                        let wrapped = wrapped + TAU;
+                        if wrapped < TAU { wrapped } else { 0.0 }
+                    }
+                }))
+            }
+
+            ///
+            /// Returns the shortest signed angles from each item of `other` to the respective
+            /// item of `self`, each within the range `(-π, π]`
+            ///
+            /// Equivalent to subtracting `other` from `self` and calling `normalize_angles()`
+            /// on the result
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            /// use core::f64::consts::PI;
+            ///
+            /// // Just either side of the wrap around point
+            #[doc = concat!("let heading = PointND::<", stringify!($t), ", 1>::from([-0.9 * PI as ", stringify!($t), "]);")]
+            #[doc = concat!("let target = PointND::<", stringify!($t), ", 1>::from([0.9 * PI as ", stringify!($t), "]);")]
+            ///
+            /// let diff = heading.angle_diff(&target);
+            #[doc = concat!("assert!((diff[0] - 0.2 * PI as ", stringify!($t), ").abs() < 1e-6);")]
+            /// ```
+            ///
+            pub fn angle_diff(&self, other: &Self) -> Self {
+                let mut diff = *self;
+                for (item, b) in diff.iter_mut().zip(other.iter()) {
+                    *item -= b;
+                }
+                diff.normalize_angles()
+            }
+
+        }
+
+        ///
+        /// Methods for creating and snapping to direction tables of 2D floating point `PointND`'s
+        ///
+        /// # Enabled by features:
+        ///
+        /// - `float-math`
+        ///
+        #[cfg(feature = "float-math")]
+        impl PointND<$t, 2> {
+
+            ///
+            /// Returns `K` evenly spaced unit directions, starting at `+X` and going counter-clockwise
+            ///
+            /// As the directions are computed with `sin()` and `cos()`, values which should be
+            /// zero may instead be very small (_e.g._ - `6.1e-17`)
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let dirs = PointND::<", stringify!($t), ", 2>::compass_directions::<4>();")]
+            ///
+            /// assert_eq!(*dirs[0], [1.0, 0.0]);
+            /// assert!((dirs[1][1] - 1.0).abs() < 1e-6);
+            /// assert!((dirs[2][0] + 1.0).abs() < 1e-6);
+            /// assert!((dirs[3][1] + 1.0).abs() < 1e-6);
+            /// ```
+            ///
+            pub fn compass_directions<const K: usize>() -> [Self; K] {
+                PointND::<Self, K>::from_fn(|i| {
+                    // Computed in f64 so the angles of f32 points are as exact as possible
+                    let angle = (core::f64::consts::TAU * i as f64 / K as f64) as $t;
+                    PointND::from([Libm::<$t>::cos(angle), Libm::<$t>::sin(angle)])
+                }).into_arr()
+            }
+
+            ///
+            /// Returns the index of the nearest of `k` evenly spaced directions to `self`, where
+            /// the directions are the same as those returned by `compass_directions()`
+            ///
+            /// Sector `0` is centred on `+X` and the indices increase counter-clockwise. Directions
+            /// exactly between two sectors snap to the counter-clockwise one. The zero vector (and
+            /// any point containing `NaN`) has no direction, so gives an index of `0`
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from([-2.0, 0.1]);")]
+            /// assert_eq!(p.direction_index(4), 2);
+            /// assert_eq!(p.direction_index(8), 4);
+            /// ```
+            ///
+            /// # Panics
+            ///
+            /// - If `k` is zero
+            ///
+            pub fn direction_index(&self, k: usize) -> usize {
+                if k == 0 {
+                    panic!("Attempted to get the direction_index() of a PointND among zero directions");
+                }
+                let [x, y] = [self[0] as f64, self[1] as f64];
+                if x == 0.0 && y == 0.0 {
+                    return 0;
+                }
+
+                // Computed in f64 so the sector boundaries of f32 points are as exact as possible
+                let sectors = Libm::<f64>::atan2(y, x) / (core::f64::consts::TAU / k as f64);
+                let index = Libm::<f64>::floor(sectors + 0.5);
+                if index.is_nan() {
+                    return 0;
+                }
+                (index as i64).rem_euclid(k as i64) as usize
+            }
+
+            ///
+            /// Consumes `self` and returns the nearest of `k` evenly spaced unit directions to it
+            ///
+            /// The direction is the one at `direction_index()` in `compass_directions()`, so values
+            /// which should be zero may instead be very small. The zero vector (and any point
+            /// containing `NaN`) has no direction, so is returned unchanged
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from([3.0, 0.4]);")]
+            /// assert_eq!(p.quantize_direction(8).into_arr(), [1.0, 0.0]);
+            /// ```
+            ///
+            /// # Panics
+            ///
+            /// - If `k` is zero
+            ///
+            pub fn quantize_direction(self, k: usize) -> Self {
+                if k == 0 {
+                    panic!("Attempted to quantize_direction() of a PointND among zero directions");
+                }
+                if (self[0] == 0.0 && self[1] == 0.0) || self[0].is_nan() || self[1].is_nan() {
+                    return self;
+                }
+                let index = self.direction_index(k);
+                let angle = (core::f64::consts::TAU * index as f64 / k as f64) as $t;
+                PointND::from([Libm::<$t>::cos(angle), Libm::<$t>::sin(angle)])
+            }
+
+        }
+
+        ///
+        /// Methods for creating direction tables of 3D floating point `PointND`'s
+        ///
+        /// # Enabled by features:
+        ///
+        /// - `float-math`
+        ///
+        #[cfg(feature = "float-math")]
+        impl PointND<$t, 3> {
+
+            ///
+            /// Returns `K` roughly evenly distributed unit directions, being the points of a
+            /// Fibonacci lattice on the unit sphere
+            ///
+            /// The points spiral from near the `-Z` pole to near the `+Z` pole, each rotated from
+            /// the last by the golden angle. See `fibonacci_sphere_iter()` to generate the points
+            /// one at a time
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let dirs = PointND::<", stringify!($t), ", 3>::fibonacci_sphere::<64>();")]
+            /// for dir in dirs.iter() {
+            ///     assert!((dir.magnitude() - 1.0).abs() < 1e-6);
+            /// }
+            /// ```
+            ///
+            pub fn fibonacci_sphere<const K: usize>() -> [Self; K] {
+                let mut points = Self::fibonacci_sphere_iter(K);
+                PointND::<Self, K>::from_fn(|_| points.next().unwrap()).into_arr()
+            }
+
+            ///
+            /// Returns an iterator over `count` roughly evenly distributed unit directions
+            ///
+            /// Yields the same points as `fibonacci_sphere()`, without needing to know the
+            /// number of points at compile time or storing them all at once
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let dirs = PointND::<", stringify!($t), ", 3>::fibonacci_sphere_iter(10_000);")]
+            /// let upwards = dirs.filter(|dir| dir[2] > 0.0).count();
+            /// assert_eq!(upwards, 5_000);
+            /// ```
+            ///
+            pub fn fibonacci_sphere_iter(count: usize) -> impl Iterator<Item = Self> {
+                // Computed in f64 so the angles of f32 points are as exact as possible
+                let golden_angle = core::f64::consts::PI * (3.0 - Libm::<f64>::sqrt(5.0));
+
+                (0..count).map(move |i| {
+                    // Offset by half a step so no points lie exactly on the poles
+                    let z = (-1.0 + (2 * i + 1) as f64 / count as f64) as $t;
+                    let radius = Libm::<$t>::sqrt(1.0 - z * z);
+                    let angle = ((golden_angle * i as f64) % core::f64::consts::TAU) as $t;
+
+                    PointND::from([
+                        Libm::<$t>::cos(angle) * radius,
+                        Libm::<$t>::sin(angle) * radius,
+                        z,
+                    ])
+                })
+            }
+
+        }
+
+        ///
+        /// Methods for converting floating point `PointND`'s to and from hyperspherical
+        /// coordinates
+        ///
+        /// # Enabled by features:
+        ///
+        /// - `float-math`
+        ///
+        #[cfg(feature = "float-math")]
+        impl<const N: usize> PointND<$t, N> {
+
+            ///
+            /// Returns the radius and the `N - 1` angles of `self` in hyperspherical coordinates
+            ///
+            /// The angles follow the standard parameterization, where the first `N - 2` angles
+            /// are within `[0, π]` and the last is within `(-π, π]`:
+            ///
+            /// ```text
+            /// x0     = r * cos(a0)
+            /// x1     = r * sin(a0) * cos(a1)
+            /// ...
+            /// x(N-2) = r * sin(a0) * ... * sin(a(N-3)) * cos(a(N-2))
+            /// x(N-1) = r * sin(a0) * ... * sin(a(N-3)) * sin(a(N-2))
+            /// ```
+            ///
+            /// For 2D points, this is the same as polar coordinates. Angles which can have any
+            /// value (_e.g._ - all angles of the zero vector) are set to zero
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 3>::from([0.0, 0.0, 2.0]);")]
+            /// let (radius, angles) = p.hyperspherical_angles::<2>();
+            ///
+            /// assert_eq!(radius, 2.0);
+            #[doc = concat!("assert_eq!(angles.into_arr(), [core::", stringify!($t), "::consts::FRAC_PI_2; 2]);")]
+            /// ```
+            ///
+            /// # Panics
+            ///
+            /// - If `N` is less than `2`
+            ///
+            /// - If `M` is not equal to `N - 1`
+            ///
+            pub fn hyperspherical_angles<const M: usize>(&self) -> ($t, PointND<$t, M>) {
+                check_hyperspherical_dims(N, M, "hyperspherical_angles");
+
+                // Adding zero turns -0.0 into 0.0, as atan2() would otherwise give angles of π
+                // (or -π) for negative zeros
+                let items = self.arr().map(|item| item + 0.0);
+
+                let mut angles = [0.0; M];
+                angles[N - 2] = Libm::<$t>::atan2(items[N - 1], items[N - 2]);
+
+                // The length of the items after each angle, computed from the back
+                let mut tail = Libm::<$t>::hypot(items[N - 1], items[N - 2]);
+                for i in (0..N - 2).rev() {
+                    angles[i] = Libm::<$t>::atan2(tail, items[i]);
+                    tail = Libm::<$t>::hypot(tail, items[i]);
+                }
+                (tail, PointND::from(angles))
+            }
+
+            ///
+            /// Returns a new `PointND` from a `radius` and the `N - 1` `angles` of hyperspherical
+            /// coordinates, reversing `hyperspherical_angles()`
+            ///
+            /// As the coordinates are computed with `sin()` and `cos()`, values which should be
+            /// zero may instead be very small (_e.g._ - `6.1e-17`)
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let angles = PointND::<", stringify!($t), ", 1>::from([0.0]);")]
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from_hyperspherical(3.0, angles);")]
+            /// assert_eq!(p.into_arr(), [3.0, 0.0]);
+            /// ```
+            ///
+            /// # Panics
+            ///
+            /// - If `N` is less than `2`
+            ///
+            /// - If `M` is not equal to `N - 1`
+            ///
+            pub fn from_hyperspherical<const M: usize>(radius: $t, angles: PointND<$t, M>) -> Self {
+                check_hyperspherical_dims(N, M, "from_hyperspherical");
+
+                let mut arr = [0.0; N];
+                let mut sin_product = radius;
+                for (item, angle) in arr.iter_mut().zip(angles.iter()) {
+                    *item = sin_product * Libm::<$t>::cos(*angle);
+                    sin_product *= Libm::<$t>::sin(*angle);
+                }
+                arr[N - 1] = sin_product;
+                PointND::from(arr)
+            }
+
+        }
+
+        ///
+        /// Methods for randomly sampling floating point `PointND`'s
+        ///
+        /// # Enabled by features:
+        ///
+        /// - `rand`
+        ///
+        #[cfg(feature = "rand")]
+        impl<const N: usize> PointND<$t, N> {
+
+            ///
+            /// Treats the items of `self` as weights and returns a randomly chosen index, with
+            /// the chance of each index being chosen proportional to its weight
+            ///
+            /// Returns `None` if all weights are zero (including zero dimensional points).
+            /// Indices with a weight of zero are never chosen
+            ///
+            /// All weights must be non-negative and finite, otherwise the result is unspecified
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            /// use rand::{rngs::SmallRng, SeedableRng};
+            ///
+            /// let mut rng = SmallRng::seed_from_u64(42);
+            #[doc = concat!("let weights = PointND::<", stringify!($t), ", 3>::from([0.0, 1.0, 3.0]);")]
+            /// let i = weights.sample_index(&mut rng).unwrap();
+            /// assert!(i == 1 || i == 2);
+            /// ```
+            ///
+            pub fn sample_index(&self, rng: &mut impl Rng) -> Option<usize> {
+                let total: $t = self.iter().sum();
+                if total <= 0.0 {
+                    return None;
+                }
+
+                let target = rng.gen::<$t>() * total;
+                let mut cumulative = 0.0;
+                for (i, weight) in self.iter().enumerate() {
+                    cumulative += weight;
+                    if target < cumulative {
+                        return Some(i);
+                    }
+                }
+
+                // Rounding errors may leave the target just above the summed weights
+                self.iter().rposition(|weight| *weight > 0.0)
+            }
+
+        }
+
+        ///
+        /// Methods for randomly sampling directions of floating point `PointND`'s
+        ///
+        /// # Enabled by features:
+        ///
+        /// - `rand`
+        ///
+        /// - `float-math`
+        ///
+        #[cfg(all(feature = "rand", feature = "float-math"))]
+        impl<const N: usize> PointND<$t, N> {
+
+            ///
+            /// Returns a new unit length point pointing in a uniformly distributed random direction
+            ///
+            /// Each item is sampled from a normal distribution before the point is normalized,
+            /// which (unlike sampling within a box) gives no preference to any direction
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            /// use rand::{rngs::SmallRng, SeedableRng};
+            ///
+            /// let mut rng = SmallRng::seed_from_u64(42);
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 3>::random_unit(&mut rng);")]
+            /// assert!((p.magnitude() - 1.0).abs() < 1e-6);
+            /// ```
+            ///
+            /// # Panics
+            ///
+            /// - If the point has zero dimensions, as it cannot have a length of one
+            ///
+            pub fn random_unit(rng: &mut impl Rng) -> Self {
+                if N == 0 {
+                    panic!("Attempted to call random_unit() on a PointND of 0 dimensions");
+                }
+                loop {
+                    // Box-Muller transform, with 1.0 - gen() keeping the logarithm finite
+                    let p = PointND::<$t, N>::from_fn(|_| {
+                        let radius = Libm::<$t>::sqrt(-2.0 * Libm::<$t>::log(1.0 - rng.gen::<$t>()));
+                        radius * Libm::<$t>::cos(core::f64::consts::TAU as $t * rng.gen::<$t>())
+                    });
+                    // Only fails in the unlikely case that every item was sampled as zero
+                    if let Some(unit) = p.normalize() {
+                        return unit;
+                    }
+                }
+            }
+
+        }
+
+    )*};
+}
+
+impl_float_methods!(f32, f64);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_get_component_mean() {
+        let p = PointND::from([2.5f32]);
+        assert_eq!(p.component_mean(), 2.5);
+
+        let p = PointND::from([-1.0f64, 2.0, 0.5, 4.5, -3.0, 3.0]);
+        assert_eq!(p.component_mean(), 1.0);
+
+        let p: PointND<f64, 0> = PointND::from([]);
+        assert!(p.component_mean().is_nan());
+    }
+
+    #[test]
+    fn ray_can_hit_box() {
+        type P = PointND<f64, 3>;
+        let min = P::from([1.0, 1.0, 1.0]);
+        let max = P::from([3.0, 3.0, 3.0]);
+
+        let origin = P::from([0.0, 0.0, 0.0]);
+        let dir = P::from([1.0, 1.0, 1.0]);
+        assert_eq!(P::ray_box_intersect(&origin, &dir, &min, &max), Some((1.0, 3.0)));
+
+        let origin = P::from([5.0, 2.0, 2.0]);
+        let dir = P::from([-2.0, 0.0, 0.0]);
+        assert_eq!(P::ray_box_intersect(&origin, &dir, &min, &max), Some((1.0, 2.0)));
+    }
+
+    #[test]
+    fn ray_can_miss_box() {
+        type P = PointND<f32, 2>;
+        let min = P::from([1.0, 1.0]);
+        let max = P::from([3.0, 3.0]);
+
+        // Passes beside the box
+        let origin = P::from([0.0, 0.0]);
+        let dir = P::from([1.0, -1.0]);
+        assert_eq!(P::ray_box_intersect(&origin, &dir, &min, &max), None);
+
+        // Box is behind the ray
+        let dir = P::from([-1.0, -1.0]);
+        assert_eq!(P::ray_box_intersect(&origin, &dir, &min, &max), None);
+    }
+
+    #[test]
+    fn ray_starting_inside_box_enters_at_zero() {
+        type P = PointND<f64, 2>;
+        let min = P::from([-1.0, -1.0]);
+        let max = P::from([1.0, 1.0]);
+        let origin = P::from([0.0, 0.0]);
+        let dir = P::from([0.5, 0.0]);
+
+        assert_eq!(P::ray_box_intersect(&origin, &dir, &min, &max), Some((0.0, 2.0)));
+    }
+
+    #[test]
+    fn axis_parallel_rays_can_graze_faces() {
+        type P = PointND<f64, 3>;
+        let min = P::from([0.0, 0.0, 0.0]);
+        let max = P::from([2.0, 2.0, 2.0]);
+        let dir = P::from([0.0, 1.0, 0.0]);
+
+        // Travels along the x = 2 face
+        let origin = P::from([2.0, -1.0, 1.0]);
+        assert_eq!(P::ray_box_intersect(&origin, &dir, &min, &max), Some((1.0, 3.0)));
+
+        // Travels just outside the x = 2 face
+        let origin = P::from([2.001, -1.0, 1.0]);
+        assert_eq!(P::ray_box_intersect(&origin, &dir, &min, &max), None);
+    }
+
+    #[test]
+    fn identity_basis_changes_nothing() {
+        let basis = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]].map(PointND::from);
+        let p = PointND::from([1.5f64, -2.0, 7.25]);
+
+        assert_eq!(p.change_basis(&basis), p);
+        assert_eq!(PointND::<f64, 3>::from_basis(&p, &basis), p);
+    }
+
+    #[test]
+    fn can_round_trip_orthonormal_bases() {
+        // Rotated by 30 degrees around z, then reflected in z
+        let (s, c) = (0.5f64, 0.75f64.sqrt());
+        let basis = [[c, s, 0.0], [-s, c, 0.0], [0.0, 0.0, -1.0]].map(PointND::from);
+
+        for arr in [[1.0, 2.0, 3.0], [-4.5, 0.0, 0.25], [100.0, -100.0, 1e-3]] {
+            let p = PointND::<f64, 3>::from(arr);
+            let local = p.change_basis(&basis);
+            assert!((local.magnitude_squared() - p.magnitude_squared()).abs() < 1e-9);
+
+            let back = PointND::<f64, 3>::from_basis(&local, &basis);
+            for (a, b) in back.iter().zip(p.iter()) {
+                assert!((a - b).abs() < 1e-9, "{:?} != {:?}", back, p);
+            }
+        }
+    }
+
+    #[test]
+    fn non_orthonormal_bases_give_projections() {
+        let basis = [[2.0, 0.0], [1.0, 1.0]].map(PointND::<f32, 2>::from);
+        let p = PointND::from([3.0f32, 4.0]);
+
+        // Dot products with each basis vector, not the coefficients [-0.5, 4.0]
+        let local = p.change_basis(&basis);
+        assert_eq!(local.into_arr(), [6.0, 7.0]);
+        assert_ne!(PointND::<f32, 2>::from_basis(&local, &basis), p);
+
+        let coeffs = PointND::from([-0.5f32, 4.0]);
+        assert_eq!(PointND::<f32, 2>::from_basis(&coeffs, &basis), p);
+    }
+
+    #[test]
+    fn can_change_zero_dimensional_bases() {
+        let p = PointND::<f64, 0>::from([]);
+        assert_eq!(p.change_basis(&[]), p);
+        assert_eq!(PointND::<f64, 0>::from_basis(&p, &[]), p);
+    }
+
+    #[test]
+    fn samples_include_endpoints() {
+        let a = PointND::from([0.1f64, -3.7, 1e6]);
+        let b = PointND::from([0.3f64, 2.9, -1e-6]);
+
+        for steps in 2..50 {
+            let samples: std::vec::Vec<_> = PointND::<f64, 3>::sample_between(a, b, steps).collect();
+            assert_eq!(samples.len(), steps);
+            assert_eq!(samples[0], a);
+            assert_eq!(samples[steps - 1], b);
+        }
+    }
+
+    #[test]
+    fn samples_are_evenly_spaced() {
+        let a = PointND::from([-1.0f32, 4.0]);
+        let b = PointND::from([2.0f32, -0.5]);
+        let samples: std::vec::Vec<_> = PointND::<f32, 2>::sample_between(a, b, 31).collect();
+
+        for pair in samples.windows(2) {
+            for j in 0..2 {
+                let expected = (b[j] - a[j]) / 30.0;
+                assert!((pair[1][j] - pair[0][j] - expected).abs() < 1e-5, "{:?}", pair);
+            }
+        }
+    }
+
+    #[test]
+    fn can_sample_few_steps() {
+        let a = PointND::from([1.0f64, 2.0]);
+        let b = PointND::from([3.0f64, 4.0]);
+
+        assert_eq!(PointND::<f64, 2>::sample_between(a, b, 0).next(), None);
+
+        let mut samples = PointND::<f64, 2>::sample_between(a, b, 1);
+        assert_eq!(samples.next(), Some(a));
+        assert_eq!(samples.next(), None);
+    }
+
+    #[test]
+    fn samples_know_their_length() {
+        let a = PointND::from([0.0f64; 4]);
+        let b = PointND::from([1.0f64; 4]);
+
+        let mut samples = PointND::<f64, 4>::sample_between(a, b, 5);
+        assert_eq!(samples.size_hint(), (5, Some(5)));
+        assert_eq!(samples.len(), 5);
+
+        assert_eq!(samples.next_back(), Some(b));
+        assert_eq!(samples.next(), Some(a));
+        assert_eq!(samples.len(), 3);
+        assert_eq!(samples.next_back(), Some(PointND::from([0.75; 4])));
+        assert_eq!(samples.size_hint(), (2, Some(2)));
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn can_get_magnitude() {
+        let p = PointND::from([2.0f32, 3.0, 6.0]);
+        assert_eq!(p.magnitude(), 7.0);
+
+        let p = PointND::from([1.0f64, 1.0, 1.0, 1.0]);
+        assert_eq!(p.magnitude(), 2.0);
+
+        let p: PointND<f64, 0> = PointND::from([]);
+        assert_eq!(p.magnitude(), 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn can_normalize() {
+        let p = PointND::from([0.0f64, -5.0, 0.0]).normalize().unwrap();
+        assert_eq!(p.into_arr(), [0.0, -1.0, 0.0]);
+
+        let p = PointND::from([1.0f32, 2.0, 3.0, 4.0]).normalize().unwrap();
+        assert!((p.magnitude() - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn cannot_normalize_zero_vectors() {
+        assert_eq!(PointND::<f64, 3>::fill(0.0).normalize(), None);
+        assert_eq!(PointND::<f32, 0>::from([]).normalize(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn normalizing_nan_gives_nan() {
+        let p = PointND::from([f64::NAN, 1.0]).normalize().unwrap();
+        assert!(p.iter().all(|item| item.is_nan()));
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn can_get_distance() {
+        let p1 = PointND::from([-1.0f64, 2.0]);
+        let p2 = PointND::from([2.0f64, -2.0]);
+        assert_eq!(p1.distance(&p2), 5.0);
+        assert_eq!(p2.distance(&p1), 5.0);
+        assert_eq!(p1.distance(&p1), 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn compass_directions_are_unit_length() {
+        for dir in PointND::<f64, 2>::compass_directions::<16>() {
+            assert!((dir.magnitude() - 1.0).abs() < 1e-12);
+        }
+        for dir in PointND::<f32, 2>::compass_directions::<7>() {
+            assert!((dir.magnitude() - 1.0).abs() < 1e-6);
+        }
+        assert_eq!(PointND::<f64, 2>::compass_directions::<0>().len(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn compass_directions_go_counter_clockwise_from_x() {
+        let dirs = PointND::<f64, 2>::compass_directions::<8>();
+        let half = core::f64::consts::FRAC_1_SQRT_2;
+        let expected = [
+            [1.0, 0.0], [half, half], [0.0, 1.0], [-half, half],
+            [-1.0, 0.0], [-half, -half], [0.0, -1.0], [half, -half],
+        ];
+
+        for (dir, exp) in dirs.iter().zip(expected.iter()) {
+            assert!(dir.distance(&PointND::from(*exp)) < 1e-12, "{:?} != {:?}", dir, exp);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn axes_have_direction_indices() {
+        let axes = [[1.0, 0.0], [0.0, 1.0], [-1.0, 0.0], [0.0, -1.0]].map(PointND::<f64, 2>::from);
+
+        for k in [4, 8, 16] {
+            for (i, axis) in axes.iter().enumerate() {
+                assert_eq!(axis.direction_index(k), i * k / 4);
+
+                let snapped = (*axis).quantize_direction(k);
+                assert!(snapped.distance(axis) < 1e-12, "{:?} != {:?}", snapped, axis);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn directions_on_boundaries_snap_counter_clockwise() {
+        let diagonals = [[1.0, 1.0], [-1.0, 1.0], [-1.0, -1.0], [1.0, -1.0]];
+        for (i, diagonal) in diagonals.into_iter().enumerate() {
+            assert_eq!(PointND::<f64, 2>::from(diagonal).direction_index(4), (i + 1) % 4);
+            assert_eq!(PointND::<f32, 2>::from(diagonal.map(|v| v as f32)).direction_index(4), (i + 1) % 4);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn directions_snap_to_nearest_sector() {
+        // Just either side of the boundary between sectors 0 and 1
+        for k in [4, 8, 16] {
+            let boundary = core::f64::consts::PI / k as f64;
+            let before = PointND::<f64, 2>::from([libm::cos(boundary - 1e-9), libm::sin(boundary - 1e-9)]);
+            let after = PointND::<f64, 2>::from([libm::cos(boundary + 1e-9), libm::sin(boundary + 1e-9)]);
+            assert_eq!(before.direction_index(k), 0);
+            assert_eq!(after.direction_index(k), 1);
+        }
+
+        // Just clockwise of +X wraps around to the last sector
+        let p = PointND::<f64, 2>::from([10.0, -0.1]);
+        assert_eq!(p.direction_index(16), 0);
+        assert_eq!(PointND::<f64, 2>::from([1.0, -0.5]).direction_index(16), 15);
+
+        let p = PointND::from([3.0f32, -4.0]).quantize_direction(8);
+        let half = core::f32::consts::FRAC_1_SQRT_2;
+        assert!(p.distance(&PointND::from([half, -half])) < 1e-6);
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn zero_vectors_have_no_direction() {
+        let zero = PointND::<f64, 2>::from([0.0, -0.0]);
+        assert_eq!(zero.direction_index(8), 0);
+        assert_eq!(zero.quantize_direction(8).into_arr(), [0.0, -0.0]);
+
+        assert_eq!(PointND::from([f32::NAN, 1.0]).direction_index(8), 0);
+        let nan = PointND::from([f32::NAN, 1.0]).quantize_direction(8);
+        assert!(nan[0].is_nan() && nan[1] == 1.0);
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    #[should_panic]
+    fn cannot_quantize_to_zero_directions() {
+        PointND::<f64, 2>::from([1.0, 0.0]).quantize_direction(0);
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    #[should_panic]
+    fn cannot_quantize_zero_vectors_to_zero_directions() {
+        PointND::<f64, 2>::from([0.0, 0.0]).quantize_direction(0);
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn fibonacci_sphere_points_are_unit_length() {
+        for dir in PointND::<f32, 3>::fibonacci_sphere::<100>() {
+            assert!((dir.magnitude() - 1.0).abs() < 1e-6);
+        }
+        for dir in PointND::<f64, 3>::fibonacci_sphere_iter(1000) {
+            assert!((dir.magnitude() - 1.0).abs() < 1e-12);
+        }
+        assert_eq!(PointND::<f64, 3>::fibonacci_sphere::<0>().len(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn fibonacci_sphere_points_dont_collapse() {
+        fn min_spacing<const K: usize>() -> f64 {
+            let dirs = PointND::<f64, 3>::fibonacci_sphere::<K>();
+            let mut min = f64::INFINITY;
+            for (i, a) in dirs.iter().enumerate() {
+                for b in dirs[i + 1..].iter() {
+                    min = min.min(a.distance(b));
+                }
+            }
+            min
+        }
+
+        // Evenly spread points are roughly sqrt(4 * PI / K) apart
+        assert!(min_spacing::<2>() > 1.5);
+        assert!(min_spacing::<12>() > 0.5 * (4.0 * core::f64::consts::PI / 12.0f64).sqrt());
+        assert!(min_spacing::<100>() > 0.5 * (4.0 * core::f64::consts::PI / 100.0f64).sqrt());
+        assert!(min_spacing::<500>() > 0.5 * (4.0 * core::f64::consts::PI / 500.0f64).sqrt());
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn fibonacci_sphere_matches_iter() {
+        let arr = PointND::<f32, 3>::fibonacci_sphere::<20>();
+        assert!(arr.iter().cloned().eq(PointND::<f32, 3>::fibonacci_sphere_iter(20)));
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn hyperspherical_coordinates_round_trip() {
+        use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+        fn round_trip<const N: usize, const M: usize>(rng: &mut SmallRng) {
+            for _ in 0..200 {
+                let p = PointND::<f64, N>::from_fn(|_| rng.gen_range(-100.0..100.0));
+                let (radius, angles) = p.hyperspherical_angles::<M>();
+                assert!((radius - p.magnitude()).abs() < 1e-9);
+
+                let back = PointND::<f64, N>::from_hyperspherical(radius, angles);
+                assert!(back.distance(&p) < 1e-9, "{:?} != {:?}", back, p);
+            }
+        }
+
+        let mut rng = SmallRng::seed_from_u64(788);
+        round_trip::<2, 1>(&mut rng);
+        round_trip::<3, 2>(&mut rng);
+        round_trip::<5, 4>(&mut rng);
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn hyperspherical_angles_are_standard() {
+        use core::f64::consts::{FRAC_PI_2, PI};
+
+        // Polar coordinates in 2D
+        let (radius, angles) = PointND::<f64, 2>::from([-2.0, 0.0]).hyperspherical_angles::<1>();
+        assert_eq!((radius, angles.into_arr()), (2.0, [PI]));
+
+        let (radius, angles) = PointND::<f64, 2>::from([0.0, -3.0]).hyperspherical_angles::<1>();
+        assert_eq!((radius, angles.into_arr()), (3.0, [-FRAC_PI_2]));
+
+        // Only the last angle can be negative
+        let (radius, angles) = PointND::<f64, 4>::from([0.0, -1.0, 0.0, 0.0]).hyperspherical_angles::<3>();
+        assert_eq!((radius, angles.into_arr()), (1.0, [FRAC_PI_2, PI, 0.0]));
+
+        let (_, angles) = PointND::<f32, 3>::from([1.0, 0.0, 0.0]).hyperspherical_angles::<2>();
+        assert_eq!(angles.into_arr(), [0.0, 0.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn zero_radii_have_zero_angles() {
+        let (radius, angles) = PointND::<f64, 5>::fill(0.0).hyperspherical_angles::<4>();
+        assert_eq!((radius, angles.into_arr()), (0.0, [0.0; 4]));
+
+        let p = PointND::<f64, 3>::from_hyperspherical(0.0, PointND::from([1.0, -2.0]));
+        assert_eq!(p.into_arr(), [0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn negative_zeros_have_zero_angles() {
+        let (radius, angles) = PointND::<f64, 2>::from([-0.0, 0.0]).hyperspherical_angles::<1>();
+        assert_eq!((radius, angles.into_arr()), (0.0, [0.0]));
+
+        let (_, angles) = PointND::<f32, 3>::from([-0.0, -0.0, -0.0]).hyperspherical_angles::<2>();
+        assert_eq!(angles.into_arr(), [0.0, 0.0]);
+
+        // The last angle stays within (-π, π]
+        let (_, angles) = PointND::<f64, 2>::from([-1.0, -0.0]).hyperspherical_angles::<1>();
+        assert_eq!(angles.into_arr(), [core::f64::consts::PI]);
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    #[should_panic]
+    fn cannot_get_wrong_number_of_angles() {
+        PointND::<f64, 3>::from([1.0, 2.0, 3.0]).hyperspherical_angles::<3>();
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    #[should_panic]
+    fn cannot_get_angles_of_1d_points() {
+        PointND::<f64, 1>::from([1.0]).hyperspherical_angles::<0>();
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn sampled_indices_match_weights() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(764);
+        let weights = PointND::from([1.0f32, 0.0, 2.0, 5.0]);
+        let mut counts = [0; 4];
+
+        const DRAWS: usize = 80_000;
+        for _ in 0..DRAWS {
+            counts[weights.sample_index(&mut rng).unwrap()] += 1;
+        }
+
+        assert_eq!(counts[1], 0);
+        for (count, weight) in counts.iter().zip(weights.iter()) {
+            let freq = *count as f32 / DRAWS as f32;
+            assert!((freq - weight / 8.0).abs() < 0.01, "{} != {}", freq, weight / 8.0);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn cannot_sample_zero_weights() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(764);
+        assert_eq!(PointND::<f64, 3>::fill(0.0).sample_index(&mut rng), None);
+        assert_eq!(PointND::<f32, 0>::from([]).sample_index(&mut rng), None);
+        assert_eq!(PointND::from([0.0f64, 0.0, 0.5]).sample_index(&mut rng), Some(2));
+    }
+
+    #[test]
+    #[cfg(all(feature = "rand", feature = "float-math"))]
+    fn random_unit_points_have_unit_magnitude() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(793);
+        for _ in 0..1000 {
+            let p = PointND::<f64, 5>::random_unit(&mut rng);
+            assert!((p.magnitude() - 1.0).abs() < 1e-12);
+
+            let p = PointND::<f32, 1>::random_unit(&mut rng);
+            assert!(p[0] == 1.0 || p[0] == -1.0);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "rand", feature = "float-math"))]
+    fn random_unit_points_are_uniform() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(793);
+        let mut octants = [0; 8];
+        let mut sum = PointND::<f64, 3>::zero();
+
+        const DRAWS: usize = 80_000;
+        for _ in 0..DRAWS {
+            let p = PointND::<f64, 3>::random_unit(&mut rng);
+            let octant = p.iter().enumerate().fold(0, |acc, (i, item)| acc | ((*item > 0.0) as usize) << i);
+            octants[octant] += 1;
+            sum.iter_mut().zip(p.iter()).for_each(|(s, item)| *s += item);
+        }
+
+        for count in octants {
+            let freq = count as f64 / DRAWS as f64;
+            assert!((freq - 0.125).abs() < 0.01, "{}", freq);
+        }
+        assert!(sum.magnitude() / (DRAWS as f64) < 0.01);
+    }
+
+    #[test]
+    #[cfg(all(feature = "rand", feature = "float-math"))]
+    #[should_panic]
+    fn cannot_get_zero_dimensional_random_unit() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        PointND::<f32, 0>::random_unit(&mut StdRng::seed_from_u64(793));
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn can_round_negative_fractions() {
+        let p = PointND::from([-0.5f64, -1.5, -2.7, -3.0, 0.5, 2.7]);
+
+        assert_eq!(p.floor().into_arr(), [-1.0, -2.0, -3.0, -3.0, 0.0, 2.0]);
+        assert_eq!(p.ceil().into_arr(), [-0.0, -1.0, -2.0, -3.0, 1.0, 3.0]);
+        assert_eq!(p.round().into_arr(), [-1.0, -2.0, -3.0, -3.0, 1.0, 3.0]);
+        assert_eq!(p.trunc().into_arr(), [-0.0, -1.0, -2.0, -3.0, 0.0, 2.0]);
+
+        let fract = p.fract();
+        assert_eq!(fract[..2], [-0.5, -0.5]);
+        assert!((fract[2] + 0.7).abs() < 1e-12);
+        assert_eq!(fract[3], 0.0);
+        assert!((fract[5] - 0.7).abs() < 1e-12);
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn rounding_keeps_nan_and_infinity() {
+        let p = PointND::from([f32::NAN, f32::INFINITY, f32::NEG_INFINITY]);
+        for rounded in [p.floor(), p.ceil(), p.round(), p.trunc()] {
+            assert!(rounded[0].is_nan());
+            assert_eq!(rounded[1..], [f32::INFINITY, f32::NEG_INFINITY]);
+        }
+        assert!(p.fract().iter().all(|item| item.is_nan()));
+    }
+
+    #[test]
+    #[cfg(all(feature = "float-math", feature = "num-traits"))]
+    fn can_round_to_ints() {
+        let p = PointND::from([-0.5f64, -1.5, 2.5, 1e20, f64::NAN]);
+        let tiles: PointND<i32, 5> = p.round_to_int();
+        assert_eq!(tiles.into_arr(), [-1, -2, 3, i32::MAX, 0]);
+
+        let tiles: PointND<u8, 2> = PointND::from([-3.0f32, 254.6]).round_to_int();
+        assert_eq!(tiles.into_arr(), [0, 255]);
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn can_snap_to_grids() {
+        let p = PointND::from([0.24f64, 0.26, -0.24, -0.26, 0.75, -0.75]);
+        assert_eq!(p.snap_to_grid(0.5).into_arr(), [0.0, 0.5, -0.0, -0.5, 1.0, -1.0]);
+
+        let p = PointND::from([1.0f32, 4.9, -4.9]);
+        let cells = PointND::from([1.0, 2.0, 10.0]);
+        assert_eq!(p.snap_to_grid_point(&cells).into_arr(), [1.0, 4.0, -0.0]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "float-math", feature = "num-traits"))]
+    fn quantizing_straddles_zero() {
+        let p = PointND::from([-0.5f64, 0.5, -1e-9, 0.0, -0.0]);
+        let cells: PointND<i32, 5> = p.quantize(1.0);
+        assert_eq!(cells.into_arr(), [-1, 0, -1, 0, 0]);
+
+        // Naive truncation puts both sides of zero in the same cell
+        assert_eq!([-0.5f64 as i32, 0.5f64 as i32], [0, 0]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "float-math", feature = "num-traits"))]
+    fn quantizing_at_cell_boundaries() {
+        let p = PointND::from([-4.0f32, -2.0, 0.0, 2.0, 4.0, 3.999]);
+        let cells: PointND<i16, 6> = p.quantize(2.0);
+        assert_eq!(cells.into_arr(), [-2, -1, 0, 1, 2, 1]);
+
+        let p = PointND::from([10.0f64, -10.0, 0.25]);
+        let sizes = PointND::from([10.0, 10.0, 0.125]);
+        let cells: PointND<i64, 3> = p.quantize_point(&sizes);
+        assert_eq!(cells.into_arr(), [1, -1, 2]);
+
+        let cells: PointND<i64, 3> = PointND::from([9.999f64, -9.999, 0.124]).quantize_point(&sizes);
+        assert_eq!(cells.into_arr(), [0, -1, 0]);
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn can_normalize_angles() {
+        use core::f64::consts::{PI, TAU};
+
+        let p = PointND::from([PI, -PI, 0.0, 1.0, PI + 1e-12, 10.0 * PI + 0.5, -10.0 * PI - 0.5]);
+        let arr = p.normalize_angles().into_arr();
+
+        assert_eq!(arr[..4], [PI, PI, 0.0, 1.0]);
+        assert!((arr[4] - (-PI + 1e-12)).abs() < 1e-15);
+        assert!((arr[5] - 0.5).abs() < 1e-12);
+        assert!((arr[6] + 0.5).abs() < 1e-12);
+
+        let arr = p.normalize_angles_positive().into_arr();
+        assert_eq!(arr[..4], [PI, PI, 0.0, 1.0]);
+        assert!((arr[4] - (PI + 1e-12)).abs() < 1e-15);
+        assert!((arr[5] - 0.5).abs() < 1e-12);
+        assert!((arr[6] - (TAU - 0.5)).abs() < 1e-12);
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn normalized_angles_are_within_range() {
+        use core::f32::consts::{PI, TAU};
+
+        for i in -2000..=2000 {
+            let angle = i as f32 * 0.0173;
+            let p = PointND::from([angle, -angle, angle * 1000.0]);
+
+            for wrapped in p.normalize_angles() {
+                assert!(-PI < wrapped && wrapped <= PI, "{} wrapped to {}", angle, wrapped);
+            }
+            for wrapped in p.normalize_angles_positive() {
+                assert!((0.0..TAU).contains(&wrapped), "{} wrapped to {}", angle, wrapped);
+            }
+        }
+
+        let p = PointND::from([-1e-30f32, -0.0]).normalize_angles_positive();
+        assert_eq!(p.into_arr(), [0.0, 0.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn angle_diffs_are_shortest() {
+        use core::f64::consts::PI;
+
+        let a = PointND::from([0.1, PI, -PI + 0.1, 10.0 * PI]);
+        let b = PointND::from([-0.1, -PI, PI - 0.1, 0.0]);
+        let diff = a.angle_diff(&b);
+
+        assert!((diff[0] - 0.2).abs() < 1e-12);
+        assert_eq!(diff[1], 0.0);
+        assert!((diff[2] - 0.2).abs() < 1e-12);
+        assert!(diff[3].abs() < 1e-12);
+
+        // Opposite angles are PI apart, never -PI
+        let diff = PointND::from([0.0, PI / 2.0]).angle_diff(&PointND::from([PI, -PI / 2.0]));
+        assert_eq!(diff.into_arr(), [PI, PI]);
+
+        for i in -100..100 {
+            for j in -100..100 {
+                let diff = PointND::from([i as f64 * 0.37]).angle_diff(&PointND::from([j as f64 * 0.41]));
+                assert!(-PI < diff[0] && diff[0] <= PI);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn can_get_lp_distance() {
+        let p1 = PointND::from([1.0f64, -1.0, 2.0]);
+        let p2 = PointND::from([3.0f64, 1.0, 3.0]);
+        assert_eq!(p1.lp_distance(&p2, 1.0), 5.0);
+        assert_eq!(p1.lp_distance(&p2, 2.0), p1.distance(&p2));
+        assert!((p1.lp_distance(&p2, 3.0) - 17.0f64.powf(1.0 / 3.0)).abs() < 1e-12);
+    }
+
+}
@@ -0,0 +1,774 @@
+use crate::PointND;
+
+///
+/// Iterator over every grid cell a line segment passes through, returned by the
+/// `supercover_line_to()` methods of integer `PointND`'s
+///
+/// Coordinates are tracked as `i128` internally so the differences between the endpoints
+/// of any (non 128 bit) integer type can't overflow. Every yielded cell lies between the
+/// endpoints, so converting back never truncates
+///
+struct SupercoverLine {
+    x: i128,
+    y: i128,
+    step_x: i128,
+    step_y: i128,
+    len_x: i128,
+    len_y: i128,
+    done_x: i128,
+    done_y: i128,
+    started: bool,
+    corner: Corner,
+}
+
+// Progress through the cells surrounding a corner the segment passes exactly through
+#[derive(Clone, Copy)]
+enum Corner {
+    None,
+    // The cell stepped to along x has been yielded, the one along y hasn't
+    YieldedX,
+    // Both side cells have been yielded, the diagonal hasn't
+    YieldedBoth,
+}
+
+impl SupercoverLine {
+
+    fn new(from: [i128; 2], to: [i128; 2]) -> Self {
+        let (dx, dy) = (to[0] - from[0], to[1] - from[1]);
+        SupercoverLine {
+            x: from[0],
+            y: from[1],
+            step_x: dx.signum(),
+            step_y: dy.signum(),
+            len_x: dx.abs(),
+            len_y: dy.abs(),
+            done_x: 0,
+            done_y: 0,
+            started: false,
+            corner: Corner::None,
+        }
+    }
+
+}
+
+impl Iterator for SupercoverLine {
+
+    type Item = [i128; 2];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return Some([self.x, self.y]);
+        }
+
+        match self.corner {
+            Corner::YieldedX => {
+                self.corner = Corner::YieldedBoth;
+                return Some([self.x, self.y + self.step_y]);
+            },
+            Corner::YieldedBoth => {
+                self.corner = Corner::None;
+                self.x += self.step_x;
+                self.y += self.step_y;
+                self.done_x += 1;
+                self.done_y += 1;
+                return Some([self.x, self.y]);
+            },
+            Corner::None => {}
+        }
+
+        if self.done_x >= self.len_x && self.done_y >= self.len_y {
+            return None;
+        }
+
+        // Compares where the segment crosses the next vertical and horizontal cell
+        //  boundaries, scaled up so only integers are needed
+        let decision = (1 + 2 * self.done_x) * self.len_y - (1 + 2 * self.done_y) * self.len_x;
+        if decision == 0 {
+            // Passes exactly through a corner, so both cells beside it are touched
+            self.corner = Corner::YieldedX;
+            Some([self.x + self.step_x, self.y])
+        } else if decision < 0 {
+            self.x += self.step_x;
+            self.done_x += 1;
+            Some([self.x, self.y])
+        } else {
+            self.y += self.step_y;
+            self.done_y += 1;
+            Some([self.x, self.y])
+        }
+    }
+
+}
+
+// Offsets of the 3^N - 1 neighbors surrounding a cell, counted like an odometer in base 3
+//  with the last dimension changing fastest, so neighbors are yielded in lexicographic order
+struct MooreOffsets<const N: usize> {
+    digits: [u8; N],
+    done: bool,
+}
+
+impl<const N: usize> MooreOffsets<N> {
+
+    fn new() -> Self {
+        MooreOffsets { digits: [0; N], done: false }
+    }
+
+}
+
+impl<const N: usize> Iterator for MooreOffsets<N> {
+
+    type Item = [i8; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let current = self.digits;
+
+            self.done = true;
+            for digit in self.digits.iter_mut().rev() {
+                if *digit < 2 {
+                    *digit += 1;
+                    self.done = false;
+                    break;
+                }
+                *digit = 0;
+            }
+
+            // The all zero offset is the cell itself, which isn't a neighbor
+            if current != [1; N] {
+                return Some(current.map(|digit| digit as i8 - 1));
+            }
+        }
+        None
+    }
+
+}
+
+// Returns the offsets of the 2N neighbors sharing a face with a cell, -1 then +1 for each
+//  dimension in turn
+fn orthogonal_offsets<const N: usize>() -> impl Iterator<Item = [i8; N]> {
+    (0..2 * N).map(|k| {
+        let mut offsets = [0; N];
+        offsets[k / 2] = if k % 2 == 0 { -1 } else { 1 };
+        offsets
+    })
+}
+
+// Implements neighbor iterators for points containing integers
+//
+// Kept in a macro as there is no trait in core for the checked methods of the integer types
+macro_rules! impl_neighbor_methods {
+    ($($t:ty),*) => {$(
+
+        ///
+        /// Methods for finding the neighbors of integer `PointND`'s on a grid
+        ///
+        impl<const N: usize> PointND<$t, N> {
+
+            ///
+            /// Returns an iterator over the `2N` points which differ from `self` by one in
+            /// exactly one dimension (_a.k.a_ - the von Neumann neighborhood)
+            ///
+            /// Neighbors are yielded one dimension at a time, with the neighbor one less than
+            /// `self` before the neighbor one greater
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from([5, 5]);")]
+            ///
+            /// let neighbors: Vec<_> = p.orthogonal_neighbors().map(|p| p.into_arr()).collect();
+            /// assert_eq!(neighbors, [[4, 5], [6, 5], [5, 4], [5, 6]]);
+            /// ```
+            ///
+            /// # Panics
+            ///
+            /// - When yielding a neighbor which would overflow the item type. Use
+            ///   `checked_orthogonal_neighbors()` to skip these neighbors instead
+            ///
+            pub fn orthogonal_neighbors(&self) -> impl Iterator<Item = Self> {
+                let p = *self;
+                orthogonal_offsets::<N>().map(move |offsets| p.expect_offset_by(&offsets))
+            }
+
+            ///
+            /// Returns an iterator over the `3^N - 1` points which differ from `self` by at most
+            /// one in every dimension, excluding `self` (_a.k.a_ - the Moore neighborhood)
+            ///
+            /// Neighbors are yielded in lexicographic order, the same as the `Ord` of `PointND`
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from([5, 5]);")]
+            ///
+            /// let neighbors: Vec<_> = p.moore_neighbors().map(|p| p.into_arr()).collect();
+            /// assert_eq!(neighbors, [
+            ///     [4, 4], [4, 5], [4, 6],
+            ///     [5, 4],         [5, 6],
+            ///     [6, 4], [6, 5], [6, 6],
+            /// ]);
+            /// ```
+            ///
+            /// # Panics
+            ///
+            /// - When yielding a neighbor which would overflow the item type. Use
+            ///   `checked_moore_neighbors()` to skip these neighbors instead
+            ///
+            pub fn moore_neighbors(&self) -> impl Iterator<Item = Self> {
+                let p = *self;
+                MooreOffsets::<N>::new().map(move |offsets| p.expect_offset_by(&offsets))
+            }
+
+            ///
+            /// Returns an iterator over the orthogonal neighbors of `self`, skipping any which
+            /// would overflow the item type
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from([0, 5]);")]
+            ///
+            /// let neighbors: Vec<_> = p.checked_orthogonal_neighbors().map(|p| p.into_arr()).collect();
+            #[doc = concat!("assert_eq!(neighbors.len(), if ", stringify!($t), "::MIN == 0 { 3 } else { 4 });")]
+            /// ```
+            ///
+            pub fn checked_orthogonal_neighbors(&self) -> impl Iterator<Item = Self> {
+                let p = *self;
+                orthogonal_offsets::<N>().filter_map(move |offsets| p.checked_offset_by(&offsets))
+            }
+
+            ///
+            /// Returns an iterator over the Moore neighbors of `self`, skipping any which would
+            /// overflow the item type
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from([", stringify!($t), "::MAX, 5]);")]
+            ///
+            /// assert_eq!(p.checked_moore_neighbors().count(), 5);
+            /// ```
+            ///
+            pub fn checked_moore_neighbors(&self) -> impl Iterator<Item = Self> {
+                let p = *self;
+                MooreOffsets::<N>::new().filter_map(move |offsets| p.checked_offset_by(&offsets))
+            }
+
+            ///
+            /// Returns an iterator over the orthogonal neighbors of `self` which are within the
+            /// box from `min` to `max` (inclusive)
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let min = PointND::<", stringify!($t), ", 2>::from([0, 0]);")]
+            /// let max = PointND::from([9, 9]);
+            ///
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from([9, 5]);")]
+            /// let neighbors: Vec<_> = p.orthogonal_neighbors_in_box(&min, &max).map(|p| p.into_arr()).collect();
+            /// assert_eq!(neighbors, [[8, 5], [9, 4], [9, 6]]);
+            /// ```
+            ///
+            pub fn orthogonal_neighbors_in_box(&self, min: &Self, max: &Self) -> impl Iterator<Item = Self> {
+                let (min, max) = (*min, *max);
+                self.checked_orthogonal_neighbors().filter(move |n| n.is_in_box(&min, &max))
+            }
+
+            ///
+            /// Returns an iterator over the Moore neighbors of `self` which are within the box
+            /// from `min` to `max` (inclusive)
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let min = PointND::<", stringify!($t), ", 2>::from([0, 0]);")]
+            /// let max = PointND::from([9, 9]);
+            ///
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from([9, 9]);")]
+            /// let neighbors: Vec<_> = p.moore_neighbors_in_box(&min, &max).map(|p| p.into_arr()).collect();
+            /// assert_eq!(neighbors, [[8, 8], [8, 9], [9, 8]]);
+            /// ```
+            ///
+            pub fn moore_neighbors_in_box(&self, min: &Self, max: &Self) -> impl Iterator<Item = Self> {
+                let (min, max) = (*min, *max);
+                self.checked_moore_neighbors().filter(move |n| n.is_in_box(&min, &max))
+            }
+
+            // Returns self moved by offsets, or None if any item would overflow
+            fn checked_offset_by(&self, offsets: &[i8; N]) -> Option<Self> {
+                let mut arr = **self;
+                for (item, offset) in arr.iter_mut().zip(offsets) {
+                    *item = match offset {
+                        -1 => item.checked_sub(1)?,
+                        1 => item.checked_add(1)?,
+                        _ => *item,
+                    };
+                }
+                Some(PointND::from(arr))
+            }
+
+            // Returns self moved by offsets, panicking if any item would overflow
+            fn expect_offset_by(&self, offsets: &[i8; N]) -> Self {
+                match self.checked_offset_by(offsets) {
+                    Some(p) => p,
+                    None => panic!("Attempted to find a neighbor of {:?} which overflows {}", self, stringify!($t)),
+                }
+            }
+
+            // Returns true if every item is within the respective items of min and max
+            fn is_in_box(&self, min: &Self, max: &Self) -> bool {
+                self.iter().zip(min.iter()).zip(max.iter()).all(|((item, l), h)| l <= item && item <= h)
+            }
+
+        }
+
+    )*};
+}
+
+impl_neighbor_methods!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+// Implements grid traversal methods for 2D points containing integers
+//
+// Kept in a macro as there is no trait in core for converting
+//  between the integer types and i128
+macro_rules! impl_grid_methods {
+    ($($t:ty),*) => {$(
+
+        ///
+        /// Methods for traversing grids with integer `PointND`'s
+        ///
+        impl PointND<$t, 2> {
+
+            ///
+            /// Returns an iterator over every cell the line segment from `self` to `other`
+            /// passes through, including both endpoints
+            ///
+            /// Each cell is treated as a unit square centered on its coordinates. Unlike
+            /// Bresenham's algorithm, which yields only one cell per step, this also yields
+            /// the cells which the segment only clips the corner of. If the segment passes
+            /// exactly through a corner, both cells beside the corner are yielded before the
+            /// diagonal cell. This makes it suitable for line of sight checks
+            ///
+            /// Does not allocate
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p1 = PointND::<", stringify!($t), ", 2>::from([0, 0]);")]
+            #[doc = concat!("let p2 = PointND::<", stringify!($t), ", 2>::from([2, 1]);")]
+            ///
+            /// let cells: Vec<_> = p1.supercover_line_to(&p2).map(|p| p.into_arr()).collect();
+            /// assert_eq!(cells, [[0, 0], [1, 0], [1, 1], [2, 1]]);
+            /// ```
+            ///
+            pub fn supercover_line_to(&self, other: &Self) -> impl Iterator<Item = Self> {
+                let from = [self[0] as i128, self[1] as i128];
+                let to = [other[0] as i128, other[1] as i128];
+                SupercoverLine::new(from, to)
+                    .map(|[x, y]| PointND::from([x as $t, y as $t]))
+            }
+
+        }
+
+    )*};
+}
+
+impl_grid_methods!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+// The largest item of a 3D point which fits in 21 bits of a morton code
+const MORTON_3D_MAX: u64 = (1 << 21) - 1;
+
+// Spreads the 32 bits of an item out to every other bit
+fn part_1_by_1(item: u64) -> u64 {
+    let mut x = item & 0xffff_ffff;
+    x = (x | x << 16) & 0x0000_ffff_0000_ffff;
+    x = (x | x << 8) & 0x00ff_00ff_00ff_00ff;
+    x = (x | x << 4) & 0x0f0f_0f0f_0f0f_0f0f;
+    x = (x | x << 2) & 0x3333_3333_3333_3333;
+    (x | x << 1) & 0x5555_5555_5555_5555
+}
+
+// Reverses part_1_by_1(), gathering every other bit back together
+fn compact_1_by_1(code: u64) -> u64 {
+    let mut x = code & 0x5555_5555_5555_5555;
+    x = (x | x >> 1) & 0x3333_3333_3333_3333;
+    x = (x | x >> 2) & 0x0f0f_0f0f_0f0f_0f0f;
+    x = (x | x >> 4) & 0x00ff_00ff_00ff_00ff;
+    x = (x | x >> 8) & 0x0000_ffff_0000_ffff;
+    (x | x >> 16) & 0xffff_ffff
+}
+
+// Spreads the 21 bits of an item out to every third bit
+fn part_1_by_2(item: u64) -> u64 {
+    let mut x = item & MORTON_3D_MAX;
+    x = (x | x << 32) & 0x001f_0000_0000_ffff;
+    x = (x | x << 16) & 0x001f_0000_ff00_00ff;
+    x = (x | x << 8) & 0x100f_00f0_0f00_f00f;
+    x = (x | x << 4) & 0x10c3_0c30_c30c_30c3;
+    (x | x << 2) & 0x1249_2492_4924_9249
+}
+
+// Reverses part_1_by_2(), gathering every third bit back together
+fn compact_1_by_2(code: u64) -> u64 {
+    let mut x = code & 0x1249_2492_4924_9249;
+    x = (x | x >> 2) & 0x10c3_0c30_c30c_30c3;
+    x = (x | x >> 4) & 0x100f_00f0_0f00_f00f;
+    x = (x | x >> 8) & 0x001f_0000_ff00_00ff;
+    x = (x | x >> 16) & 0x001f_0000_0000_ffff;
+    (x | x >> 32) & MORTON_3D_MAX
+}
+
+///
+/// Methods for encoding 2D `PointND`'s as morton (_a.k.a_ - Z-order) codes
+///
+impl PointND<u32, 2> {
+
+    ///
+    /// Returns the morton (_a.k.a_ - Z-order) code of `self`, made by interleaving the bits of
+    /// its items
+    ///
+    /// Bit `i` of `x` becomes bit `2i` of the code and bit `i` of `y` becomes bit `2i + 1`, so
+    /// both items use their full 32 bits. Sorting points by their codes keeps points which are
+    /// close together in space close together in order (_e.g._ - for quadtrees)
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::<u32, 2>::from([0b11, 0b01]);
+    /// assert_eq!(p.morton_encode(), 0b0111);
+    /// ```
+    ///
+    pub fn morton_encode(&self) -> u64 {
+        part_1_by_1(self[0] as u64) | part_1_by_1(self[1] as u64) << 1
+    }
+
+    ///
+    /// Returns the point encoded by the morton `code`, reversing `morton_encode()`
+    ///
+    /// Every `u64` is a valid code for 2D points
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::<u32, 2>::from_morton(0b0111);
+    /// assert_eq!(p.into_arr(), [0b11, 0b01]);
+    /// ```
+    ///
+    pub fn from_morton(code: u64) -> Self {
+        PointND::from([compact_1_by_1(code) as u32, compact_1_by_1(code >> 1) as u32])
+    }
+
+}
+
+// Implements morton encoding for 3D points of each unsigned integer type which
+//  (at least partly) fits in 21 bits
+macro_rules! impl_morton_3d {
+    ($($t:ty),*) => {$(
+
+        ///
+        /// Methods for encoding 3D `PointND`'s as morton (_a.k.a_ - Z-order) codes
+        ///
+        impl PointND<$t, 3> {
+
+            ///
+            /// Returns the morton (_a.k.a_ - Z-order) code of `self`, made by interleaving the
+            /// bits of its items
+            ///
+            /// Bit `i` of `x` becomes bit `3i` of the code, bit `i` of `y` becomes bit `3i + 1`
+            /// and bit `i` of `z` becomes bit `3i + 2`. Only 21 bits are available for each item,
+            /// giving codes of up to 63 bits. Sorting points by their codes keeps points which are
+            /// close together in space close together in order (_e.g._ - for octrees)
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 3>::from([0b1, 0b0, 0b1]);")]
+            /// assert_eq!(p.morton_encode(), 0b101);
+            /// ```
+            ///
+            /// # Panics
+            ///
+            /// - If any item is greater than `2^21 - 1` (_i.e._ - needs more than 21 bits)
+            ///
+            #[allow(clippy::unnecessary_cast)]
+            pub fn morton_encode(&self) -> u64 {
+                if let Some(i) = self.iter().position(|item| *item as u64 > MORTON_3D_MAX) {
+                    panic!("Attempted to morton encode a 3D PointND with item {} at dimension {}, which is more than the 21 bits available",
+                           self[i], i);
+                }
+                part_1_by_2(self[0] as u64) | part_1_by_2(self[1] as u64) << 1 | part_1_by_2(self[2] as u64) << 2
+            }
+
+            ///
+            /// Returns the point encoded by the morton `code`, reversing `morton_encode()`
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 3>::from_morton(0b101);")]
+            /// assert_eq!(p.into_arr(), [0b1, 0b0, 0b1]);
+            /// ```
+            ///
+            /// # Panics
+            ///
+            #[doc = concat!(" - If the code decodes to an item greater than `", stringify!($t), "::MAX`, or uses")]
+            ///   the 64th bit (which is never set by `morton_encode()`)
+            ///
+            pub fn from_morton(code: u64) -> Self {
+                let arr = [compact_1_by_2(code), compact_1_by_2(code >> 1), compact_1_by_2(code >> 2)];
+                if code >> 63 != 0 || arr.iter().any(|item| *item > <$t>::MAX as u64) {
+                    panic!("Attempted to decode morton code {:#x} into a 3D PointND of {}, which is out of range",
+                           code, stringify!($t));
+                }
+                PointND::from(arr.map(|item| item as $t))
+            }
+
+        }
+
+    )*};
+}
+
+impl_morton_3d!(u16, u32);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    fn supercover(from: [i32; 2], to: [i32; 2]) -> Vec<[i32; 2]> {
+        PointND::from(from)
+            .supercover_line_to(&PointND::from(to))
+            .map(PointND::into_arr)
+            .collect()
+    }
+
+    // Reference implementation to compare against
+    fn bresenham(from: [i32; 2], to: [i32; 2]) -> Vec<[i32; 2]> {
+        let (dx, dy) = ((to[0] - from[0]).abs(), -(to[1] - from[1]).abs());
+        let (sx, sy) = ((to[0] - from[0]).signum(), (to[1] - from[1]).signum());
+        let (mut x, mut y, mut err) = (from[0], from[1], dx + dy);
+
+        let mut cells = Vec::new();
+        loop {
+            cells.push([x, y]);
+            if [x, y] == to {
+                return cells;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy { err += dy; x += sx; }
+            if e2 <= dx { err += dx; y += sy; }
+        }
+    }
+
+    #[test]
+    fn single_cell_line_yields_itself() {
+        assert_eq!(supercover([3, -4], [3, -4]), [[3, -4]]);
+    }
+
+    #[test]
+    fn axis_aligned_lines_match_bresenham() {
+        for (from, to) in [
+            ([0, 0], [5, 0]),
+            ([0, 0], [-5, 0]),
+            ([2, 1], [2, 7]),
+            ([2, 1], [2, -7]),
+        ] {
+            assert_eq!(supercover(from, to), bresenham(from, to));
+        }
+    }
+
+    #[test]
+    fn diagonal_lines_include_corner_touching_cells() {
+        assert_eq!(
+            supercover([0, 0], [2, 2]),
+            [[0, 0], [1, 0], [0, 1], [1, 1], [2, 1], [1, 2], [2, 2]]
+        );
+        assert_eq!(
+            supercover([0, 0], [-1, 1]),
+            [[0, 0], [-1, 0], [0, 1], [-1, 1]]
+        );
+
+        // Bresenham skips the cells beside each corner
+        assert_eq!(bresenham([0, 0], [2, 2]), [[0, 0], [1, 1], [2, 2]]);
+    }
+
+    #[test]
+    fn shallow_lines_include_clipped_cells() {
+        // Passes through the corner at (1.5, 0.5)
+        assert_eq!(
+            supercover([0, 0], [3, 1]),
+            [[0, 0], [1, 0], [2, 0], [1, 1], [2, 1], [3, 1]]
+        );
+        assert_eq!(
+            supercover([0, 0], [5, 2]),
+            [[0, 0], [1, 0], [1, 1], [2, 1], [3, 1], [4, 1], [4, 2], [5, 2]]
+        );
+    }
+
+    #[test]
+    fn reversed_lines_cover_the_same_cells() {
+        for (from, to) in [([0, 0], [5, 2]), ([-3, 4], [6, -1]), ([1, 1], [4, 4])] {
+            let mut forward = supercover(from, to);
+            let mut backward = supercover(to, from);
+            forward.sort();
+            backward.sort();
+            assert_eq!(forward, backward);
+        }
+    }
+
+    #[test]
+    fn has_correct_neighbor_counts() {
+        let p = PointND::from([0i32]);
+        assert_eq!(p.orthogonal_neighbors().count(), 2);
+        assert_eq!(p.moore_neighbors().count(), 2);
+
+        let p = PointND::from([0i32, 0]);
+        assert_eq!(p.orthogonal_neighbors().count(), 4);
+        assert_eq!(p.moore_neighbors().count(), 8);
+
+        let p = PointND::from([0i32, 0, 0]);
+        assert_eq!(p.orthogonal_neighbors().count(), 6);
+        assert_eq!(p.moore_neighbors().count(), 26);
+
+        let p = PointND::<i32, 0>::from([]);
+        assert_eq!(p.orthogonal_neighbors().count(), 0);
+        assert_eq!(p.moore_neighbors().count(), 0);
+    }
+
+    #[test]
+    fn moore_neighbors_are_unique_and_adjacent() {
+        let p = PointND::from([10i64, -3, 7, 0]);
+        let neighbors: Vec<_> = p.moore_neighbors().collect();
+        assert_eq!(neighbors.len(), 80);
+
+        for (i, n) in neighbors.iter().enumerate() {
+            assert_ne!(*n, p);
+            assert!(n.iter().zip(p.iter()).all(|(a, b)| (a - b).abs() <= 1));
+            // Lexicographic order also means there are no duplicates
+            if i > 0 {
+                assert!(neighbors[i - 1] < *n);
+            }
+        }
+    }
+
+    #[test]
+    fn orthogonal_neighbors_differ_in_one_dimension() {
+        let p = PointND::from([1u16, 2, 3]);
+        let neighbors: Vec<_> = p.orthogonal_neighbors().map(PointND::into_arr).collect();
+        assert_eq!(neighbors, [[0, 2, 3], [2, 2, 3], [1, 1, 3], [1, 3, 3], [1, 2, 2], [1, 2, 4]]);
+    }
+
+    #[test]
+    fn checked_neighbors_are_clipped_at_zero() {
+        let p = PointND::from([0u8, 0]);
+        let neighbors: Vec<_> = p.checked_orthogonal_neighbors().map(PointND::into_arr).collect();
+        assert_eq!(neighbors, [[1, 0], [0, 1]]);
+        let neighbors: Vec<_> = p.checked_moore_neighbors().map(PointND::into_arr).collect();
+        assert_eq!(neighbors, [[0, 1], [1, 0], [1, 1]]);
+
+        let p = PointND::from([0u8, 5, u8::MAX]);
+        assert_eq!(p.checked_orthogonal_neighbors().count(), 4);
+        assert_eq!(p.checked_moore_neighbors().count(), 2 * 3 * 2 - 1);
+    }
+
+    #[test]
+    fn box_neighbors_are_clipped_at_box() {
+        let min = PointND::from([-2i32, -2, -2]);
+        let max = PointND::from([2, 2, 2]);
+
+        let p = PointND::<i32, 3>::from([0, 0, 0]);
+        assert_eq!(p.orthogonal_neighbors_in_box(&min, &max).count(), 6);
+        assert_eq!(p.moore_neighbors_in_box(&min, &max).count(), 26);
+
+        let p = PointND::<i32, 3>::from([-2, 2, 0]);
+        assert_eq!(p.orthogonal_neighbors_in_box(&min, &max).count(), 4);
+        assert_eq!(p.moore_neighbors_in_box(&min, &max).count(), 2 * 2 * 3 - 1);
+        assert!(p.moore_neighbors_in_box(&min, &max).all(|n| n.iter().all(|item| (-2..=2).contains(item))));
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_find_neighbors_which_overflow() {
+        PointND::from([5u8, 0]).orthogonal_neighbors().for_each(drop);
+    }
+
+    // Reference implementation to compare against, interleaving one bit at a time
+    fn naive_morton(arr: &[u64]) -> u64 {
+        let mut code = 0;
+        for bit in 0..64 / arr.len() {
+            for (i, item) in arr.iter().enumerate() {
+                code |= (item >> bit & 1) << (bit * arr.len() + i);
+            }
+        }
+        code
+    }
+
+    #[test]
+    fn morton_2d_round_trips() {
+        for x in 0..256 {
+            for y in 0..256 {
+                let p = PointND::<u32, 2>::from([x, y]);
+                let code = p.morton_encode();
+                assert_eq!(code, naive_morton(&[x as u64, y as u64]));
+                assert_eq!(PointND::<u32, 2>::from_morton(code), p);
+            }
+        }
+    }
+
+    #[test]
+    fn morton_2d_works_at_max() {
+        let p = PointND::<u32, 2>::from([u32::MAX, u32::MAX]);
+        assert_eq!(p.morton_encode(), u64::MAX);
+        assert_eq!(PointND::<u32, 2>::from_morton(u64::MAX), p);
+
+        let p = PointND::<u32, 2>::from([u32::MAX, 0]);
+        assert_eq!(p.morton_encode(), 0x5555_5555_5555_5555);
+        assert_eq!(PointND::<u32, 2>::from_morton(0xaaaa_aaaa_aaaa_aaaa).into_arr(), [0, u32::MAX]);
+    }
+
+    #[test]
+    fn morton_3d_round_trips() {
+        for x in 0..40 {
+            for y in 0..40 {
+                for z in 0..40 {
+                    let p = PointND::<u16, 3>::from([x, y, z]);
+                    let code = p.morton_encode();
+                    assert_eq!(code, naive_morton(&[x as u64, y as u64, z as u64]));
+                    assert_eq!(PointND::<u16, 3>::from_morton(code), p);
+
+                    let p = PointND::<u32, 3>::from([x as u32 * 1000, y as u32 * 50000, z as u32]);
+                    assert_eq!(PointND::<u32, 3>::from_morton(p.morton_encode()), p);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn morton_3d_works_at_max() {
+        let max = (1 << 21) - 1;
+        let p = PointND::<u32, 3>::from([max, max, max]);
+        assert_eq!(p.morton_encode(), u64::MAX >> 1);
+        assert_eq!(PointND::<u32, 3>::from_morton(u64::MAX >> 1), p);
+
+        let p = PointND::<u16, 3>::from([u16::MAX, 0, u16::MAX]);
+        assert_eq!(PointND::<u16, 3>::from_morton(p.morton_encode()), p);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_morton_encode_more_than_21_bits() {
+        PointND::<u32, 3>::from([0, 1 << 21, 0]).morton_encode();
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_decode_morton_codes_out_of_range() {
+        PointND::<u16, 3>::from_morton(1 << 48);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_decode_morton_codes_using_64th_bit() {
+        PointND::<u32, 3>::from_morton(1 << 63);
+    }
+
+    #[test]
+    fn works_at_integer_extremes() {
+        let p1 = PointND::from([u8::MAX, 0]);
+        let p2 = PointND::from([u8::MAX - 2, 0]);
+        let cells: Vec<_> = p1.supercover_line_to(&p2).map(PointND::into_arr).collect();
+        assert_eq!(cells, [[255, 0], [254, 0], [253, 0]]);
+    }
+
+}
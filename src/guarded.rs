@@ -0,0 +1,291 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::Deref;
+
+#[cfg(feature = "float-math")]
+use libm::Libm;
+
+use crate::PointND;
+use crate::error::{NonFiniteError, ZeroPointError};
+
+/**
+A `PointND` which is guaranteed to contain at least one non-zero item.
+
+Zero is the `Default` value of the contained type. Useful for upholding invariants such as
+"a direction must not be the zero vector", as methods relying on a non-zero point (such as
+`normalize()`) no longer have to handle the zero case.
+
+As `NonZeroPoint` dereferences to a `PointND`, all of its read accessors are available. It
+cannot be mutably dereferenced, as this could be used to set every item to zero.
+
+```
+# use point_nd::{PointND, NonZeroPoint};
+use core::convert::TryFrom;
+
+let dir = NonZeroPoint::try_from(PointND::from([0, 3])).unwrap();
+assert_eq!(dir.dims(), 2);
+
+let zero = NonZeroPoint::try_from(PointND::from([0, 0]));
+assert!(zero.is_err());
+```
+*/
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct NonZeroPoint<T, const N: usize>(PointND<T, N>);
+
+impl<T, const N: usize> NonZeroPoint<T, N> {
+
+    /// Returns a reference to the contained point
+    pub fn get(&self) -> &PointND<T, N> {
+        &self.0
+    }
+
+    /// Consumes `self`, returning the contained point
+    pub fn into_point(self) -> PointND<T, N> {
+        self.0
+    }
+
+}
+
+impl<T, const N: usize> TryFrom<PointND<T, N>> for NonZeroPoint<T, N>
+    where T: Default + PartialEq {
+
+    type Error = ZeroPointError;
+    fn try_from(point: PointND<T, N>) -> Result<Self, Self::Error> {
+
+        let zero = T::default();
+        if point.iter().all(|item| *item == zero) {
+            return Err(ZeroPointError);
+        }
+        Ok(NonZeroPoint(point))
+    }
+
+}
+
+impl<T, const N: usize> Deref for NonZeroPoint<T, N> {
+
+    type Target = PointND<T, N>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for NonZeroPoint<T, N> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+
+}
+
+/**
+A floating point `PointND` which is guaranteed to contain no `NaN` or infinite items.
+
+As none of the items can be `NaN`, every pair of items can be compared. This means
+`FinitePoint` implements `Eq` and the `PointND` methods which skip incomparable items
+(such as `argmin()` and `component_min()`) behave as a total ordering would.
+
+As with `NonZeroPoint`, it dereferences to a `PointND` but cannot be mutably dereferenced.
+
+```
+# use point_nd::{PointND, FinitePoint};
+use core::convert::TryFrom;
+
+let p = FinitePoint::try_from(PointND::from([1.0, -2.5])).unwrap();
+assert_eq!(p.argmin(), Some((1, &-2.5)));
+
+let err = FinitePoint::try_from(PointND::from([1.0, f64::NAN])).unwrap_err();
+assert_eq!(err.index, 1);
+```
+*/
+#[derive(Clone, Copy, PartialEq)]
+pub struct FinitePoint<T, const N: usize>(PointND<T, N>);
+
+impl<T, const N: usize> FinitePoint<T, N> {
+
+    /// Returns a reference to the contained point
+    pub fn get(&self) -> &PointND<T, N> {
+        &self.0
+    }
+
+    /// Consumes `self`, returning the contained point
+    pub fn into_point(self) -> PointND<T, N> {
+        self.0
+    }
+
+}
+
+impl<T, const N: usize> Deref for FinitePoint<T, N> {
+
+    type Target = PointND<T, N>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for FinitePoint<T, N> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+
+}
+
+// Implements the float specific parts of the guarded points
+//
+// Kept in a macro as is_finite() and the libm functions used by
+//  normalize() are only provided for concrete float types
+macro_rules! impl_float_guards {
+    ($($t:ty),*) => {$(
+
+        impl<const N: usize> TryFrom<PointND<$t, N>> for FinitePoint<$t, N> {
+
+            type Error = NonFiniteError;
+            fn try_from(point: PointND<$t, N>) -> Result<Self, Self::Error> {
+
+                match point.iter().position(|item| !item.is_finite()) {
+                    Some(index) => Err(NonFiniteError { index }),
+                    None => Ok(FinitePoint(point)),
+                }
+            }
+
+        }
+
+        // Only finite values are contained, so equality is reflexive
+        impl<const N: usize> Eq for FinitePoint<$t, N> {}
+
+        ///
+        /// Methods for computing directions of non-zero floating point points
+        ///
+        /// # Enabled by features:
+        ///
+        /// - `float-math`
+        ///
+        #[cfg(feature = "float-math")]
+        impl<const N: usize> NonZeroPoint<$t, N> {
+
+            ///
+            /// Consumes `self` and returns a new point with the same direction and a magnitude
+            /// of `1.0`
+            ///
+            /// Unlike `normalize()` in `PointND`, this cannot fail as the point is never the
+            /// zero vector. Points too small to have their magnitude represented (_e.g._ -
+            /// `[1e-200, 0.0]`) are scaled up beforehand, so are normalized correctly too
+            ///
+            /// Any `NaN` or infinite values will result in a point containing `NaN` values
+            ///
+            /// ```
+            /// # use point_nd::{PointND, NonZeroPoint};
+            /// use core::convert::TryFrom;
+            ///
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from([0.0, -4.0]);")]
+            /// let dir = NonZeroPoint::try_from(p).unwrap();
+            /// assert_eq!(dir.normalize().into_arr(), [0.0, -1.0]);
+            /// ```
+            ///
+            pub fn normalize(self) -> PointND<$t, N> {
+                let scale = self.0.iter()
+                    .map(|item| Libm::<$t>::fabs(*item))
+                    .fold(0.0, |max: $t, item| if item > max || item.is_nan() { item } else { max });
+
+                let mut arr = self.0.into_arr();
+                for item in arr.iter_mut() {
+                    *item /= scale;
+                }
+
+                // At least one item is now 1.0 (or NaN), so the magnitude can't be zero
+                PointND::from(arr)
+                    .normalize()
+                    .expect("the magnitude of a NonZeroPoint should never be zero")
+            }
+
+        }
+
+    )*};
+}
+
+impl_float_guards!(f32, f64);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::format;
+
+    #[test]
+    fn can_create_non_zero_points() {
+        let p = NonZeroPoint::try_from(PointND::from([0, 0, -1])).unwrap();
+        assert_eq!(**p.get(), [0, 0, -1]);
+        assert_eq!(p.into_point().into_arr(), [0, 0, -1]);
+
+        let p = NonZeroPoint::try_from(PointND::from([f64::NAN])).unwrap();
+        assert_eq!(p.dims(), 1);
+    }
+
+    #[test]
+    fn cannot_create_zero_points() {
+        assert_eq!(NonZeroPoint::try_from(PointND::from([0, 0])), Err(ZeroPointError));
+        assert_eq!(NonZeroPoint::try_from(PointND::from([0.0, -0.0])), Err(ZeroPointError));
+        assert_eq!(NonZeroPoint::<u8, 0>::try_from(PointND::from([])), Err(ZeroPointError));
+    }
+
+    #[test]
+    fn can_create_finite_points() {
+        let p = FinitePoint::try_from(PointND::from([0.0f32, -1.5, f32::MAX])).unwrap();
+        assert_eq!(**p.get(), [0.0, -1.5, f32::MAX]);
+        assert_eq!(p.clone(), p);
+
+        let p = FinitePoint::<f64, 0>::try_from(PointND::from([])).unwrap();
+        assert_eq!(p.into_point().dims(), 0);
+    }
+
+    #[test]
+    fn cannot_create_non_finite_points() {
+        let err = FinitePoint::try_from(PointND::from([0.0, f64::INFINITY, f64::NAN]));
+        assert_eq!(err, Err(NonFiniteError { index: 1 }));
+
+        let err = FinitePoint::try_from(PointND::from([f32::NAN]));
+        assert_eq!(err, Err(NonFiniteError { index: 0 }));
+
+        let err = FinitePoint::try_from(PointND::from([1.0, f64::NEG_INFINITY]));
+        assert_eq!(err, Err(NonFiniteError { index: 1 }));
+    }
+
+    #[test]
+    fn debug_is_passed_through() {
+        let p = PointND::from([1.0, 2.0]);
+        let non_zero = NonZeroPoint::try_from(p).unwrap();
+        let finite = FinitePoint::try_from(p).unwrap();
+
+        assert_eq!(format!("{:?}", non_zero), format!("{:?}", p));
+        assert_eq!(format!("{:#?}", finite), format!("{:#?}", p));
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn can_always_normalize_non_zero_points() {
+        let p = NonZeroPoint::try_from(PointND::from([3.0f64, 4.0])).unwrap();
+        assert_eq!(p.normalize().into_arr(), [0.6, 0.8]);
+
+        // Would underflow to a magnitude of zero without scaling
+        let tiny = PointND::from([1e-300f64, 0.0]);
+        assert_eq!(tiny.normalize(), None);
+        let p = NonZeroPoint::try_from(tiny).unwrap();
+        assert_eq!(p.normalize().into_arr(), [1.0, 0.0]);
+
+        let p = NonZeroPoint::try_from(PointND::from([0.0f32, f32::MIN_POSITIVE, 0.0])).unwrap();
+        assert_eq!(p.normalize().into_arr(), [0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn normalizing_non_finite_non_zero_points_gives_nan() {
+        let p = NonZeroPoint::try_from(PointND::from([f64::NAN, 1.0])).unwrap();
+        assert!(p.normalize().iter().any(|item| item.is_nan()));
+
+        let p = NonZeroPoint::try_from(PointND::from([f64::INFINITY, 1.0])).unwrap();
+        assert!(p.normalize().iter().any(|item| item.is_nan()));
+    }
+
+}
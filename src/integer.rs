@@ -0,0 +1,725 @@
+use core::cmp::Ordering;
+
+use crate::PointND;
+
+// Squared distances are summed into 256 bits, as (high, low) halves, so they
+//  are exact no matter how many dimensions the points have
+type WideSum = (u128, u128);
+
+// Returns the exact squared euclidean distance between two points of widened items
+fn wide_dist_sq<const N: usize>(a: [i128; N], b: [i128; N]) -> WideSum {
+    a.iter().zip(b.iter()).fold((0, 0), |(high, low), (a, b)| {
+        // Items are at most 64 bits, so the absolute difference fits
+        //  in 64 bits and its square in 128
+        let diff = (a - b).unsigned_abs();
+        let (low, carry) = low.overflowing_add(diff * diff);
+        (high + carry as u128, low)
+    })
+}
+
+// Implements exact distance comparisons, averages and box stepping for points containing integers
+//
+// Kept in a macro as there is no trait in core for converting
+//  between the integer types and i128
+macro_rules! impl_integer_methods {
+    ($($t:ty),*) => {$(
+
+        ///
+        /// Methods for exactly comparing distances between, averaging and stepping through
+        /// boxes of integer `PointND`'s
+        ///
+        impl<const N: usize> PointND<$t, N> {
+
+            ///
+            /// Compares the squared euclidean distances from `origin` to `a` and from `origin` to `b`
+            ///
+            /// The distances are computed in a wider integer type, so they never overflow and are
+            /// never rounded. This avoids misordering nearly equal distances, as can happen when
+            /// converting to floats first
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            /// use core::cmp::Ordering;
+            ///
+            #[doc = concat!("let origin = PointND::<", stringify!($t), ", 2>::from([0, 0]);")]
+            /// let a = PointND::from([3, 4]);
+            /// let b = PointND::from([5, 0]);
+            #[doc = concat!("let cmp = PointND::<", stringify!($t), ", 2>::dist_sq_cmp(&a, &b, &origin);")]
+            /// assert_eq!(cmp, Ordering::Equal);
+            /// ```
+            ///
+            pub fn dist_sq_cmp(a: &Self, b: &Self, origin: &Self) -> Ordering {
+                let origin = (**origin).map(|item| item as i128);
+                let dist_a = wide_dist_sq((**a).map(|item| item as i128), origin);
+                let dist_b = wide_dist_sq((**b).map(|item| item as i128), origin);
+                dist_a.cmp(&dist_b)
+            }
+
+            ///
+            /// Returns `true` if the euclidean distance between `self` and `other` is less than
+            /// or equal to `radius`
+            ///
+            /// The comparison is made between squared distances in a wider integer type, so it
+            /// never overflows and is exact. Negative radii are never within any distance
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p1 = PointND::<", stringify!($t), ", 2>::from([1, 1]);")]
+            /// let p2 = PointND::from([4, 5]);
+            /// assert!(p1.within_distance(&p2, 5));
+            /// assert!(!p1.within_distance(&p2, 4));
+            /// ```
+            ///
+            #[allow(unused_comparisons)]
+            pub fn within_distance(&self, other: &Self, radius: $t) -> bool {
+                if radius < 0 {
+                    return false;
+                }
+                let radius = radius as u128;
+                let dist = wide_dist_sq((**self).map(|item| item as i128), (**other).map(|item| item as i128));
+                dist <= (0, radius * radius)
+            }
+
+            ///
+            /// Returns the point halfway between `self` and `other`
+            ///
+            /// Each item is computed in a wider integer type, so it never overflows, even for
+            /// items near the limits of the type. Halves are rounded towards negative infinity
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p1 = PointND::<", stringify!($t), ", 2>::from([", stringify!($t), "::MAX, 1]);")]
+            #[doc = concat!("let p2 = PointND::from([", stringify!($t), "::MAX - 2, 4]);")]
+            #[doc = concat!("assert_eq!(p1.midpoint_exact(&p2).into_arr(), [", stringify!($t), "::MAX - 1, 2]);")]
+            /// ```
+            ///
+            pub fn midpoint_exact(&self, other: &Self) -> Self {
+                PointND::from_fn(|i| (self[i] as i128 + other[i] as i128).div_euclid(2) as $t)
+            }
+
+            ///
+            /// Returns the componentwise mean of `points`, or `None` if there are no points
+            ///
+            /// The items are summed in a wider integer type, so the sum never overflows. Each
+            /// mean is rounded towards negative infinity
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            /// let points = [PointND::from([1, 10]), PointND::from([2, 20]), PointND::from([4, 30])];
+            #[doc = concat!("let mean = PointND::<", stringify!($t), ", 2>::mean_exact(&points).unwrap();")]
+            /// assert_eq!(mean.into_arr(), [2, 20]);
+            /// ```
+            ///
+            pub fn mean_exact(points: &[Self]) -> Option<Self> {
+                if points.is_empty() {
+                    return None;
+                }
+                let mut sums = [0i128; N];
+                for point in points {
+                    for (sum, item) in sums.iter_mut().zip(point.iter()) {
+                        *sum += *item as i128;
+                    }
+                }
+                // The mean lies between the smallest and largest items, so always fits
+                let count = points.len() as i128;
+                Some(PointND::from(sums.map(|sum| sum.div_euclid(count) as $t)))
+            }
+
+            ///
+            /// Returns the lexicographic successor of `self` within the box from `min` to `max`
+            /// (inclusive), or `None` if `self` is the last point of the box
+            ///
+            /// Points are stepped through like an odometer. The last item is incremented, and when
+            /// it passes `max` it wraps back to `min` and the item before it is incremented, and so
+            /// on. This is the same order as the lexicographic `Ord` of `PointND`, so a sweep over
+            /// the box can be stopped and resumed from any point
+            ///
+            /// `None` is also returned if `self` is outside the box
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let min = PointND::<", stringify!($t), ", 2>::from([0, 0]);")]
+            /// let max = PointND::from([1, 2]);
+            ///
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from([0, 2]);")]
+            /// assert_eq!(p.next_in_box(&min, &max), Some(PointND::from([1, 0])));
+            /// assert_eq!(max.next_in_box(&min, &max), None);
+            /// ```
+            ///
+            pub fn next_in_box(&self, min: &Self, max: &Self) -> Option<Self> {
+                if !self.in_box(min, max) {
+                    return None;
+                }
+                let mut arr = **self;
+                for i in (0..N).rev() {
+                    if arr[i] < max[i] {
+                        arr[i] += 1;
+                        return Some(PointND::from(arr));
+                    }
+                    arr[i] = min[i];
+                }
+                None
+            }
+
+            ///
+            /// Returns the lexicographic predecessor of `self` within the box from `min` to `max`
+            /// (inclusive), or `None` if `self` is the first point of the box
+            ///
+            /// This reverses `next_in_box()`, and `None` is also returned if `self` is outside
+            /// the box
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let min = PointND::<", stringify!($t), ", 2>::from([0, 0]);")]
+            /// let max = PointND::from([1, 2]);
+            ///
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from([1, 0]);")]
+            /// assert_eq!(p.prev_in_box(&min, &max), Some(PointND::from([0, 2])));
+            /// assert_eq!(min.prev_in_box(&min, &max), None);
+            /// ```
+            ///
+            pub fn prev_in_box(&self, min: &Self, max: &Self) -> Option<Self> {
+                if !self.in_box(min, max) {
+                    return None;
+                }
+                let mut arr = **self;
+                for i in (0..N).rev() {
+                    if arr[i] > min[i] {
+                        arr[i] -= 1;
+                        return Some(PointND::from(arr));
+                    }
+                    arr[i] = max[i];
+                }
+                None
+            }
+
+            // Returns true if every item is within the respective items of min and max
+            fn in_box(&self, min: &Self, max: &Self) -> bool {
+                self.iter().zip(min.iter()).zip(max.iter()).all(|((item, l), h)| l <= item && item <= h)
+            }
+
+        }
+
+    )*};
+}
+
+impl_integer_methods!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+// Implements conversions to and from normalized floats (e.g. for colors) for points
+//  containing unsigned integers
+macro_rules! impl_normalized_methods {
+    ($($t:ty),*) => {$(
+
+        ///
+        /// Methods for converting unsigned integer `PointND`'s to and from normalized floats
+        ///
+        impl<const N: usize> PointND<$t, N> {
+
+            ///
+            #[doc = concat!(" Consumes `self` and returns a new point with each item divided by `", stringify!($t), "::MAX`,")]
+            /// so that all items are within `[0.0, 1.0]`
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let rgba = PointND::<", stringify!($t), ", 4>::from([0, ", stringify!($t), "::MAX, 0, ", stringify!($t), "::MAX]);")]
+            /// assert_eq!(rgba.to_normalized_f32().into_arr(), [0.0, 1.0, 0.0, 1.0]);
+            /// ```
+            ///
+            pub fn to_normalized_f32(self) -> PointND<f32, N> {
+                PointND::from(self.into_arr().map(|item| item as f32 / <$t>::MAX as f32))
+            }
+
+            ///
+            #[doc = concat!(" Returns a new point with each item of `p` multiplied by `", stringify!($t), "::MAX`, reversing")]
+            /// `to_normalized_f32()`
+            ///
+            /// Items are clamped to `[0.0, 1.0]` beforehand and `NaN` items become zero. Halves are
+            /// rounded up
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            /// let p = PointND::from([0.0, 1.0, 2.0, -1.0]);
+            #[doc = concat!("let rgba = PointND::<", stringify!($t), ", 4>::from_normalized_f32(p);")]
+            #[doc = concat!("assert_eq!(rgba.into_arr(), [0, ", stringify!($t), "::MAX, ", stringify!($t), "::MAX, 0]);")]
+            /// ```
+            ///
+            pub fn from_normalized_f32(p: PointND<f32, N>) -> Self {
+                PointND::from(p.into_arr().map(|item| {
+                    // Clamps NaN to zero too, as the comparison fails
+                    let item = if item > 0.0 { item.min(1.0) } else { 0.0 };
+                    // Items are non-negative, so truncating rounds down
+                    (item * <$t>::MAX as f32 + 0.5) as $t
+                }))
+            }
+
+        }
+
+    )*};
+}
+
+impl_normalized_methods!(u8, u16);
+
+// Implements overflow aware addition and subtraction for points containing integers
+//
+// Kept in a macro as there is no trait in core for the checked, saturating and wrapping
+//  methods of the integer types
+macro_rules! impl_overflow_methods {
+    ($($t:ty),*) => {$(
+
+        ///
+        /// Methods for adding and subtracting integer `PointND`'s near the bounds of their type
+        ///
+        impl<const N: usize> PointND<$t, N> {
+
+            ///
+            /// Returns the sum of the respective items of `self` and `other`, or `None` if any
+            /// item overflows
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from([1, ", stringify!($t), "::MAX]);")]
+            #[doc = concat!("assert_eq!(p.checked_add_point(&PointND::from([1, 0])), Some(PointND::from([2, ", stringify!($t), "::MAX])));")]
+            /// assert_eq!(p.checked_add_point(&PointND::from([0, 1])), None);
+            /// ```
+            ///
+            pub fn checked_add_point(&self, other: &Self) -> Option<Self> {
+                let mut arr = **self;
+                for (item, b) in arr.iter_mut().zip(other.iter()) {
+                    *item = item.checked_add(*b)?;
+                }
+                Some(PointND::from(arr))
+            }
+
+            ///
+            /// Returns the respective items of `other` subtracted from those of `self`, or `None`
+            /// if any item overflows
+            ///
+            /// See `checked_add_point()` for more info
+            ///
+            pub fn checked_sub_point(&self, other: &Self) -> Option<Self> {
+                let mut arr = **self;
+                for (item, b) in arr.iter_mut().zip(other.iter()) {
+                    *item = item.checked_sub(*b)?;
+                }
+                Some(PointND::from(arr))
+            }
+
+            ///
+            /// Returns the sum of the respective items of `self` and `other`, with each item
+            /// clamped to the bounds of the type instead of overflowing
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from([1, ", stringify!($t), "::MAX]);")]
+            #[doc = concat!("assert_eq!(p.saturating_add_point(&PointND::from([1, 1])).into_arr(), [2, ", stringify!($t), "::MAX]);")]
+            /// ```
+            ///
+            pub fn saturating_add_point(&self, other: &Self) -> Self {
+                let mut arr = **self;
+                for (item, b) in arr.iter_mut().zip(other.iter()) {
+                    *item = item.saturating_add(*b);
+                }
+                PointND::from(arr)
+            }
+
+            ///
+            /// Returns the respective items of `other` subtracted from those of `self`, with each
+            /// item clamped to the bounds of the type instead of overflowing
+            ///
+            /// See `saturating_add_point()` for more info
+            ///
+            pub fn saturating_sub_point(&self, other: &Self) -> Self {
+                let mut arr = **self;
+                for (item, b) in arr.iter_mut().zip(other.iter()) {
+                    *item = item.saturating_sub(*b);
+                }
+                PointND::from(arr)
+            }
+
+            ///
+            /// Returns the sum of the respective items of `self` and `other`, with each item
+            /// wrapping around at the bounds of the type instead of overflowing
+            ///
+            /// ```
+            /// # use point_nd::PointND;
+            #[doc = concat!("let p = PointND::<", stringify!($t), ", 2>::from([1, ", stringify!($t), "::MAX]);")]
+            #[doc = concat!("assert_eq!(p.wrapping_add_point(&PointND::from([1, 1])).into_arr(), [2, ", stringify!($t), "::MIN]);")]
+            /// ```
+            ///
+            pub fn wrapping_add_point(&self, other: &Self) -> Self {
+                let mut arr = **self;
+                for (item, b) in arr.iter_mut().zip(other.iter()) {
+                    *item = item.wrapping_add(*b);
+                }
+                PointND::from(arr)
+            }
+
+            ///
+            /// Returns the respective items of `other` subtracted from those of `self`, with each
+            /// item wrapping around at the bounds of the type instead of overflowing
+            ///
+            /// See `wrapping_add_point()` for more info
+            ///
+            pub fn wrapping_sub_point(&self, other: &Self) -> Self {
+                let mut arr = **self;
+                for (item, b) in arr.iter_mut().zip(other.iter()) {
+                    *item = item.wrapping_sub(*b);
+                }
+                PointND::from(arr)
+            }
+
+        }
+
+    )*};
+}
+
+impl_overflow_methods!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+// Implements checked and saturating shifters for 1..=4 dimensional points of a single integer type
+macro_rules! impl_sized_shifts {
+    ($t:ty, $feature:literal, $dims:literal, [$(($checked:ident, $saturating:ident, $i:literal)),+]) => {
+
+        ///
+        #[doc = concat!(" Methods for transforming the values of a ", stringify!($dims), "D `PointND` without overflowing")]
+        ///
+        /// The `checked_shift` methods return `false` and leave the value unchanged if it would
+        /// overflow, while the `saturating_shift` methods clamp the value to the bounds of its type
+        ///
+        /// # Enabled by features:
+        ///
+        /// - `default`
+        ///
+        /// - `conv_methods`
+        ///
+        #[doc = concat!(" - `", $feature, "`")]
+        ///
+        #[cfg(feature = $feature)]
+        impl PointND<$t, $dims> {
+
+            $(
+            pub fn $checked(&mut self, delta: $t) -> bool {
+                match self[$i].checked_add(delta) {
+                    Some(value) => { self[$i] = value; true }
+                    None => false,
+                }
+            }
+            )+
+
+            $(
+            pub fn $saturating(&mut self, delta: $t) { self[$i] = self[$i].saturating_add(delta); }
+            )+
+
+        }
+
+    };
+}
+
+// Implements checked and saturating shifters for points of each integer type
+macro_rules! impl_checked_shifts {
+    ($($t:ty),*) => {$(
+
+        impl_sized_shifts!($t, "x", 1, [(checked_shift_x, saturating_shift_x, 0)]);
+        impl_sized_shifts!($t, "y", 2, [
+            (checked_shift_x, saturating_shift_x, 0),
+            (checked_shift_y, saturating_shift_y, 1)
+        ]);
+        impl_sized_shifts!($t, "z", 3, [
+            (checked_shift_x, saturating_shift_x, 0),
+            (checked_shift_y, saturating_shift_y, 1),
+            (checked_shift_z, saturating_shift_z, 2)
+        ]);
+        impl_sized_shifts!($t, "w", 4, [
+            (checked_shift_x, saturating_shift_x, 0),
+            (checked_shift_y, saturating_shift_y, 1),
+            (checked_shift_z, saturating_shift_z, 2),
+            (checked_shift_w, saturating_shift_w, 3)
+        ]);
+
+    )*};
+}
+
+impl_checked_shifts!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    #[test]
+    fn can_compare_distances() {
+        let origin = PointND::from([1, 1, 1]);
+        let near = PointND::from([2, 2, 2]);
+        let far = PointND::from([-1, 1, 1]);
+
+        assert_eq!(PointND::<i32, 3>::dist_sq_cmp(&near, &far, &origin), Ordering::Less);
+        assert_eq!(PointND::<i32, 3>::dist_sq_cmp(&far, &near, &origin), Ordering::Greater);
+        assert_eq!(PointND::<i32, 3>::dist_sq_cmp(&near, &near, &origin), Ordering::Equal);
+    }
+
+    #[test]
+    fn distances_order_correctly_where_floats_dont() {
+        // Distances differ by one, far below the precision of f64 at this scale
+        let origin = PointND::from([0i64, 0]);
+        let a = PointND::from([1i64 << 40, 0]);
+        let b = PointND::from([1i64 << 40, 1]);
+
+        let as_float = |p: &PointND<i64, 2>| p.iter().map(|i| (*i as f64) * (*i as f64)).sum::<f64>();
+        assert_eq!(as_float(&a), as_float(&b));
+
+        assert_eq!(PointND::<i64, 2>::dist_sq_cmp(&a, &b, &origin), Ordering::Less);
+        assert!(a.within_distance(&origin, 1 << 40));
+        assert!(!b.within_distance(&origin, 1 << 40));
+    }
+
+    #[test]
+    fn distances_dont_overflow_at_extremes() {
+        let min = PointND::from([i64::MIN; 8]);
+        let max = PointND::from([i64::MAX; 8]);
+        let almost = PointND::from([i64::MAX, i64::MAX, i64::MAX, i64::MAX, i64::MAX, i64::MAX, i64::MAX, i64::MAX - 1]);
+
+        assert_eq!(PointND::<i64, 8>::dist_sq_cmp(&almost, &max, &min), Ordering::Less);
+        assert!(!min.within_distance(&max, i64::MAX));
+
+        let min = PointND::from([0u64, 0]);
+        let max = PointND::from([u64::MAX, 0]);
+        assert!(min.within_distance(&max, u64::MAX));
+        assert!(!min.within_distance(&max, u64::MAX - 1));
+    }
+
+    #[test]
+    fn midpoints_dont_overflow() {
+        let a = PointND::from([i64::MAX, i64::MIN, i64::MAX]);
+        let b = PointND::from([i64::MAX - 1, i64::MIN + 1, i64::MIN]);
+
+        // Adding the items first would overflow
+        assert_eq!(a[0].checked_add(b[0]), None);
+        assert_eq!(a.midpoint_exact(&b).into_arr(), [i64::MAX - 1, i64::MIN, -1]);
+
+        let a = PointND::from([u64::MAX, 0]);
+        let b = PointND::from([u64::MAX, u64::MAX]);
+        assert_eq!(a.midpoint_exact(&b).into_arr(), [u64::MAX, u64::MAX / 2]);
+    }
+
+    #[test]
+    fn midpoints_round_towards_negative_infinity() {
+        let a = PointND::from([1i32, -1, -3, 4]);
+        let b = PointND::from([2i32, -2, 0, 4]);
+        assert_eq!(a.midpoint_exact(&b).into_arr(), [1, -2, -2, 4]);
+        assert_eq!(b.midpoint_exact(&a), a.midpoint_exact(&b));
+    }
+
+    #[test]
+    fn means_dont_overflow() {
+        let points = [PointND::from([i64::MAX, i64::MIN]); 5];
+        let mean = PointND::<i64, 2>::mean_exact(&points).unwrap();
+        assert_eq!(mean.into_arr(), [i64::MAX, i64::MIN]);
+
+        let points = [
+            PointND::from([i64::MAX, i64::MAX - 4]),
+            PointND::from([i64::MAX - 1, i64::MAX]),
+            PointND::from([i64::MAX - 2, i64::MIN]),
+        ];
+        let mean = PointND::<i64, 2>::mean_exact(&points).unwrap();
+        assert_eq!(mean.into_arr(), [i64::MAX - 1, (i64::MAX - 4) / 3 - 1]);
+
+        let points = [PointND::from([u8::MAX]), PointND::from([u8::MAX - 1])];
+        assert_eq!(PointND::<u8, 1>::mean_exact(&points).unwrap().into_arr(), [254]);
+    }
+
+    #[test]
+    fn means_round_towards_negative_infinity() {
+        let points = [PointND::from([0, 0]), PointND::from([-1, 1]), PointND::from([-1, 1])];
+        let mean = PointND::<i16, 2>::mean_exact(&points).unwrap();
+        assert_eq!(mean.into_arr(), [-1, 0]);
+
+        assert_eq!(PointND::<i32, 3>::mean_exact(&[]), None);
+    }
+
+    #[test]
+    fn normalized_floats_round_trip() {
+        for b in 0..=u8::MAX {
+            let p = PointND::from([b]).to_normalized_f32();
+            assert!((0.0..=1.0).contains(&p[0]));
+            assert_eq!(PointND::<u8, 1>::from_normalized_f32(p).into_arr(), [b]);
+        }
+        for b in 0..=u16::MAX {
+            let p = PointND::from([b]).to_normalized_f32();
+            assert_eq!(PointND::<u16, 1>::from_normalized_f32(p).into_arr(), [b]);
+        }
+    }
+
+    #[test]
+    fn normalized_floats_are_clamped() {
+        let p = PointND::from([-0.5, 1.5, f32::NAN, f32::INFINITY, f32::NEG_INFINITY, -0.0]);
+        assert_eq!(PointND::<u8, 6>::from_normalized_f32(p).into_arr(), [0, 255, 0, 255, 0, 0]);
+        assert_eq!(PointND::<u16, 6>::from_normalized_f32(p).into_arr(), [0, 65535, 0, 65535, 0, 0]);
+    }
+
+    #[test]
+    fn normalized_floats_round_halves_up() {
+        for k in 0..u8::MAX {
+            let half = (k as f32 + 0.5) / 255.0;
+            let p = PointND::from([half - 1e-4, half, half + 1e-4]);
+            assert_eq!(PointND::<u8, 3>::from_normalized_f32(p).into_arr(), [k, k + 1, k + 1]);
+        }
+        let p = PointND::from([0.5 / 255.0, 0.4 / 255.0, 254.5 / 255.0]);
+        assert_eq!(PointND::<u8, 3>::from_normalized_f32(p).into_arr(), [1, 0, 255]);
+    }
+
+    #[test]
+    fn can_add_and_sub_u8_points_at_bounds() {
+        let max = PointND::<u8, 3>::from([255, 255, 0]);
+        let one = PointND::<u8, 3>::from([1, 0, 1]);
+
+        assert_eq!(max.checked_add_point(&one), None);
+        assert_eq!(max.saturating_add_point(&one).into_arr(), [255, 255, 1]);
+        assert_eq!(max.wrapping_add_point(&one).into_arr(), [0, 255, 1]);
+
+        assert_eq!(max.checked_sub_point(&one), None);
+        assert_eq!(max.saturating_sub_point(&one).into_arr(), [254, 255, 0]);
+        assert_eq!(max.wrapping_sub_point(&one).into_arr(), [254, 255, 255]);
+
+        let zero = PointND::<u8, 3>::from([0, 0, 0]);
+        assert_eq!(max.checked_add_point(&zero), Some(max));
+        assert_eq!(max.checked_sub_point(&max), Some(zero));
+    }
+
+    #[test]
+    fn can_add_and_sub_i32_points_at_bounds() {
+        let min = PointND::<i32, 2>::from([i32::MIN, -5]);
+        let one = PointND::<i32, 2>::from([1, 1]);
+
+        assert_eq!(min.checked_sub_point(&one), None);
+        assert_eq!(min.saturating_sub_point(&one).into_arr(), [i32::MIN, -6]);
+        assert_eq!(min.wrapping_sub_point(&one).into_arr(), [i32::MAX, -6]);
+
+        assert_eq!(min.checked_add_point(&one), Some(PointND::from([i32::MIN + 1, -4])));
+        assert_eq!(min.saturating_add_point(&min).into_arr(), [i32::MIN, -10]);
+        assert_eq!(min.wrapping_add_point(&min).into_arr(), [0, -10]);
+
+        // Subtracting a negative can overflow upwards too
+        let max = PointND::<i32, 1>::from([i32::MAX]);
+        let neg = PointND::<i32, 1>::from([-1]);
+        assert_eq!(max.checked_sub_point(&neg), None);
+        assert_eq!(max.saturating_sub_point(&neg).into_arr(), [i32::MAX]);
+        assert_eq!(max.wrapping_sub_point(&neg).into_arr(), [i32::MIN]);
+    }
+
+    #[test]
+    fn checked_ops_fail_if_any_item_overflows() {
+        let p = PointND::<i64, 5>::from([0, 0, 0, 0, i64::MAX]);
+        let q = PointND::<i64, 5>::from([1, 2, 3, 4, 5]);
+        assert_eq!(p.checked_add_point(&q), None);
+        assert_eq!(q.checked_add_point(&q), Some(PointND::from([2, 4, 6, 8, 10])));
+
+        let p = PointND::<u128, 0>::from([]);
+        assert_eq!(p.checked_sub_point(&p), Some(p));
+    }
+
+    #[test]
+    #[cfg(feature = "conv_methods")]
+    fn can_shift_u8_points_at_bounds() {
+        let mut p = PointND::<u8, 2>::from([254, 1]);
+        assert!(p.checked_shift_x(1));
+        assert!(!p.checked_shift_x(1));
+        assert_eq!(p.into_arr(), [255, 1]);
+
+        p.saturating_shift_y(255);
+        assert_eq!(p.into_arr(), [255, 255]);
+        p.saturating_shift_x(0);
+        assert_eq!(p.into_arr(), [255, 255]);
+
+        let mut p = PointND::<u8, 1>::from([0]);
+        assert!(p.checked_shift_x(255));
+        assert!(!p.checked_shift_x(255));
+        assert_eq!(p.into_arr(), [255]);
+    }
+
+    #[test]
+    #[cfg(feature = "conv_methods")]
+    fn can_shift_i16_points_at_bounds() {
+        let mut p = PointND::<i16, 4>::from([i16::MIN, i16::MAX, 0, -1]);
+        assert!(!p.checked_shift_x(-1));
+        assert!(!p.checked_shift_y(1));
+        assert!(p.checked_shift_z(i16::MIN));
+        assert!(p.checked_shift_w(i16::MIN + 1));
+        assert_eq!(p.into_arr(), [i16::MIN, i16::MAX, i16::MIN, i16::MIN]);
+
+        p.saturating_shift_x(-100);
+        p.saturating_shift_y(100);
+        p.saturating_shift_z(i16::MAX);
+        p.saturating_shift_w(-1);
+        assert_eq!(p.into_arr(), [i16::MIN, i16::MAX, -1, i16::MIN]);
+
+        let mut p = PointND::<i16, 3>::from([1, 2, 3]);
+        assert!(p.checked_shift_y(-2));
+        assert_eq!(p.into_arr(), [1, 0, 3]);
+    }
+
+    #[test]
+    fn can_step_through_boxes() {
+        let min = PointND::<i32, 2>::from([-1, 5]);
+        let max = PointND::from([0, 7]);
+
+        let mut expected = Vec::new();
+        for x in -1..=0 {
+            for y in 5..=7 {
+                expected.push(PointND::from([x, y]));
+            }
+        }
+
+        let mut forwards = Vec::from([min]);
+        while let Some(next) = forwards.last().unwrap().next_in_box(&min, &max) {
+            forwards.push(next);
+        }
+        assert_eq!(forwards, expected);
+        assert!(forwards.windows(2).all(|pair| pair[0] < pair[1]));
+
+        let mut backwards = Vec::from([max]);
+        while let Some(prev) = backwards.last().unwrap().prev_in_box(&min, &max) {
+            backwards.push(prev);
+        }
+        backwards.reverse();
+        assert_eq!(backwards, expected);
+    }
+
+    #[test]
+    fn stepping_stops_at_box_ends() {
+        let min = PointND::from([0u8, 0, 0]);
+        let max = PointND::from([2u8, 0, 255]);
+
+        assert_eq!(max.next_in_box(&min, &max), None);
+        assert_eq!(min.prev_in_box(&min, &max), None);
+
+        // Items at the limits of the type don't overflow
+        let p = PointND::from([1u8, 0, 255]);
+        assert_eq!(p.next_in_box(&min, &max), Some(PointND::from([2, 0, 0])));
+        assert_eq!(PointND::from([2u8, 0, 0]).prev_in_box(&min, &max), Some(p));
+
+        let p: PointND<i32, 0> = PointND::from([]);
+        assert_eq!(p.next_in_box(&p, &p), None);
+        assert_eq!(p.prev_in_box(&p, &p), None);
+    }
+
+    #[test]
+    fn points_outside_boxes_cannot_step() {
+        let min = PointND::<i32, 2>::from([0, 0]);
+        let max = PointND::from([1, 2]);
+
+        assert_eq!(PointND::<i32, 2>::from([2, 0]).next_in_box(&min, &max), None);
+        assert_eq!(PointND::<i32, 2>::from([0, -1]).next_in_box(&min, &max), None);
+        assert_eq!(PointND::<i32, 2>::from([1, 3]).prev_in_box(&min, &max), None);
+
+        // Inverted boxes contain nothing
+        assert_eq!(min.next_in_box(&max, &min), None);
+    }
+
+    #[test]
+    fn negative_radii_are_never_within() {
+        let p = PointND::from([3i8, -3]);
+        assert!(p.within_distance(&p, 0));
+        assert!(!p.within_distance(&p, -1));
+    }
+
+}
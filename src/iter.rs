@@ -0,0 +1,303 @@
+use core::ops::{Add, Mul};
+
+use crate::PointND;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+///
+/// Trait for items which the mean of can be found by `centroid()` in `PointIterExt`
+///
+/// This is implemented for all primitive integers, `f32` and `f64`, and cannot be implemented
+/// for other types
+///
+pub trait CentroidItem: sealed::Sealed + Sized {
+
+    /// Divides the `sum` of `count` items by `count`
+    #[doc(hidden)]
+    fn div_count(sum: Self, count: usize) -> Self;
+
+}
+
+// Implements CentroidItem for each integer type, dividing in a type wide enough to hold any
+//  count so that the count can't overflow even when the sum fits
+macro_rules! impl_centroid_ints {
+    ($wide:ty => $($t:ty),*) => {$(
+
+        impl sealed::Sealed for $t {}
+
+        impl CentroidItem for $t {
+
+            fn div_count(sum: Self, count: usize) -> Self {
+                (sum as $wide / count as $wide) as $t
+            }
+
+        }
+
+    )*};
+}
+
+// Implements CentroidItem for each float type
+macro_rules! impl_centroid_floats {
+    ($($t:ty),*) => {$(
+
+        impl sealed::Sealed for $t {}
+
+        impl CentroidItem for $t {
+
+            fn div_count(sum: Self, count: usize) -> Self {
+                sum / count as $t
+            }
+
+        }
+
+    )*};
+}
+
+impl_centroid_ints!(u128 => u8, u16, u32, u64, u128, usize);
+impl_centroid_ints!(i128 => i8, i16, i32, i64, i128, isize);
+impl_centroid_floats!(f32, f64);
+
+///
+/// Extension methods for processing iterators of `PointND`'s in bulk
+///
+/// Implemented for every iterator yielding `PointND`'s, so it only needs to be imported
+///
+/// ```
+/// # use point_nd::PointND;
+/// use point_nd::PointIterExt;
+///
+/// let points = [PointND::from([0, 0]), PointND::from([1, 2]), PointND::from([2, 1])];
+/// let moved: Vec<_> = points
+///     .into_iter()
+///     .translate_all(PointND::from([1, 1]))
+///     .scale_all(10)
+///     .collect();
+///
+/// assert_eq!(moved[1].into_arr(), [20, 30]);
+/// ```
+///
+pub trait PointIterExt<T, const N: usize>: Iterator<Item = PointND<T, N>> + Sized {
+
+    ///
+    /// Returns an iterator which adds `delta` to each item of every point
+    ///
+    fn translate_all(self, delta: PointND<T, N>) -> TranslateAll<Self, T, N>
+        where T: Add<Output = T> + Copy {
+        TranslateAll { iter: self, delta }
+    }
+
+    ///
+    /// Returns an iterator which multiplies each item of every point by `factor`
+    ///
+    fn scale_all(self, factor: T) -> ScaleAll<Self, T>
+        where T: Mul<Output = T> + Copy {
+        ScaleAll { iter: self, factor }
+    }
+
+    ///
+    /// Consumes the iterator, returning the componentwise minimum and maximum of every point,
+    /// or `None` if the iterator is empty
+    ///
+    /// As with `component_min()` and `component_max()` in `PointND`, items which cannot be
+    /// compared (_e.g._ - `NaN`) are only kept if they were yielded first
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// use point_nd::PointIterExt;
+    ///
+    /// let points = [PointND::from([3, -1]), PointND::from([0, 4]), PointND::from([2, 2])];
+    /// let (min, max) = points.into_iter().bounding_box().unwrap();
+    ///
+    /// assert_eq!(min.into_arr(), [0, -1]);
+    /// assert_eq!(max.into_arr(), [3, 4]);
+    /// ```
+    ///
+    fn bounding_box(mut self) -> Option<(PointND<T, N>, PointND<T, N>)>
+        where T: PartialOrd + Clone {
+
+        let first = self.next()?;
+        Some(self.fold((first.clone(), first), |(min, max), point| {
+            (min.component_min(point.clone()), max.component_max(point))
+        }))
+    }
+
+    ///
+    /// Consumes the iterator, returning the componentwise mean of every point, or `None` if
+    /// the iterator is empty
+    ///
+    /// Points with integer items give a centroid rounded towards zero. Only the sum of the
+    /// points has to fit in `T`, as they are counted in a `usize`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// use point_nd::PointIterExt;
+    ///
+    /// let points = [PointND::from([0.0, 0.0]), PointND::from([2.0, 0.0]), PointND::from([1.0, 3.0])];
+    /// let centroid = points.into_iter().centroid().unwrap();
+    ///
+    /// assert_eq!(centroid.into_arr(), [1.0, 1.0]);
+    /// ```
+    ///
+    fn centroid(mut self) -> Option<PointND<T, N>>
+        where T: Add<Output = T> + CentroidItem + Copy {
+
+        let first = self.next()?;
+        let (sum, count) = self.fold((first.into_arr(), 1_usize), |(mut sum, count), point| {
+            for (total, item) in sum.iter_mut().zip(point.iter()) {
+                *total = *total + *item;
+            }
+            (sum, count + 1)
+        });
+        Some(PointND::from_fn(|i| T::div_count(sum[i], count)))
+    }
+
+}
+
+impl<I, T, const N: usize> PointIterExt<T, N> for I
+    where I: Iterator<Item = PointND<T, N>> {}
+
+///
+/// Iterator returned by `translate_all()` in `PointIterExt`
+///
+#[derive(Clone, Debug)]
+pub struct TranslateAll<I, T, const N: usize> {
+    iter: I,
+    delta: PointND<T, N>,
+}
+
+impl<I, T, const N: usize> Iterator for TranslateAll<I, T, N>
+    where I: Iterator<Item = PointND<T, N>>,
+          T: Add<Output = T> + Copy {
+
+    type Item = PointND<T, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut arr = self.iter.next()?.into_arr();
+        for (item, d) in arr.iter_mut().zip(self.delta.iter()) {
+            *item = *item + *d;
+        }
+        Some(PointND::from(arr))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+}
+
+///
+/// Iterator returned by `scale_all()` in `PointIterExt`
+///
+#[derive(Clone, Debug)]
+pub struct ScaleAll<I, T> {
+    iter: I,
+    factor: T,
+}
+
+impl<I, T, const N: usize> Iterator for ScaleAll<I, T>
+    where I: Iterator<Item = PointND<T, N>>,
+          T: Mul<Output = T> + Copy {
+
+    type Item = PointND<T, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut arr = self.iter.next()?.into_arr();
+        for item in arr.iter_mut() {
+            *item = *item * self.factor;
+        }
+        Some(PointND::from(arr))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    const POINTS: [[f64; 3]; 4] = [
+        [1.0, -2.0, 0.5],
+        [4.0, 0.0, -1.5],
+        [-3.0, 6.0, 2.0],
+        [0.0, 0.0, 3.0],
+    ];
+
+    #[test]
+    fn translating_and_scaling_match_manual_loops() {
+        let delta = PointND::from([1.0, 1.0, -1.0]);
+        let factor = 2.0;
+
+        let chained: Vec<_> = POINTS
+            .map(PointND::from)
+            .into_iter()
+            .translate_all(delta)
+            .scale_all(factor)
+            .collect();
+
+        let mut manual = Vec::new();
+        for point in POINTS {
+            let mut arr = point;
+            for (item, d) in arr.iter_mut().zip(delta.iter()) {
+                *item = (*item + d) * factor;
+            }
+            manual.push(PointND::from(arr));
+        }
+
+        assert_eq!(chained, manual);
+    }
+
+    #[test]
+    fn adapters_keep_size_hints() {
+        let iter = POINTS.map(PointND::from).into_iter().translate_all(PointND::fill(0.0)).scale_all(1.0);
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+    }
+
+    #[test]
+    fn can_get_bounding_box() {
+        let (min, max) = POINTS.map(PointND::from).into_iter().bounding_box().unwrap();
+        assert_eq!(min.into_arr(), [-3.0, -2.0, -1.5]);
+        assert_eq!(max.into_arr(), [4.0, 6.0, 3.0]);
+
+        let (min, max) = core::iter::once(PointND::from([5, 5])).bounding_box().unwrap();
+        assert_eq!(min, max);
+    }
+
+    #[test]
+    fn can_get_centroid() {
+        let centroid = POINTS.map(PointND::from).into_iter().centroid().unwrap();
+        assert_eq!(centroid.into_arr(), [0.5, 1.0, 1.0]);
+
+        // Rounded towards zero
+        let points = [PointND::from([1u32, 9]), PointND::from([2, 0])];
+        assert_eq!(points.into_iter().centroid().unwrap().into_arr(), [1, 4]);
+    }
+
+    #[test]
+    fn centroids_only_need_the_sum_to_fit() {
+        let zeros = core::iter::repeat(PointND::<u8, 1>::from([0])).take(300);
+        assert_eq!(zeros.centroid().unwrap().into_arr(), [0]);
+
+        let points = core::iter::repeat(PointND::<i8, 2>::from([0, -1])).take(100);
+        assert_eq!(points.centroid().unwrap().into_arr(), [0, -1]);
+
+        // Past 2^24 points, counting in f32 would stop increasing
+        let count = (1 << 24) + 2;
+        let points = core::iter::once(PointND::<f32, 1>::from([count as f32]))
+            .chain(core::iter::repeat(PointND::from([0.0])).take(count - 1));
+        assert_eq!(points.centroid().unwrap().into_arr(), [1.0]);
+    }
+
+    #[test]
+    fn empty_iterators_have_no_reductions() {
+        assert_eq!(core::iter::empty::<PointND<f32, 2>>().bounding_box(), None);
+        assert_eq!(core::iter::empty::<PointND<f32, 2>>().centroid(), None);
+    }
+
+}
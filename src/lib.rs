@@ -9,7 +9,9 @@
 //!
 //! This crate was designed to be `no_std` and `wasm` compatible, and has been tested in those environments.
 //!
-//! `PointND` uses constant generics, it is recommended for use with a Rust version of **at least 1.51**
+//! `PointND` uses constant generics and its errors implement `core::error::Error`, so it needs a Rust
+//! version of **at least 1.81**. Constructors and getters such as `new()`, `dims()`, `into_arr()` and
+//! `x()` are `const fn`, so can be used to define constants
 //!
 //! # Features
 //!
@@ -35,19 +37,146 @@
 //!
 //!     - Methods which allow function pointers to be passed to points in order to transform values.
 //!
-//!     - If this and the `var-dims` feature are disabled, this crate will include zero dependencies
-//!
 //! - `var-dims`
 //!
 //!     - Methods which append or remove values from points.
 //!
-//!     - If this and the `appliers` feature are disabled, this crate will include zero dependencies
+//! - `alloc`
+//!
+//!     - Methods which work with heap allocated (_e.g._ - boxed) points.
+//!
+//!     - Uses the `alloc` crate, which is available on most `no_std` targets with an allocator
+//!
+//! - `float-math`
+//!
+//!     - Methods which compute magnitudes and distances of `f32` and `f64` points.
+//!
+//!     - Enables the [`libm`][libm] dependency in order to stay `no_std` compatible
+//!
+//! - `rand`
+//!
+//!     - A `Distribution` implementation for points (so they can be created with `rng.gen()`),
+//!       and methods which sample points within boxes or weighted indices of `f32` and `f64` points.
+//!
+//!     - With the `float-math` feature also enabled, methods which sample random unit directions.
+//!
+//!     - Enables the [`rand`][rand] dependency (without its default features)
+//!
+//! - `serde`
+//!
+//!     - `Serialize` and `Deserialize` implementations for points of any dimensions, written as
+//!       sequences of items.
+//!
+//!     - Enables the [`serde`][serde] dependency (without its default features)
+//!
+//! - `bytemuck`
+//!
+//!     - `Pod` and `Zeroable` implementations for points of `Pod` items, so slices of points can
+//!       be cast to and from bytes (_e.g._ - to upload vertex data to a GPU).
+//!
+//!     - Enables the [`bytemuck`][bytemuck] dependency
+//!
+//! - `mint`
 //!
+//!     - Conversions between points and the `Point2`, `Point3`, `Vector2`, `Vector3` and `Vector4`
+//!       types of [`mint`][mint], which allow points to be used with most math crates (_e.g._ -
+//!       `glam`, `nalgebra` and `cgmath`).
+//!
+//!     - Enables the [`mint`][mint] dependency
+//!
+//!       ```
+//!       # #[cfg(feature = "mint")] {
+//!       # use point_nd::PointND;
+//!       let v = glam::Vec3::new(1.0, 2.0, 3.0);
+//!       let p: PointND<f32, 3> = mint::Vector3::from(v).into();
+//!       assert_eq!(p.into_arr(), [1.0, 2.0, 3.0]);
+//!       # }
+//!       ```
+//!
+//! - `num-traits`
+//!
+//!     - An `as_cast` method which converts the items of points with the `as` operator.
+//!
+//!     - Enables the [`num-traits`][num-traits] dependency (without its default features)
+//!
+//! - `proptest`
+//!
+//!     - An `Arbitrary` implementation for points of `Arbitrary` items, which shrinks failing
+//!       cases one item at a time.
+//!
+//!     - Enables the [`proptest`][proptest] dependency, which needs `std`. As this crate is
+//!       `no_std`, this is not enabled by the `full` feature and is meant for tests only
+//!
+//! - `compact-fmt`
+//!
+//!     - A `write_compact` method which writes integer and float points into byte buffers
+//!       without `core::fmt`, for embedded targets where its size matters.
+//!
+//!     - Enables the [`itoa`][itoa] and [`ryu`][ryu] dependencies
+//!
+//! This crate has zero dependencies unless the `float-math`, `rand`, `serde`, `bytemuck`,
+//! `mint`, `num-traits`, `proptest` or `compact-fmt` features are enabled
+//!
+//! [libm]: https://crates.io/crates/libm
+//! [rand]: https://crates.io/crates/rand
+//! [serde]: https://crates.io/crates/serde
+//! [bytemuck]: https://crates.io/crates/bytemuck
+//! [mint]: https://crates.io/crates/mint
+//! [num-traits]: https://crates.io/crates/num-traits
+//! [proptest]: https://crates.io/crates/proptest
+//! [itoa]: https://crates.io/crates/itoa
+//! [ryu]: https://crates.io/crates/ryu
+//!
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+// Tests are allowed to use std (e.g. to catch panics in user supplied closures)
+#[cfg(test)]
+extern crate std;
 
 mod point;
 mod utils;
+mod axis;
+mod error;
+mod text;
+mod tagged;
+mod float;
+mod grid;
+mod stats;
+mod guarded;
+mod iter;
+mod integer;
+mod affine;
+mod pipeline;
+#[cfg(feature = "rand")]
+mod rand_impls;
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impls;
+#[cfg(feature = "mint")]
+mod mint_impls;
+#[cfg(feature = "proptest")]
+mod proptest_impls;
+#[cfg(feature = "compact-fmt")]
+mod compact;
 
 pub use point::PointND;
+pub use error::{Error, ParsePointError, FromIterError, DimensionMismatchError, ZeroPointError, NonFiniteError, ContractError};
+pub use utils::BoundsMode;
+pub use axis::Axis;
+pub use tagged::TaggedPoint;
+pub use affine::{Position, Displacement};
+pub use pipeline::PointPipeline;
+pub use stats::PointStats;
+pub use guarded::{NonZeroPoint, FinitePoint};
+pub use iter::{PointIterExt, CentroidItem, TranslateAll, ScaleAll};
+
+#[cfg(feature = "compact-fmt")]
+pub use compact::CompactItem;
+#[cfg(feature = "compact-fmt")]
+pub use error::BufferTooSmallError;
 
 #[cfg(feature = "appliers")]
 pub use utils::{ApplyFn, ApplyDimsFn, ApplyValsFn, ApplyPointFn};
@@ -0,0 +1,105 @@
+use crate::PointND;
+
+// Implements conversions to and from a mint type, with fields given in the order of the
+//  dimensions of the point
+macro_rules! impl_mint_conversions {
+    ($($mint:ident, $dims:literal, [$($field:ident),+];)*) => {$(
+
+        #[doc = concat!(" Moves the fields of a `mint::", stringify!($mint), "` into a `PointND`")]
+        ///
+        /// # Enabled by features:
+        ///
+        /// - `mint`
+        ///
+        impl<T> From<mint::$mint<T>> for PointND<T, $dims> {
+
+            fn from(value: mint::$mint<T>) -> Self {
+                PointND::from([$(value.$field),+])
+            }
+
+        }
+
+        #[doc = concat!(" Moves the items of a `PointND` into a `mint::", stringify!($mint), "`")]
+        ///
+        /// # Enabled by features:
+        ///
+        /// - `mint`
+        ///
+        impl<T> From<PointND<T, $dims>> for mint::$mint<T> {
+
+            fn from(point: PointND<T, $dims>) -> Self {
+                let [$($field),+] = point.into_arr();
+                mint::$mint { $($field),+ }
+            }
+
+        }
+
+    )*};
+}
+
+impl_mint_conversions! {
+    Point2, 2, [x, y];
+    Point3, 3, [x, y, z];
+    Vector2, 2, [x, y];
+    Vector3, 3, [x, y, z];
+    Vector4, 4, [x, y, z, w];
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::{String, ToString};
+
+    #[test]
+    fn can_round_trip_points() {
+        let p = PointND::from([1.5, -2.0]);
+        let m: mint::Point2<f64> = p.into();
+        assert_eq!((m.x, m.y), (1.5, -2.0));
+        assert_eq!(PointND::from(m), p);
+
+        let p = PointND::from([1, 2, 3]);
+        let m: mint::Point3<i32> = p.into();
+        assert_eq!((m.x, m.y, m.z), (1, 2, 3));
+        assert_eq!(PointND::from(m), p);
+    }
+
+    #[test]
+    fn can_round_trip_vectors() {
+        let p = PointND::from([0.5f32, 4.0]);
+        let m: mint::Vector2<f32> = p.into();
+        assert_eq!((m.x, m.y), (0.5, 4.0));
+        assert_eq!(PointND::from(m), p);
+
+        let p = PointND::from([-1i8, 0, 1]);
+        let m: mint::Vector3<i8> = p.into();
+        assert_eq!((m.x, m.y, m.z), (-1, 0, 1));
+        assert_eq!(PointND::from(m), p);
+
+        let p = PointND::from([1u64, 2, 3, 4]);
+        let m: mint::Vector4<u64> = p.into();
+        assert_eq!((m.x, m.y, m.z, m.w), (1, 2, 3, 4));
+        assert_eq!(PointND::from(m), p);
+    }
+
+    #[test]
+    fn can_move_items_without_clone() {
+        let p = PointND::from(["x", "y", "z", "w"].map(|s| s.to_string()));
+        let m: mint::Vector4<String> = p.into();
+        assert_eq!(m.w, "w");
+
+        let p = PointND::from(m);
+        assert_eq!(p.into_arr(), ["x", "y", "z", "w"]);
+    }
+
+    #[test]
+    fn can_convert_glam_types() {
+        let v = glam::Vec3::new(1.0, 2.0, 3.0);
+        let p = PointND::<f32, 3>::from(mint::Vector3::from(v));
+        assert_eq!(p.into_arr(), [1.0, 2.0, 3.0]);
+
+        let back: glam::Vec3 = mint::Vector3::from(p).into();
+        assert_eq!(back, v);
+    }
+
+}
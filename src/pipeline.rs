@@ -0,0 +1,295 @@
+use core::ops::{Add, Mul, Neg};
+
+use crate::PointND;
+
+/**
+A reusable sequence of transformations which can be applied to many `PointND`'s.
+
+The steps are stored inline in a fixed capacity list, so building and applying a pipeline
+never allocates. The capacity `CAP` defaults to `8` steps, and adding more steps than this
+panics.
+
+Steps are applied in the order they were added:
+
+- `translate(delta)` adds each item of `delta` to the respective item of the point
+- `scale(factor)` multiplies each item of the point by `factor`
+- `clamp(lo, hi)` restricts each item of the point to the respective items of `lo` and `hi`
+- `mirror(axis)` negates the item at `axis`, reflecting the point across the plane where
+  that axis is zero
+
+```
+# use point_nd::{PointND, PointPipeline};
+let pipeline = PointPipeline::<i32, 2>::new()
+    .translate(PointND::from([1, -1]))
+    .scale(10)
+    .clamp(PointND::from([-50, -50]), PointND::from([50, 50]))
+    .mirror(0);
+
+let p = pipeline.apply(PointND::from([2, 3]));
+assert_eq!(p.into_arr(), [-30, 20]);
+
+let p = pipeline.apply(PointND::from([-8, 9]));
+assert_eq!(p.into_arr(), [50, 50]);
+```
+ */
+#[derive(Clone, Debug)]
+pub struct PointPipeline<T, const N: usize, const CAP: usize = 8> {
+    steps: [Option<Step<T, N>>; CAP],
+    len: usize,
+}
+
+// A single transformation of a pipeline
+#[derive(Clone, Debug)]
+enum Step<T, const N: usize> {
+    Translate(PointND<T, N>),
+    Scale(T),
+    Clamp(PointND<T, N>, PointND<T, N>),
+    Mirror(usize),
+}
+
+impl<T, const N: usize, const CAP: usize> PointPipeline<T, N, CAP> {
+
+    /// Returns a new `PointPipeline` with no steps, which leaves points unchanged
+    pub fn new() -> Self {
+        PointPipeline {
+            steps: [(); CAP].map(|_| None),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of steps in the pipeline
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the pipeline has no steps
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Appends a step, panicking if the pipeline is full
+    fn push(mut self, step: Step<T, N>) -> Self {
+        if self.len == CAP {
+            panic!("Attempted to add more than {} steps to a PointPipeline", CAP);
+        }
+        self.steps[self.len] = Some(step);
+        self.len += 1;
+        self
+    }
+
+    ///
+    /// Consumes `self` and returns the pipeline with a step adding `delta` to points
+    ///
+    /// # Panics
+    ///
+    /// - If the pipeline already has `CAP` steps
+    ///
+    pub fn translate(self, delta: PointND<T, N>) -> Self {
+        self.push(Step::Translate(delta))
+    }
+
+    ///
+    /// Consumes `self` and returns the pipeline with a step multiplying each item of points
+    /// by `factor`
+    ///
+    /// # Panics
+    ///
+    /// - If the pipeline already has `CAP` steps
+    ///
+    pub fn scale(self, factor: T) -> Self {
+        self.push(Step::Scale(factor))
+    }
+
+    ///
+    /// Consumes `self` and returns the pipeline with a step restricting each item of points
+    /// to the range of the respective items in `lo` and `hi`
+    ///
+    /// This is the same as calling `clamp()` on each point
+    ///
+    /// # Panics
+    ///
+    /// - If any item in `lo` is greater than the respective item in `hi`
+    ///
+    /// - If the pipeline already has `CAP` steps
+    ///
+    pub fn clamp(self, lo: PointND<T, N>, hi: PointND<T, N>) -> Self
+        where T: PartialOrd {
+
+        if let Some(i) = lo.iter().zip(hi.iter()).position(|(l, h)| l > h) {
+            panic!("Attempted to add a clamp() step to a PointPipeline with a lower bound greater than its upper bound at dimension {}", i);
+        }
+        self.push(Step::Clamp(lo, hi))
+    }
+
+    ///
+    /// Consumes `self` and returns the pipeline with a step negating the item of points
+    /// at `axis`
+    ///
+    /// # Panics
+    ///
+    /// - If `axis` is greater than or equal to `N`
+    ///
+    /// - If the pipeline already has `CAP` steps
+    ///
+    pub fn mirror(self, axis: usize) -> Self {
+        if axis >= N {
+            panic!("Attempted to add a mirror() step at axis {} to a PointPipeline of {} dimensions", axis, N);
+        }
+        self.push(Step::Mirror(axis))
+    }
+
+}
+
+impl<T, const N: usize, const CAP: usize> PointPipeline<T, N, CAP>
+    where T: Add<Output = T> + Mul<Output = T> + Neg<Output = T> + PartialOrd + Copy {
+
+    ///
+    /// Consumes `point` and returns it with every step of the pipeline applied in order
+    ///
+    /// ```
+    /// # use point_nd::{PointND, PointPipeline};
+    /// let pipeline = PointPipeline::<f64, 3>::new()
+    ///     .scale(0.5)
+    ///     .translate(PointND::from([1.0, 1.0, 1.0]));
+    ///
+    /// let points = [PointND::from([2.0, 4.0, 6.0]), PointND::from([0.0, -2.0, 1.0])];
+    /// let moved = points.map(|p| pipeline.apply(p));
+    /// assert_eq!(moved[0].into_arr(), [2.0, 3.0, 4.0]);
+    /// assert_eq!(moved[1].into_arr(), [1.0, 0.0, 1.5]);
+    /// ```
+    ///
+    pub fn apply(&self, point: PointND<T, N>) -> PointND<T, N> {
+        let mut arr = point.into_arr();
+        for step in self.steps[..self.len].iter().flatten() {
+            match step {
+                Step::Translate(delta) => {
+                    for (item, d) in arr.iter_mut().zip(delta.iter()) {
+                        *item = *item + *d;
+                    }
+                }
+                Step::Scale(factor) => {
+                    for item in arr.iter_mut() {
+                        *item = *item * *factor;
+                    }
+                }
+                // Compares the same way as component_max() and component_min()
+                Step::Clamp(lo, hi) => {
+                    for ((item, l), h) in arr.iter_mut().zip(lo.iter()).zip(hi.iter()) {
+                        if l > item {
+                            *item = *l;
+                        }
+                        if h < item {
+                            *item = *h;
+                        }
+                    }
+                }
+                Step::Mirror(axis) => {
+                    arr[*axis] = -arr[*axis];
+                }
+            }
+        }
+        PointND::from(arr)
+    }
+
+}
+
+impl<T, const N: usize, const CAP: usize> Default for PointPipeline<T, N, CAP> {
+
+    fn default() -> Self {
+        PointPipeline::new()
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POINTS: [[f64; 3]; 4] = [
+        [1.0, -2.0, 0.5],
+        [40.0, 0.0, -1.5],
+        [-3.0, 6.0, 2.0],
+        [0.0, -100.0, 3.0],
+    ];
+
+    // Negates a single item by hand, as PointND has no method for it
+    fn mirrored(point: PointND<f64, 3>, axis: usize) -> PointND<f64, 3> {
+        let mut arr = point.into_arr();
+        arr[axis] = -arr[axis];
+        PointND::from(arr)
+    }
+
+    #[test]
+    fn pipelines_match_chained_methods() {
+        let delta = PointND::from([1.0, 1.0, -1.0]);
+        let lo = PointND::from([-10.0, -10.0, -10.0]);
+        let hi = PointND::from([10.0, 5.0, 10.0]);
+
+        let pipeline = PointPipeline::<f64, 3>::new()
+            .translate(delta)
+            .scale(2.0)
+            .clamp(lo, hi)
+            .mirror(1);
+        assert_eq!(pipeline.len(), 4);
+
+        for point in POINTS.map(PointND::from) {
+            let mut manual = point;
+            for (item, d) in manual.iter_mut().zip(delta.iter()) {
+                *item += d;
+            }
+            for item in manual.iter_mut() {
+                *item *= 2.0;
+            }
+            let manual = mirrored(manual.clamp(lo, hi), 1);
+
+            assert_eq!(pipeline.apply(point), manual);
+        }
+    }
+
+    #[test]
+    fn steps_are_applied_in_order() {
+        let p = PointND::from([3, 4]);
+
+        let scale_first = PointPipeline::<i32, 2>::new().scale(2).translate(PointND::from([1, 1]));
+        let translate_first = PointPipeline::<i32, 2>::new().translate(PointND::from([1, 1])).scale(2);
+
+        assert_eq!(scale_first.apply(p).into_arr(), [7, 9]);
+        assert_eq!(translate_first.apply(p).into_arr(), [8, 10]);
+    }
+
+    #[test]
+    fn empty_pipelines_change_nothing() {
+        let pipeline = PointPipeline::<f64, 3, 0>::new();
+        assert!(pipeline.is_empty());
+        for point in POINTS.map(PointND::from) {
+            assert_eq!(pipeline.apply(point), point);
+        }
+        assert!(PointPipeline::<i8, 2>::default().is_empty());
+    }
+
+    #[test]
+    fn can_fill_pipelines_to_capacity() {
+        let pipeline = PointPipeline::<i32, 1, 3>::new().mirror(0).mirror(0).mirror(0);
+        assert_eq!(pipeline.apply(PointND::from([5])).into_arr(), [-5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_add_steps_past_capacity() {
+        PointPipeline::<i32, 1, 2>::new().scale(1).scale(1).scale(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_mirror_outside_dimensions() {
+        PointPipeline::<i32, 2>::new().mirror(2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_clamp_with_inverted_bounds() {
+        PointPipeline::<i32, 2>::new().clamp(PointND::from([0, 5]), PointND::from([1, 4]));
+    }
+
+}
@@ -1,17 +1,32 @@
 use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::array::TryFromSliceError;
-use core::ops::{Deref, DerefMut};
+use core::borrow::{Borrow, BorrowMut};
+use core::iter::{Product, Sum};
+use core::mem::ManuallyDrop;
+use core::ops::{Deref, DerefMut, Index, IndexMut, Add, Div, Mul, Sub};
+use core::slice::SliceIndex;
 
-#[cfg(any(feature = "x", feature = "y", feature = "z", feature = "w"))]
-use core::ops::AddAssign;
+use core::ops::{AddAssign, SubAssign, MulAssign, DivAssign};
 
-#[cfg(any(feature = "appliers", feature = "var-dims"))]
-use arrayvec::ArrayVec;
-#[cfg(any(feature = "appliers", feature = "var-dims"))]
-use crate::utils::ARRVEC_CAP;
-#[cfg(any(feature = "appliers", feature = "var-dims"))]
-use crate::utils::arrvec_into_inner;
+#[cfg(feature = "z")]
+use core::ops::Neg;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+#[cfg(feature = "num-traits")]
+use num_traits::AsPrimitive;
+
+use crate::utils::{BoundsMode, PartialArray, zip_with};
+use crate::error::{Error, DimensionMismatchError, FromIterError};
+#[cfg(feature = "var-dims")]
+use crate::error::ContractError;
+use crate::iter::{PointIterExt, CentroidItem};
 
+#[cfg(feature = "appliers")]
+use core::ops::{Bound, RangeBounds};
 #[cfg(feature = "appliers")]
 use crate::utils::{ApplyFn, ApplyDimsFn, ApplyValsFn, ApplyPointFn};
 
@@ -33,11 +48,12 @@ As the struct dereferences to a slice, all methods implemented for slices are av
 
 # Making a Point
 
-There are three `PointND` constructors (in order of usefulness): `from()`, `fill()`
-and `from_slice()`.
+The most commonly used `PointND` constructors are (in order of usefulness): `from()`, `fill()`
+and `from_slice()`. Points can also be created from functions and iterators with `from_fn()`
+and `try_from_iter()`.
 
 The `from_slice()` and `fill()` functions can only be used if creating a point where the
-items implement `Copy`
+items implement `Clone`
 
 ```
 # use point_nd::PointND;
@@ -142,7 +158,7 @@ assert_eq!(p.len(), 2);
 # Transforming Values
 
 If the dimensions of the point are within **1..=4**, it is recommended to use the convenience
-`set` and `shift` methods.
+`set`, `shift` and `_mut` methods.
 
 ```
 # use point_nd::PointND;
@@ -165,6 +181,20 @@ p.shift_x(5);
 p.shift_y(25);
 assert_eq!(*p.x(), -5);
 assert_eq!(*p.y(), 5);
+
+// For anything else, the mut getters give mutable
+//  references to the values
+*p.x_mut() *= 3;
+assert_eq!(*p.x(), -15);
+```
+
+The `with` methods consume the point and return it with a value replaced, which is handy for
+deriving points without a mutable binding.
+
+```
+# use point_nd::PointND;
+let p = PointND::<_, 3>::fill(0).with_y(10).with_z(5);
+assert_eq!(p.into_arr(), [0, 10, 5]);
 ```
 
 The above methods are not implemented for `PointND`'s with more than 4 dimensions.
@@ -204,6 +234,9 @@ assert_eq!(p.into_arr(), [6, 9, 12]);
 Each applier has it's own subtle differences, it is recommended to read the documentation for
 each of them
 
+If a function passed to an applier panics, no items will be leaked. See the [notes][notes-panic-safety]
+below for the exact guarantees made.
+
 # Iterating
 
 Iterating over a `PointND` is as easy as:
@@ -214,24 +247,34 @@ let mut p = PointND::from([0,1]);
 
 for _ in p.iter()      { /* Do stuff     */ }
 for _ in p.iter_mut()  { /* Change stuff */ }
-for _ in p.into_iter() { /* Move stuff (unless items implement Copy) */ }
+for _ in p.into_iter() { /* Move stuff   */ }
 ```
 
-It must be noted that if the items implement `Copy`, using `into_iter()` will not actually
-move the point out of scope.
-
-If this behaviour is necessary, use the `into_arr()` method to consume the point and move the
-contained array into the loop
+As with arrays, references to points can be iterated over directly and `into_iter()` always
+consumes the point, even if the items implement `Copy`
 
 ```
 # use point_nd::PointND;
-# let mut p = PointND::from([0,1]);
-for _ in p.into_arr().into_iter() { /* Move stuff */ }
+let mut p = PointND::from([0,1]);
+
+for _ in &p     { /* Do stuff     */ }
+for _ in &mut p { /* Change stuff */ }
+for _ in p      { /* Move stuff   */ }
 
 // ERROR: Can't access moved value
 // assert_eq!(p.dims(), 2);
 ```
 
+Iterators of points can be summed or multiplied together componentwise, starting from the
+zero and one points respectively
+
+```
+# use point_nd::PointND;
+let deltas = [PointND::from([1, 2]), PointND::from([3, -4])];
+let total: PointND<i32, 2> = deltas.iter().sum();
+assert_eq!(total.into_arr(), [4, -2]);
+```
+
 # Things (not strictly necessary) to Note
 
 ### Convenience Methods
@@ -250,6 +293,9 @@ macros have been moved to the [`axmac`][axmac] crate which provides macros to in
 
 The `axmac` crate is **highly recommended** when working with points above 4 dimensions
 
+Points can also be indexed by the `Axis` enum (_e.g._ - `p[Axis::Y]`). Being a type rather than a
+macro, axes can be matched on and stored in data structures, and `Axis::ALL` can be looped over.
+
 ### Math Operations
 
 Unlike structures in other crates, `PointND`'s (as of `v0.5.0`) do not implement mutating
@@ -262,26 +308,86 @@ functions which could be imported and passed to the `apply` methods.
 
 ### Dimensional Capacity
 
-This crate relies heavily on the [`arrayvec`][arrayvec] crate when applying
-transformations to points. Due to the fact that `arrayvec::ArrayVec`'s lengths are capped at
-`u32::MAX`, any `apply`, `extend` and `retain` methods will panic if used on `PointND`'s with
-dimensions exceeding that limit.
+Previous versions of this crate relied on the `arrayvec` crate when applying transformations
+to points, which capped the dimensions of points passed to any `apply`, `extend` and `retain`
+methods at `u32::MAX`.
+
+This is no longer the case. These methods now build their arrays in place and work with
+points of any dimensions.
+
+### Memory Layout
+
+`PointND<T, N>` is `#[repr(transparent)]`, meaning it is **guaranteed** to have the same size,
+alignment and layout as the array `[T; N]` it contains. It is therefore safe to pass points to
+foreign (_e.g._ - C) code expecting `N` contiguous values of type `T`.
+
+This is part of the public API, and will not change without a major version bump.
+
+See the `as_ptr()`, `as_mut_ptr()`, `from_ptr()` and `from_mut_ptr()` methods. Arrays can also be
+borrowed as points without copying using the safe `from_ref()`, `from_mut_ref()`, `cast_slice()` and
+`cast_slice_mut()` methods. With the `bytemuck` feature enabled, points of `Pod` items implement `Pod`
+too, so can be safely cast to and from bytes.
+
+### Panic Safety
+
+The `apply`, `apply_dims`, `apply_vals`, `apply_point`, `extend`, `retain`, `contract`,
+`contract_back`, `try_contract`, `try_contract_back`, `contract_by`, `contract_front`,
+`remove_dim`, `insert_dim`, `split_at`, `resize` and `resize_with` methods all guarantee that,
+if they panic (or a function passed to them panics), every item moved into the method is
+dropped **exactly once**. This includes:
+
+- Items which have already been passed to the function
+- The item the function panicked on
+- Items which were yet to be passed to the function
+
+No items are leaked and none are dropped twice. None of these methods clone items, except for
+`resize`, which clones `fill` for each dimension it adds.
+
+### Hashing and Equality
+
+Points hash exactly the same as the arrays they contain, so can be used interchangeably with
+them as keys. All items are passed to `Hash::hash_slice()` at once, so the items of integer
+points (such as `PointND<u8, 4096>`) are written to the hasher in a single call rather than
+item by item.
 
-This shouldn't be a problem in most use cases (who needs a `u32::MAX + 1` dimensional point
-anyway?), but it is probably worth mentioning.
+Likewise, comparing points compares their arrays directly, which the standard library already
+does with a single `memcmp` for integer items.
+
+### Ordering
+
+Points are ordered **lexicographically** by dimension index, exactly as the arrays they contain
+are. The first items are compared, and only if they are equal are the second items compared, and
+so on. The ordering has nothing to do with the magnitudes of the points.
+
+```
+# use point_nd::PointND;
+assert!(PointND::from([1, 9]) < PointND::from([2, 0]));
+assert!(PointND::from([1, 2, 3]) < PointND::from([1, 2, 4]));
+```
+
+This makes points usable as keys of ordered collections such as `BTreeMap`. Note that the `min()`
+and `max()` methods of `Ord` follow this ordering too. For the componentwise minimum and maximum,
+use `component_min()` and `component_max()` instead.
 
  [axmac]: https://crates.io/crates/axmac
- [arrayvec]: https://crates.io/crates/arrayvec
 
  [notes]: https://docs.rs/point-nd/0.5.0/point_nd/struct.PointND.html#things-not-strictly-necessary-to-note
  [notes-indexing]: https://docs.rs/point-nd/0.5.0/point_nd/struct.PointND.html#direct-indexing
+ [notes-panic-safety]: https://docs.rs/point-nd/0.5.0/point_nd/struct.PointND.html#panic-safety
  */
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(transparent)]
 pub struct PointND<T, const N: usize>([T; N]);
 
+// Reinterprets a PointND as its contained array, for moving the array out in const fns
+union IntoArr<T, const N: usize> {
+    point: ManuallyDrop<PointND<T, N>>,
+    arr: ManuallyDrop<[T; N]>,
+}
+
 // From and Fill
 impl<T, const N: usize> PointND<T, N>
-    where T: Copy {
+    where T: Clone {
 
     /**
      Returns a new `PointND` with values from the specified slice
@@ -302,23 +408,53 @@ impl<T, const N: usize> PointND<T, N>
      let p3 = p1.apply_point(p2, |a, b| a + b);
      ```
 
-     If the length of the slice being passed is uncertain, it is recommended to use the `try_from()`
-     method for more graceful error handling.
+     If the length of the slice being passed is uncertain, it is recommended to use the
+     `try_from_slice()` method for more graceful error handling.
 
      # Panics
 
-     - If the slice passed cannot be converted into an array
+     - If the length of the slice is not equal to the dimensions of the point
 
     ```should_panic
     # use point_nd::PointND;
     let arr = [0,1,2];
-    // ERROR: Cannot convert slice of [i32; 3] to [i32; 100]
+    // ERROR: Cannot create a PointND of 100 dimensions from a slice of 3 items
     let p = PointND::<_, 100>::from_slice(&arr[..]);
     ```
      */
     pub fn from_slice(slice: &[T]) -> Self {
-        let arr: [T; N] = slice.try_into().unwrap();
-        PointND::from(arr)
+        match Self::try_from_slice(slice) {
+            Ok(p) => p,
+            Err(err) => panic!("Attempted to create a PointND of {} dimensions from a slice of {} items",
+                               err.expected, err.found),
+        }
+    }
+
+    ///
+    /// Returns a new `PointND` with values cloned from the specified slice, or an error if
+    /// the length of the slice is not equal to the dimensions of the point
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let v = vec![String::from("a"), String::from("b")];
+    ///
+    /// let p = PointND::<_, 2>::try_from_slice(&v).unwrap();
+    /// assert_eq!(p.into_arr(), ["a", "b"]);
+    ///
+    /// let err = PointND::<_, 3>::try_from_slice(&v).unwrap_err();
+    /// assert_eq!((err.expected, err.found), (3, 2));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - `DimensionMismatchError` if the length of the slice is not equal to the dimensions of
+    ///   the point
+    ///
+    pub fn try_from_slice(slice: &[T]) -> Result<Self, DimensionMismatchError> {
+        match <&[T; N]>::try_from(slice) {
+            Ok(arr) => Ok(PointND::from(arr.clone())),
+            Err(_) => Err(DimensionMismatchError { expected: N, found: slice.len() }),
+        }
     }
 
     ///
@@ -339,1048 +475,6819 @@ impl<T, const N: usize> PointND<T, N>
     /// ```
     ///
     pub fn fill(value: T) -> Self {
-        PointND::from([value; N])
+        let mut arr = PartialArray::<T, N>::new();
+        if N > 0 {
+            for _ in 1..N {
+                arr.push(value.clone());
+            }
+            // Moves the original into the last item rather than cloning it once more
+            arr.push(value);
+        }
+
+        PointND::from(arr.into_inner("fill"))
     }
 
 }
 
-impl<T, const N: usize> PointND<T, N> {
+// Zero, One and Unit Axes
+impl<T, const N: usize> PointND<T, N>
+    where T: Default {
 
     ///
-    /// Returns the number of dimensions of the point (a 2D point will return 2, a 3D point 3, _etc_)
-    ///
-    /// Equivalent to calling ```len()```
-    ///
-    pub fn dims(&self) -> usize {
-        self.0.len()
-    }
-
-    /// Consumes `self`, returning the contained array
-    pub fn into_arr(self) -> [T; N] {
-        self.0
-    }
-
-
+    /// Returns a new `PointND` with all values set to zero, being the `Default` value of `T`
     ///
-    /// Panics with customised error message if specified `cap` is greater than the max `ArrayVec` capacity (`u32::MAX`)
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::<f32, 3>::zero();
+    /// assert_eq!(p.into_arr(), [0.0; 3]);
+    /// ```
     ///
-    #[cfg(any(feature = "appliers", feature = "var-dims"))]
-    fn _check_arrvec_cap(&self, cap: usize, method_name: &str) {
-        if cap > ARRVEC_CAP {
-            panic!("Attempted to call {}() on PointND with more than u32::MAX dimensions",  method_name);
-        }
+    pub fn zero() -> Self {
+        PointND::from_fn(|_| T::default())
     }
 
-
     ///
-    /// Consumes `self` and calls the `modifier` on each item contained
-    /// by `self` to create a new `PointND` of the same length.
+    /// Returns a new `PointND` with all values set to one, being `T::from(1)`
     ///
     /// ```
     /// # use point_nd::PointND;
-    /// let p = PointND
-    ///     ::from([0,1,2])             // Creates a new PointND
-    ///     .apply(|item| item + 2)     // Adds 2 to each item
-    ///     .apply(|item| item * 3);    // Multiplies each item by 3
-    /// assert_eq!(p.into_arr(), [6, 9, 12]);
+    /// let p = PointND::<i32, 3>::one();
+    /// assert_eq!(p.into_arr(), [1; 3]);
     /// ```
     ///
-    /// The return type of the `modifier` does not necessarily have to be
-    /// the same as the type of the items passed to it. This means that ```apply```
-    /// can create a new point with items of a different type, but the same length.
+    pub fn one() -> Self
+        where T: From<u8> {
+        PointND::from_fn(|_| T::from(1))
+    }
+
+    ///
+    /// Returns a new `PointND` with a value of one at the specified `axis` and zero everywhere else
+    ///
+    /// Zero and one are created in the same way as the `zero()` and `one()` methods
     ///
     /// ```
     /// # use point_nd::PointND;
-    /// let p = PointND
-    ///     ::from([0,1,2])                // Creates a new PointND
-    ///     .apply(|item| item as f32);    // Converts items to float
-    /// assert_eq!(p.into_arr(), [0.0, 1.0, 2.0]);
+    /// let p = PointND::<i32, 4>::unit_axis(2);
+    /// assert_eq!(p.into_arr(), [0, 0, 1, 0]);
     /// ```
     ///
-    /// # Enabled by features:
-    ///
-    /// - `default`
-    ///
-    /// - `appliers`
-    ///
     /// # Panics
     ///
-    /// - If the dimensions of `self` are greater than `u32::MAX`.
+    /// - If `axis` is greater than or equal to the dimensions of the point
     ///
-    #[cfg(feature = "appliers")]
-    pub fn apply<U>(self, modifier: ApplyFn<T, U>) -> PointND<U, N> {
-        self._check_arrvec_cap(N, "apply");
-
-        let mut arr_v = ArrayVec::<U, N>::new();
-        let mut this = ArrayVec::from(self.into_arr());
+    /// ```should_panic
+    /// # use point_nd::PointND;
+    /// let p = PointND::<i32, 2>::unit_axis(2);
+    /// ```
+    ///
+    pub fn unit_axis(axis: usize) -> Self
+        where T: From<u8> {
 
-        for _ in 0..N {
-            let item = this.pop_at(0).unwrap();
-            arr_v.push(modifier(item));
+        if axis >= N {
+            panic!("Attempted to create a unit_axis() at axis {} on a PointND of {} dimensions", axis, N);
         }
-
-        PointND::from(
-            arrvec_into_inner(arr_v, "apply")
-        )
+        PointND::from_fn(|i| if i == axis { T::from(1) } else { T::default() })
     }
 
     ///
-    /// Consumes `self` and calls the `modifier` on the items at the
-    /// specified `dims` to create a new `PointND` of the same length.
-    ///
-    /// Any items at dimensions not specified will be passed to the new point without change
+    /// Returns a new `PointND` with a value of one at the specified `axis` and zero everywhere
+    /// else, or an error if `axis` is out of bounds
     ///
     /// ```
-    /// # use point_nd::PointND;
-    /// let p = PointND
-    ///     ::from([0,1,2,3,4])                       // Creates a PointND
-    ///     .apply_dims(&[1,3], |item| item * 2)      // Multiplies items 1 and 3 by 2
-    ///     .apply_dims(&[0,2], |item| item + 10);    // Adds 10 to items 0 and 2
-    /// assert_eq!(p.into_arr(), [10, 2, 12, 6, 4]);
+    /// # use point_nd::{PointND, Error};
+    /// assert_eq!(PointND::<i32, 2>::try_unit_axis(1), Ok(PointND::from([0, 1])));
+    /// assert_eq!(PointND::<i32, 2>::try_unit_axis(2), Err(Error::IndexOutOfBounds { index: 2, dims: 2 }));
     /// ```
     ///
-    /// Unlike some other apply methods, this ```apply_dims``` cannot return
-    /// a `PointND` with items of a different type from the original.
-    ///
-    /// # Enabled by features:
-    ///
-    /// - `default`
-    ///
-    /// - `appliers`
-    ///
-    /// # Panics
+    /// # Errors
     ///
-    /// - If the dimensions of `self` are greater than `u32::MAX`.
+    /// - `Error::IndexOutOfBounds` if `axis` is greater than or equal to the dimensions of the point
     ///
-    #[cfg(feature = "appliers")]
-    pub fn apply_dims(self, dims: &[usize], modifier: ApplyDimsFn<T>) -> Self {
-        self._check_arrvec_cap(N, "apply_dims");
-
-        let mut arr_v = ArrayVec::<T, N>::new();
-        let mut this = ArrayVec::from(self.into_arr());
+    pub fn try_unit_axis(axis: usize) -> Result<Self, Error>
+        where T: From<u8> {
 
-        for i in 0..N {
-            let item = this.pop_at(0).unwrap();
-            if dims.contains(&i) {
-                arr_v.push(modifier(item));
-            } else {
-                arr_v.push(item);
-            }
+        if axis >= N {
+            return Err(Error::IndexOutOfBounds { index: axis, dims: N });
         }
-
-        PointND::from(
-            arrvec_into_inner(arr_v, "apply_dims")
-        )
+        Ok(Self::unit_axis(axis))
     }
 
-    /**
-     Consumes `self` and calls the `modifier` on each item contained by
-     `self` and ```values``` to create a new `PointND` of the same length.
-
-     As this method may modify every value in the original point,
-     the ```values``` array must be the same length as the point.
-
-     When creating a modifier function to be used by this method, keep
-     in mind that the items in `self` are passed to it through the
-     **first arg**, and the items in ```values``` through the **second**.
-
-     ```
-     # use point_nd::PointND;
-     let p = PointND
-         ::from([0,1,2])                      // Creates a new PointND
-         .apply_vals([1,3,5], |a, b| a + b)   // Adds items in point to items in array
-         .apply_vals([2,4,6], |a, b| a * b);  // Multiplies items in point to items in array
-     assert_eq!(p.into_arr(), [2, 16, 42]);
-     ```
-
-     Neither the return type of the `modifier` nor the type of the items contained
-     by the ```values``` array necessarily have to be the same as the item type of the
-     original point. This means that ```apply_vals``` can create a new point with items
-     of a different type, but the same length.
-
-     ```
-     # use point_nd::PointND;
-     enum Op {
-        Add,
-        Sub,
-     }
+}
 
-    // Adds or subtracts 10 from 'a' depending on the
-    //  operation specified in 'b', then converts to float
-    let add_or_sub = |a, b| {
-        match b {
-            Op::Add => (a + 10) as f32,
-            Op::Sub => (a - 10) as f32
+// Implements the UNIT_X, UNIT_Y, UNIT_Z and UNIT_W constants on 2D, 3D and 4D points of each numeric type
+//
+// i8 is included even though it has no one() or unit_axis() (it doesn't implement From<u8>),
+//  as these constants are then the only way to get unit vectors of i8 points
+macro_rules! impl_unit_consts {
+    ($($t:ty),*) => {$(
+
+        #[cfg(feature = "y")]
+        impl PointND<$t, 2> {
+            /// Unit vector along the x axis
+            pub const UNIT_X: Self = PointND([1 as $t, 0 as $t]);
+            /// Unit vector along the y axis
+            pub const UNIT_Y: Self = PointND([0 as $t, 1 as $t]);
         }
-    };
-
-     let p = PointND
-         ::from([0,1,2])
-         .apply_vals(
-             [Op::Add, Op::Sub, Op::Add],
-             add_or_sub
-         );
-     assert_eq!(p.into_arr(), [10.0, -9.0, 12.0]);
-     ```
 
-     # Enabled by features:
+        #[cfg(feature = "z")]
+        impl PointND<$t, 3> {
+            /// Unit vector along the x axis
+            pub const UNIT_X: Self = PointND([1 as $t, 0 as $t, 0 as $t]);
+            /// Unit vector along the y axis
+            pub const UNIT_Y: Self = PointND([0 as $t, 1 as $t, 0 as $t]);
+            /// Unit vector along the z axis
+            pub const UNIT_Z: Self = PointND([0 as $t, 0 as $t, 1 as $t]);
+        }
 
-     - `default`
+        #[cfg(feature = "w")]
+        impl PointND<$t, 4> {
+            /// Unit vector along the x axis
+            pub const UNIT_X: Self = PointND([1 as $t, 0 as $t, 0 as $t, 0 as $t]);
+            /// Unit vector along the y axis
+            pub const UNIT_Y: Self = PointND([0 as $t, 1 as $t, 0 as $t, 0 as $t]);
+            /// Unit vector along the z axis
+            pub const UNIT_Z: Self = PointND([0 as $t, 0 as $t, 1 as $t, 0 as $t]);
+            /// Unit vector along the w axis
+            pub const UNIT_W: Self = PointND([0 as $t, 0 as $t, 0 as $t, 1 as $t]);
+        }
 
-     - `appliers`
+    )*};
+}
 
-     # Panics
+impl_unit_consts!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
 
-     - If the dimensions of `self` or ```values``` are greater than `u32::MAX`.
-     */
-    #[cfg(feature = "appliers")]
-    pub fn apply_vals<U, V>(
-        self,
-        values: [V; N],
-        modifier: ApplyValsFn<T, U, V>
-    ) -> PointND<U, N> {
-        self._check_arrvec_cap(N, "apply_vals");
+///
+/// Constant for stepping between the cells of a 2D grid
+///
+/// # Enabled by features:
+///
+/// - `default`
+///
+/// - `conv_methods`
+///
+/// - `y`
+///
+#[cfg(feature = "y")]
+impl PointND<i32, 2> {
 
-        let mut arr_v = ArrayVec::<U, N>::new();
-        let mut vals = ArrayVec::from(values);
-        let mut this = ArrayVec::from(self.into_arr());
+    ///
+    /// The 8 directions to the neighbours of a grid cell, including diagonals
+    ///
+    /// Starts at `+X` and goes counter-clockwise, in the order `E`, `NE`, `N`, `NW`, `W`,
+    /// `SW`, `S` and `SE` (assuming `+Y` points north)
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let cell = PointND::from([4, 4]);
+    /// let east = PointND::DIRECTIONS_8[0];
+    /// let north_west = PointND::DIRECTIONS_8[3];
+    ///
+    /// assert_eq!(cell.apply_point(east, |a, b| a + b).into_arr(), [5, 4]);
+    /// assert_eq!(cell.apply_point(north_west, |a, b| a + b).into_arr(), [3, 5]);
+    /// ```
+    ///
+    pub const DIRECTIONS_8: [Self; 8] = [
+        PointND([ 1,  0]),
+        PointND([ 1,  1]),
+        PointND([ 0,  1]),
+        PointND([-1,  1]),
+        PointND([-1,  0]),
+        PointND([-1, -1]),
+        PointND([ 0, -1]),
+        PointND([ 1, -1]),
+    ];
 
-        for _ in 0..N {
-            let a = this.pop_at(0).unwrap();
-            let b = vals.pop_at(0).unwrap();
-            arr_v.push(modifier(a, b));
-        }
+}
 
-        PointND::from(
-            // Had to put two method names here as this function is called from apply_point()
-            arrvec_into_inner(arr_v, "apply_vals() or apply_point")
-        )
-    }
+impl<T, const N: usize> PointND<T, N> {
 
     ///
-    /// Consumes `self` and calls the `modifier` on each item contained by
-    /// `self` and another `PointND` to create a new point of the same length.
+    /// Returns a new `PointND` containing the items of `arr`
     ///
-    /// When creating a modifier function to be used by this method, keep
-    /// in mind that the items in `self` are passed to it through the
-    /// **first arg**, and the items in `other` through the **second**.
+    /// This is the same as `PointND::from()`, but can be called in constant contexts
     ///
     /// ```
     /// # use point_nd::PointND;
-    /// let p1 = PointND::from([0,9,3,1]);
-    /// let p2 = PointND::fill(10);
-    /// let p3 = PointND
-    ///     ::from([1,2,3,4])                // Creates a new PointND
-    ///     .apply_point(p1, |a, b| a - b)   // Subtracts items in p3 with those in p1
-    ///     .apply_point(p2, |a, b| a * b);  // Multiplies items in p3 with those in p2
-    /// assert_eq!(p3.into_arr(), [10, -70, 0, 30]);
+    /// const ORIGIN: PointND<i32, 3> = PointND::new([0, 0, 0]);
+    /// assert_eq!(ORIGIN.into_arr(), [0, 0, 0]);
     /// ```
     ///
-    /// Neither the return type of the `modifier` nor the type of the items
-    /// contained by the `other` point necessarily have to be  the same as
-    /// the type of the items in the original point. This means that ```apply_point```
-    /// can create a new point with items of a different type, but the same length.
+    pub const fn new(arr: [T; N]) -> Self {
+        PointND(arr)
+    }
+
     ///
-    /// # Enabled by features:
+    /// Returns a new `PointND` with each item created by calling `f` with its index
     ///
-    /// - `default`
+    /// The function is called with the indexes `0..N` in order
     ///
-    /// - `appliers`
+    /// ```
+    /// # use point_nd::PointND;
+    /// // Creates a basis vector along the y axis
+    /// let axis = 1;
+    /// let p = PointND::<i32, 3>::from_fn(|i| if i == axis { 1 } else { 0 });
+    /// assert_eq!(p.into_arr(), [0, 1, 0]);
+    /// ```
     ///
     /// # Panics
     ///
-    /// - If the dimensions of `self` or `other` are greater than `u32::MAX`.
+    /// - If `f` panics. Any items already created are dropped
     ///
-    #[cfg(feature = "appliers")]
-    pub fn apply_point<U, V>(
-        self,
-        other: PointND<V, N>,
-        modifier: ApplyPointFn<T, U, V>
-    ) -> PointND<U, N> {
-        self._check_arrvec_cap(N, "apply_point");
-
-        self.apply_vals(other.into_arr(), modifier)
+    pub fn from_fn(mut f: impl FnMut(usize) -> T) -> Self {
+        let mut arr = PartialArray::<T, N>::new();
+        for i in 0..N {
+            arr.push(f(i));
+        }
+        PointND::from(arr.into_inner("from_fn"))
     }
 
-    
     ///
-    /// Consumes `self` and returns a new `PointND` with items from `values` appended to
-    /// items from the original.
-    /// 
+    /// Returns a new `PointND` with values yielded by the specified iterator
+    ///
+    /// Exactly `N` items are taken from the iterator. To check that the iterator didn't
+    /// yield too many items, one extra item is consumed if available
+    ///
     /// ```
     /// # use point_nd::PointND;
-    /// let p = PointND
-    ///     ::from([0,1])
-    ///     .extend([2,3]);
-    ///  assert_eq!(p.into_arr(), [0,1,2,3]);
+    /// let data = ["1", "2", "3"];
+    /// let p = PointND::<i32, 3>::try_from_iter(data.iter().map(|s| s.parse().unwrap()));
+    /// assert_eq!(p.unwrap().into_arr(), [1, 2, 3]);
     /// ```
     ///
-    /// # **Warning!**
+    /// # Errors
     ///
-    /// Although we believe it has been tested against the most common use cases, no guarantees are
-    /// made as to the stability of this method.
+    /// - `FromIterError::TooFew` if the iterator yields less than `N` items
     ///
-    /// # Enabled by features:
+    /// - `FromIterError::TooMany` if the iterator yields more than `N` items
     ///
-    /// - `var-dims`
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, FromIterError>
+        where I: IntoIterator<Item = T> {
+
+        let mut iter = iter.into_iter();
+        let mut arr = PartialArray::<T, N>::new();
+
+        for found in 0..N {
+            match iter.next() {
+                Some(item) => arr.push(item),
+                None => return Err(FromIterError::TooFew { expected: N, found }),
+            }
+        }
+        if iter.next().is_some() {
+            return Err(FromIterError::TooMany { expected: N });
+        }
+
+        Ok(PointND::from(arr.into_inner("try_from_iter")))
+    }
+
     ///
-    /// # Panics
+    /// Returns the number of dimensions of the point (a 2D point will return 2, a 3D point 3, _etc_)
     ///
-    /// - If the combined length of `self` and `values` are greater than `u32::MAX`.
+    /// Equivalent to calling ```len()```
     ///
-    /// ```should_panic
+    pub const fn dims(&self) -> usize {
+        N
+    }
+
+    /// Consumes `self`, returning the contained array
+    pub const fn into_arr(self) -> [T; N] {
+        // Moving out of self.0 directly isn't allowed in a const fn, as the compiler can't
+        //  tell that no destructor of self would run, so the point is reinterpreted instead
+        // SAFETY: PointND is repr(transparent), so has the same layout as the array it contains
+        let arr = unsafe { IntoArr { point: ManuallyDrop::new(self) }.arr };
+        ManuallyDrop::into_inner(arr)
+    }
+
+    ///
+    /// Returns a reference to the contained array
+    ///
+    /// This is the canonical way of borrowing the array of a point. Unlike dereferencing
+    /// (which is an array too, but is often coerced to a slice), this is always an array
+    ///
+    /// ```
     /// # use point_nd::PointND;
-    /// const N: usize = u32::MAX as usize;
-    /// const L: usize = 1;
-    /// const M: usize = N + L;
+    /// let p = PointND::from([0, 1, 2]);
+    /// let arr: &[i32; 3] = p.arr();
+    /// assert_eq!(arr, &[0, 1, 2]);
+    /// ```
+    ///
+    pub const fn arr(&self) -> &[T; N] {
+        &self.0
+    }
+
     ///
-    /// let p: PointND<_, M> = PointND
-    ///     ::from([0; N])
-    ///     .extend([1; L]);
+    /// Returns a mutable reference to the contained array
+    ///
+    /// See `arr()` for more info
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let mut p = PointND::from([0, 1, 2]);
+    /// p.arr_mut().reverse();
+    /// assert_eq!(p.into_arr(), [2, 1, 0]);
     /// ```
     ///
-    #[cfg(feature = "var-dims")]
-    pub fn extend<const L: usize, const M: usize>(self, values: [T; L]) -> PointND<T, M> {
-        self._check_arrvec_cap(N, "extend");
-        if N + L > ARRVEC_CAP {
-            panic!("Attempted to extend() a PointND to more than u32::MAX dimensions");
-        }
+    pub fn arr_mut(&mut self) -> &mut [T; N] {
+        &mut self.0
+    }
 
-        let mut arr_v = ArrayVec::<T, M>::new();
-        let mut this = ArrayVec::from(self.into_arr());
-        let mut vals = ArrayVec::from(values);
+    ///
+    /// Consumes a boxed point, returning its array in the same box
+    ///
+    /// The items are neither copied nor moved, making this useful for points too large to fit
+    /// on the stack
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = Box::new(PointND::from([0, 1, 2]));
+    /// let arr: Box<[i32; 3]> = p.into_boxed_arr();
+    /// assert_eq!(*arr, [0, 1, 2]);
+    /// ```
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `alloc`
+    ///
+    #[cfg(feature = "alloc")]
+    pub fn into_boxed_arr(self: Box<Self>) -> Box<[T; N]> {
+        // SAFETY: PointND is repr(transparent) over [T; N], so the allocation has the
+        //  same layout for both types and can be freed as either
+        unsafe { Box::from_raw(Box::into_raw(self) as *mut [T; N]) }
+    }
 
-        for _ in 0..N { arr_v.push(this.pop_at(0).unwrap()); }
-        for _ in 0..L { arr_v.push(vals.pop_at(0).unwrap());  }
+    ///
+    /// Returns a reference to the first item of the point, or `None` if the point has zero dimensions
+    ///
+    /// Unlike `x()`, this is available for points of any dimensions, making it useful in functions
+    /// which are generic over the dimensions of a point
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([0, 1, 2]);
+    /// assert_eq!(p.dim_x(), Some(&0));
+    /// ```
+    ///
+    pub fn dim_x(&self) -> Option<&T> { self.first() }
 
-        PointND::from(
-            arrvec_into_inner(arr_v, "extend")
-        )
-    }
+    ///
+    /// Returns a reference to the second item of the point, or `None` if the point has less than 2 dimensions
+    ///
+    /// See `dim_x()` for more info
+    ///
+    pub fn dim_y(&self) -> Option<&T> { self.get(1) }
 
     ///
-    /// Consumes `self` and returns a new `PointND` which retains only the first `dims` items of the
-    /// original.
+    /// Returns a reference to the third item of the point, or `None` if the point has less than 3 dimensions
     ///
-    /// This method always removes the rearmost items first.
+    /// See `dim_x()` for more info
     ///
     /// ```
     /// # use point_nd::PointND;
-    /// let p = PointND
-    ///     ::from([0,1,2,3])
-    ///     .retain(2);
-    /// assert_eq!(p.dims(), 2);
-    /// assert_eq!(p.into_arr(), [0,1]);
+    /// // Can be called on points without a z() method
+    /// let p = PointND::from([0, 1]);
+    /// assert_eq!(p.dim_z(), None);
     /// ```
     ///
-    /// # **Warning!**
+    pub fn dim_z(&self) -> Option<&T> { self.get(2) }
+
     ///
-    /// Although we believe it has been tested against the most common use cases, no guarantees are
-    /// made as to the stability of this method.
+    /// Returns a reference to the fourth item of the point, or `None` if the point has less than 4 dimensions
     ///
-    /// # Enabled by features:
+    /// See `dim_x()` for more info
     ///
-    /// - `var-dims`
+    pub fn dim_w(&self) -> Option<&T> { self.get(3) }
+
     ///
-    /// # Panics
+    /// Consumes `self` and returns it with the value at the specified `dim` replaced by `value`
     ///
-    /// - If `dims` is greater than the original dimensions of the point (_a.k.a_ - you cannot
-    ///   shorten the dimensions of a point to more than it had originally).
+    /// Useful for deriving points in expression position without a mutable binding
     ///
-    /// ```should_panic
+    /// ```
     /// # use point_nd::PointND;
-    /// let p = PointND
-    ///     ::from([0,1,2])
-    ///     .retain(1_000_000);
-    /// # // Just to silence the type error
-    /// # let _p2 = PointND::from([0,1,2]).apply_point(p, |a, b| a + b);
+    /// let p = PointND::<_, 5>::fill(0)
+    ///     .with_dim(1, 10)
+    ///     .with_dim(4, -10);
+    /// assert_eq!(p.into_arr(), [0, 10, 0, 0, -10]);
     /// ```
     ///
-    /// - If the dimensions of `self` are greater than `u32::MAX`.
+    /// # Panics
     ///
-    #[cfg(feature = "var-dims")]
-    pub fn retain<const M: usize>(self, dims: usize) -> PointND<T, M> {
-        self._check_arrvec_cap(N, "retain");
-        // This check allows us to safely unwrap the values in self
-        if dims > N || M > N {
-            panic!("Attempted to contract PointND to more dimensions than it had originally. Try \
-                    passing a usize value that is less than the dimensions of the original point");
+    /// - If `dim` is greater than or equal to the dimensions of the point
+    ///
+    pub fn with_dim(mut self, dim: usize, value: T) -> Self {
+        if dim >= N {
+            panic!("Attempted to set dimension {} of a PointND with {} dimensions", dim, N);
         }
+        self[dim] = value;
+        self
+    }
 
-        let mut arr_v = ArrayVec::<T, M>::new();
-        let mut this = ArrayVec::from(self.into_arr());
-
-        for _ in 0..dims {
-            let item = this.pop_at(0).unwrap();
-            arr_v.push(item);
+    ///
+    /// Consumes `self` and returns it with the value at the specified `dim` replaced by `value`,
+    /// or an error if `dim` is out of bounds
+    ///
+    /// ```
+    /// # use point_nd::{PointND, Error};
+    /// let p = PointND::from([0, 0]);
+    /// assert_eq!(p.try_with_dim(1, 10), Ok(PointND::from([0, 10])));
+    /// assert_eq!(p.try_with_dim(2, 10), Err(Error::IndexOutOfBounds { index: 2, dims: 2 }));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - `Error::IndexOutOfBounds` if `dim` is greater than or equal to the dimensions of the point
+    ///
+    pub fn try_with_dim(self, dim: usize, value: T) -> Result<Self, Error> {
+        if dim >= N {
+            return Err(Error::IndexOutOfBounds { index: dim, dims: N });
         }
-
-        PointND::from(
-            arrvec_into_inner(arr_v, "retain")
-        )
+        Ok(self.with_dim(dim, value))
     }
 
-}
-
-
-// Deref
-impl<T, const N: usize> Deref for PointND<T, N> {
+    ///
+    /// Returns a raw pointer to the first item of the point
+    ///
+    /// As `PointND` has the same layout as `[T; N]`, the pointer can be used to read `N`
+    /// contiguous items. The caller must ensure the point outlives the pointer
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([0, 1, 2]);
+    /// let ptr = p.as_ptr();
+    /// assert_eq!(unsafe { *ptr.add(2) }, 2);
+    /// ```
+    ///
+    pub fn as_ptr(&self) -> *const T {
+        self.0.as_ptr()
+    }
 
-    type Target = [T; N];
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    ///
+    /// Returns a raw mutable pointer to the first item of the point
+    ///
+    /// See `as_ptr()` for more info
+    ///
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.0.as_mut_ptr()
     }
 
-}
+    ///
+    /// Returns a reference to a `PointND` made from the `N` contiguous items at `ptr`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let arr = [0, 1, 2, 3];
+    /// let p: &PointND<i32, 4> = unsafe { PointND::from_ptr(arr.as_ptr()) };
+    /// assert_eq!(p[..], arr[..]);
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// - `ptr` must be non-null, aligned for `T` and valid for reads of `N` contiguous,
+    ///   initialised items of type `T`
+    ///
+    /// - The items must not be mutated (except through an `UnsafeCell`) for the lifetime `'a`,
+    ///   which the caller must ensure does not outlive the memory being pointed to
+    ///
+    pub unsafe fn from_ptr<'a>(ptr: *const T) -> &'a PointND<T, N> {
+        // SAFETY: PointND is repr(transparent) over [T; N], so the pointer
+        //  cast is valid. The caller upholds the remaining requirements
+        unsafe { &*(ptr as *const PointND<T, N>) }
+    }
 
-impl<T, const N: usize> DerefMut for PointND<T, N> {
+    ///
+    /// Returns a mutable reference to a `PointND` made from the `N` contiguous items at `ptr`
+    ///
+    /// # Safety
+    ///
+    /// - `ptr` must be non-null, aligned for `T` and valid for reads and writes of `N`
+    ///   contiguous, initialised items of type `T`
+    ///
+    /// - The items must not be accessed through any other pointer for the lifetime `'a`,
+    ///   which the caller must ensure does not outlive the memory being pointed to
+    ///
+    pub unsafe fn from_mut_ptr<'a>(ptr: *mut T) -> &'a mut PointND<T, N> {
+        // SAFETY: See from_ptr()
+        unsafe { &mut *(ptr as *mut PointND<T, N>) }
+    }
 
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+    ///
+    /// Returns a reference to `arr` as a `PointND`, without copying or taking ownership of it
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let arr = [0, 1, 2];
+    /// let p = PointND::from_ref(&arr);
+    /// assert_eq!(*p.z(), 2);
+    /// ```
+    ///
+    pub fn from_ref(arr: &[T; N]) -> &PointND<T, N> {
+        // SAFETY: The array is borrowed for the lifetime of the returned reference
+        unsafe { PointND::from_ptr(arr.as_ptr()) }
     }
 
-}
+    ///
+    /// Returns a mutable reference to `arr` as a `PointND`, without copying or taking ownership of it
+    ///
+    /// Changes made through the point are made to `arr`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let mut arr = [0, 1, 2];
+    /// PointND::from_mut_ref(&mut arr).set_x(10);
+    /// assert_eq!(arr, [10, 1, 2]);
+    /// ```
+    ///
+    pub fn from_mut_ref(arr: &mut [T; N]) -> &mut PointND<T, N> {
+        // SAFETY: The array is mutably borrowed for the lifetime of the returned reference
+        unsafe { PointND::from_mut_ptr(arr.as_mut_ptr()) }
+    }
 
+    ///
+    /// Returns a reference to the array contained by the point
+    ///
+    /// Equivalent to calling `arr()`
+    ///
+    pub fn as_array(&self) -> &[T; N] {
+        self.arr()
+    }
 
-// Convenience Getters and Setters
-///
-/// Methods for safely getting and setting the value contained by a 1D `PointND`
-///
-/// # Enabled by features:
-///
-/// - `default`
-///
-/// - `conv_methods`
-///
-/// - `x`
-///
-#[cfg(feature = "x")]
-impl<T> PointND<T, 1> {
+    ///
+    /// Returns a mutable reference to the array contained by the point
+    ///
+    /// Equivalent to calling `arr_mut()`
+    ///
+    pub fn as_mut_array(&mut self) -> &mut [T; N] {
+        self.arr_mut()
+    }
 
-    pub fn x(&self) -> &T { &self[0] }
+    ///
+    /// Returns a slice of arrays as a slice of `PointND`'s, without copying any items
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let rows = [[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]];
+    /// let points = PointND::cast_slice(&rows);
+    /// assert_eq!(points.len(), 3);
+    /// assert_eq!(*points[2].y(), 5.0);
+    /// ```
+    ///
+    pub fn cast_slice(arrs: &[[T; N]]) -> &[PointND<T, N>] {
+        // SAFETY: PointND is repr(transparent) over [T; N], so slices of
+        //  each have the same layout. The slice is borrowed for the lifetime
+        //  of the returned slice
+        unsafe { core::slice::from_raw_parts(arrs.as_ptr() as *const PointND<T, N>, arrs.len()) }
+    }
 
-    pub fn set_x(&mut self, new_value: T) { self[0] = new_value; }
+    ///
+    /// Returns a mutable slice of arrays as a mutable slice of `PointND`'s, without copying any items
+    ///
+    /// See `cast_slice()` for more info
+    ///
+    pub fn cast_slice_mut(arrs: &mut [[T; N]]) -> &mut [PointND<T, N>] {
+        // SAFETY: See cast_slice()
+        unsafe { core::slice::from_raw_parts_mut(arrs.as_mut_ptr() as *mut PointND<T, N>, arrs.len()) }
+    }
 
-}
-///
-/// Methods for safely getting and setting the values contained by a 2D `PointND`
-///
-/// # Enabled by features:
-///
-/// - `default`
-///
-/// - `conv_methods`
-///
-/// - `y`
-///
-#[cfg(feature = "y")]
-impl<T> PointND<T, 2> {
+    ///
+    /// Returns a reference to a `PointND` of the `M` items of `self` starting at `start`
+    ///
+    /// No items are copied, as the view borrows them from `self`. This gives access to all the
+    /// read only methods of `PointND` for a subset of dimensions, which slicing does not
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([0, 1, 2, 3, 4, 5, 6]);
+    /// let view = p.view::<3>(2);
+    /// assert_eq!((*view.x(), *view.y(), *view.z()), (2, 3, 4));
+    /// ```
+    ///
+    /// The view cannot outlive the point it borrows from:
+    ///
+    /// ```compile_fail
+    /// # use point_nd::PointND;
+    /// let view = {
+    ///     let p = PointND::from([0, 1, 2, 3]);
+    ///     // ERROR: p does not live long enough
+    ///     p.view::<2>(1)
+    /// };
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - If `start + M` is greater than the dimensions of the point
+    ///
+    pub fn view<const M: usize>(&self, start: usize) -> &PointND<T, M> {
+        if start > N || M > N - start {
+            panic!("Attempted to view {} dimensions from dimension {} of a PointND with {} dimensions",
+                   M, start, N);
+        }
+        // SAFETY: The M items from start are within self, so are initialised and
+        //  contiguous. They are borrowed from self for the lifetime of the view
+        unsafe { PointND::from_ptr(self.as_ptr().add(start)) }
+    }
 
-    pub fn x(&self) -> &T { &self[0] }
-    pub fn y(&self) -> &T { &self[1] }
+    ///
+    /// Returns a view of the `M` items of `self` starting at dimension `start`, or an error if
+    /// they are not all within the point
+    ///
+    /// ```
+    /// # use point_nd::{PointND, Error};
+    /// let p = PointND::from([0, 1, 2, 3]);
+    /// assert_eq!(p.try_view::<2>(2).map(|v| v.into_arr()), Ok([2, 3]));
+    /// assert_eq!(p.try_view::<2>(3), Err(Error::IndexOutOfBounds { index: 4, dims: 4 }));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - `Error::IndexOutOfBounds` if `start + M` is greater than the dimensions of the point.
+    ///   The error contains the index of the last item which would be viewed
+    ///
+    pub fn try_view<const M: usize>(&self, start: usize) -> Result<&PointND<T, M>, Error> {
+        if start > N || M > N - start {
+            return Err(Error::IndexOutOfBounds { index: start.saturating_add(M) - 1, dims: N });
+        }
+        Ok(self.view(start))
+    }
 
-    pub fn set_x(&mut self, new_value: T) { self[0] = new_value; }
-    pub fn set_y(&mut self, new_value: T) { self[1] = new_value; }
 
-}
-///
+    ///
+    /// Consumes `self` and calls the `modifier` on each item contained
+    /// by `self` to create a new `PointND` of the same length.
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND
+    ///     ::from([0,1,2])             // Creates a new PointND
+    ///     .apply(|item| item + 2)     // Adds 2 to each item
+    ///     .apply(|item| item * 3);    // Multiplies each item by 3
+    /// assert_eq!(p.into_arr(), [6, 9, 12]);
+    /// ```
+    ///
+    /// The return type of the `modifier` does not necessarily have to be
+    /// the same as the type of the items passed to it. This means that ```apply```
+    /// can create a new point with items of a different type, but the same length.
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND
+    ///     ::from([0,1,2])                // Creates a new PointND
+    ///     .apply(|item| item as f32);    // Converts items to float
+    /// assert_eq!(p.into_arr(), [0.0, 1.0, 2.0]);
+    /// ```
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `default`
+    ///
+    /// - `appliers`
+    ///
+    #[cfg(feature = "appliers")]
+    pub fn apply<U>(self, modifier: ApplyFn<T, U>) -> PointND<U, N> {
+        PointND::from(self.into_arr().map(modifier))
+    }
+
+    ///
+    /// Consumes `self` and calls the `modifier` on the items at the
+    /// specified `dims` to create a new `PointND` of the same length.
+    ///
+    /// Any items at dimensions not specified will be passed to the new point without change
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND
+    ///     ::from([0,1,2,3,4])                       // Creates a PointND
+    ///     .apply_dims(&[1,3], |item| item * 2)      // Multiplies items 1 and 3 by 2
+    ///     .apply_dims(&[0,2], |item| item + 10);    // Adds 10 to items 0 and 2
+    /// assert_eq!(p.into_arr(), [10, 2, 12, 6, 4]);
+    /// ```
+    ///
+    /// Unlike some other apply methods, this ```apply_dims``` cannot return
+    /// a `PointND` with items of a different type from the original.
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `default`
+    ///
+    /// - `appliers`
+    ///
+    /// # Panics
+    ///
+    /// - If any of the `dims` are greater than or equal to the dimensions of the point
+    ///
+    #[cfg(feature = "appliers")]
+    pub fn apply_dims(self, dims: &[usize], modifier: ApplyDimsFn<T>) -> Self {
+        if let Some(dim) = dims.iter().find(|dim| **dim >= N) {
+            panic!("Attempted to apply a modifier to dimension {} of a PointND with {} dimensions",
+                   dim, N);
+        }
+
+        let mut arr = PartialArray::<T, N>::new();
+        for (i, item) in self.into_arr().into_iter().enumerate() {
+            if dims.contains(&i) {
+                arr.push(modifier(item));
+            } else {
+                arr.push(item);
+            }
+        }
+
+        PointND::from(arr.into_inner("apply_dims"))
+    }
+
+    ///
+    /// Consumes `self` and calls the `modifier` on the items at the specified `dims`, or
+    /// returns an error if any of the `dims` are out of bounds
+    ///
+    /// ```
+    /// # use point_nd::{PointND, Error};
+    /// let p = PointND::from([0,1,2]);
+    /// assert_eq!(p.try_apply_dims(&[0,2], |item| item + 10), Ok(PointND::from([10,1,12])));
+    /// assert_eq!(p.try_apply_dims(&[0,7], |item| item + 10), Err(Error::IndexOutOfBounds { index: 7, dims: 3 }));
+    /// ```
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `default`
+    ///
+    /// - `appliers`
+    ///
+    /// # Errors
+    ///
+    /// - `Error::IndexOutOfBounds` if any of the `dims` are greater than or equal to the
+    ///   dimensions of the point
+    ///
+    #[cfg(feature = "appliers")]
+    pub fn try_apply_dims(self, dims: &[usize], modifier: ApplyDimsFn<T>) -> Result<Self, Error> {
+        if let Some(dim) = dims.iter().find(|dim| **dim >= N) {
+            return Err(Error::IndexOutOfBounds { index: *dim, dims: N });
+        }
+        Ok(self.apply_dims(dims, modifier))
+    }
+
+    ///
+    /// Consumes `self` and calls the `modifier` on the items at the dimensions within
+    /// `range` to create a new `PointND` of the same length.
+    ///
+    /// Any items at dimensions outside the range will be passed to the new point without
+    /// change. Unbounded ends reach to the first or last dimension of the point, and empty
+    /// ranges (_e.g._ - `2..2` or `5..5`) leave the point unchanged, even if they are beyond
+    /// its dimensions
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND
+    ///     ::from([0,1,2,3,4])                    // Creates a PointND
+    ///     .apply_range(1..=3, |item| item * 2)   // Multiplies items 1 to 3 by 2
+    ///     .apply_range(4.., |item| item + 10);   // Adds 10 to the last item
+    /// assert_eq!(p.into_arr(), [0, 2, 4, 6, 14]);
+    /// ```
+    ///
+    /// Like ```apply_dims```, this cannot return a `PointND` with items of a different type
+    /// from the original.
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `default`
+    ///
+    /// - `appliers`
+    ///
+    /// # Panics
+    ///
+    /// - If `range` is not empty and its end is beyond the dimensions of the point
+    ///
+    /// - If `range` has no end and its start is beyond the dimensions of the point
+    ///   (_e.g._ - `7..` on a 3D point)
+    ///
+    #[cfg(feature = "appliers")]
+    pub fn apply_range<R>(self, range: R, modifier: ApplyDimsFn<T>) -> Self
+        where R: RangeBounds<usize> {
+
+        let start = match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => end.saturating_add(1),
+            Bound::Excluded(end) => *end,
+            Bound::Unbounded if start > N => {
+                panic!("Attempted to apply a modifier to dimensions from {} of a PointND with {} dimensions",
+                       start, N);
+            },
+            Bound::Unbounded => N,
+        };
+        if start >= end {
+            return self;
+        }
+        if end > N {
+            panic!("Attempted to apply a modifier to dimensions up to {} of a PointND with {} dimensions",
+                   end, N);
+        }
+
+        let mut arr = PartialArray::<T, N>::new();
+        for (i, item) in self.into_arr().into_iter().enumerate() {
+            if start <= i && i < end {
+                arr.push(modifier(item));
+            } else {
+                arr.push(item);
+            }
+        }
+
+        PointND::from(arr.into_inner("apply_range"))
+    }
+
+    /**
+     Consumes `self` and calls the `modifier` on each item contained by
+     `self` and ```values``` to create a new `PointND` of the same length.
+
+     As this method may modify every value in the original point,
+     the ```values``` array must be the same length as the point.
+
+     When creating a modifier function to be used by this method, keep
+     in mind that the items in `self` are passed to it through the
+     **first arg**, and the items in ```values``` through the **second**.
+
+     ```
+     # use point_nd::PointND;
+     let p = PointND
+         ::from([0,1,2])                      // Creates a new PointND
+         .apply_vals([1,3,5], |a, b| a + b)   // Adds items in point to items in array
+         .apply_vals([2,4,6], |a, b| a * b);  // Multiplies items in point to items in array
+     assert_eq!(p.into_arr(), [2, 16, 42]);
+     ```
+
+     Neither the return type of the `modifier` nor the type of the items contained
+     by the ```values``` array necessarily have to be the same as the item type of the
+     original point. This means that ```apply_vals``` can create a new point with items
+     of a different type, but the same length.
+
+     ```
+     # use point_nd::PointND;
+     enum Op {
+        Add,
+        Sub,
+     }
+
+    // Adds or subtracts 10 from 'a' depending on the
+    //  operation specified in 'b', then converts to float
+    let add_or_sub = |a, b| {
+        match b {
+            Op::Add => (a + 10) as f32,
+            Op::Sub => (a - 10) as f32
+        }
+    };
+
+     let p = PointND
+         ::from([0,1,2])
+         .apply_vals(
+             [Op::Add, Op::Sub, Op::Add],
+             add_or_sub
+         );
+     assert_eq!(p.into_arr(), [10.0, -9.0, 12.0]);
+     ```
+
+     # Enabled by features:
+
+     - `default`
+
+     - `appliers`
+     */
+    #[cfg(feature = "appliers")]
+    pub fn apply_vals<U, V>(
+        self,
+        values: [V; N],
+        modifier: ApplyValsFn<T, U, V>
+    ) -> PointND<U, N> {
+        let mut arr = PartialArray::<U, N>::new();
+        for (a, b) in self.into_arr().into_iter().zip(values) {
+            arr.push(modifier(a, b));
+        }
+
+        PointND::from(
+            // Had to put two method names here as this function is called from apply_point()
+            arr.into_inner("apply_vals() or apply_point")
+        )
+    }
+
+    ///
+    /// Consumes `self` and calls the `modifier` on each item contained by
+    /// `self` and another `PointND` to create a new point of the same length.
+    ///
+    /// When creating a modifier function to be used by this method, keep
+    /// in mind that the items in `self` are passed to it through the
+    /// **first arg**, and the items in `other` through the **second**.
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p1 = PointND::from([0,9,3,1]);
+    /// let p2 = PointND::fill(10);
+    /// let p3 = PointND
+    ///     ::from([1,2,3,4])                // Creates a new PointND
+    ///     .apply_point(p1, |a, b| a - b)   // Subtracts items in p3 with those in p1
+    ///     .apply_point(p2, |a, b| a * b);  // Multiplies items in p3 with those in p2
+    /// assert_eq!(p3.into_arr(), [10, -70, 0, 30]);
+    /// ```
+    ///
+    /// Neither the return type of the `modifier` nor the type of the items
+    /// contained by the `other` point necessarily have to be  the same as
+    /// the type of the items in the original point. This means that ```apply_point```
+    /// can create a new point with items of a different type, but the same length.
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `default`
+    ///
+    /// - `appliers`
+    ///
+    #[cfg(feature = "appliers")]
+    pub fn apply_point<U, V>(
+        self,
+        other: PointND<V, N>,
+        modifier: ApplyPointFn<T, U, V>
+    ) -> PointND<U, N> {
+        self.apply_vals(other.into_arr(), modifier)
+    }
+
+    ///
+    /// Calls `f` with the index of and a reference to each item in `self`, stopping at the
+    /// first error returned
+    ///
+    /// Unlike the appliers, this only reads the items, making it suited to validating them
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([2, -1, 5]);
+    /// let res = p.try_for_each(|i, item| {
+    ///     if *item < 0 { Err(i) } else { Ok(()) }
+    /// });
+    /// assert_eq!(res, Err(1));
+    /// ```
+    ///
+    pub fn try_for_each<E>(&self, mut f: impl FnMut(usize, &T) -> Result<(), E>) -> Result<(), E> {
+        for (i, item) in self.iter().enumerate() {
+            f(i, item)?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the index of the first item in `self` for which `pred` returns `true`, or `None`
+    /// if it returns `false` for every item
+    ///
+    /// No items after the first match are passed to `pred`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([0.5, f64::NAN, 2.0]);
+    /// assert_eq!(p.position(|item| item.is_nan()), Some(1));
+    /// ```
+    ///
+    pub fn position(&self, pred: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(pred)
+    }
+
+    
+    ///
+    /// Consumes `self` and returns a new `PointND` with items from `values` appended to
+    /// items from the original.
+    /// 
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND
+    ///     ::from([0,1])
+    ///     .extend([2,3]);
+    ///  assert_eq!(p.into_arr(), [0,1,2,3]);
+    /// ```
+    ///
+    /// # **Warning!**
+    ///
+    /// Although we believe it has been tested against the most common use cases, no guarantees are
+    /// made as to the stability of this method.
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `var-dims`
+    ///
+    /// # Panics
+    ///
+    /// - If the dimensions of the returned point are not equal to the combined length of `self`
+    ///   and `values`.
+    ///
+    /// ```should_panic
+    /// # use point_nd::PointND;
+    /// let p: PointND<_, 10> = PointND
+    ///     ::from([0,1])
+    ///     .extend([2,3]);
+    /// ```
+    ///
+    #[cfg(feature = "var-dims")]
+    pub fn extend<const L: usize, const M: usize>(self, values: [T; L]) -> PointND<T, M> {
+        if N + L != M {
+            panic!("Attempted to extend() a PointND of {} dimensions with {} values into a PointND of {} dimensions",
+                   N, L, M);
+        }
+
+        let mut arr = PartialArray::<T, M>::new();
+        for item in self.into_arr() { arr.push(item); }
+        for item in values          { arr.push(item); }
+
+        PointND::from(arr.into_inner("extend"))
+    }
+
+    ///
+    /// Consumes `self` and returns a new `PointND` which retains only the first `dims` items of the
+    /// original.
+    ///
+    /// This method always removes the rearmost items first.
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND
+    ///     ::from([0,1,2,3])
+    ///     .retain(2);
+    /// assert_eq!(p.dims(), 2);
+    /// assert_eq!(p.into_arr(), [0,1]);
+    /// ```
+    ///
+    /// # **Warning!**
+    ///
+    /// Although we believe it has been tested against the most common use cases, no guarantees are
+    /// made as to the stability of this method.
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `var-dims`
+    ///
+    /// # Panics
+    ///
+    /// - If `dims` is greater than the original dimensions of the point (_a.k.a_ - you cannot
+    ///   shorten the dimensions of a point to more than it had originally).
+    ///
+    /// ```should_panic
+    /// # use point_nd::PointND;
+    /// let p = PointND
+    ///     ::from([0,1,2])
+    ///     .retain(1_000_000);
+    /// # // Just to silence the type error
+    /// # let _p2 = PointND::from([0,1,2]).apply_point(p, |a, b| a + b);
+    /// ```
+    ///
+    #[cfg(feature = "var-dims")]
+    #[deprecated(note = "the dims arg must always equal M, use contract() instead")]
+    pub fn retain<const M: usize>(self, dims: usize) -> PointND<T, M> {
+        // This check ensures the array we build from self is always filled
+        if dims > N || M > N {
+            panic!("Attempted to contract PointND to more dimensions than it had originally. Try \
+                    passing a usize value that is less than the dimensions of the original point");
+        }
+
+        let mut arr = PartialArray::<T, M>::new();
+        for item in self.into_arr().into_iter().take(dims) {
+            arr.push(item);
+        }
+
+        PointND::from(arr.into_inner("retain"))
+    }
+
+    ///
+    /// Consumes `self` and returns a new `PointND` which keeps only the first `M` items of the
+    /// original.
+    ///
+    /// This replaces `retain()`, taking the dimensions to keep from the returned point alone.
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p: PointND<_, 2> = PointND
+    ///     ::from([0,1,2,3])
+    ///     .contract();
+    /// assert_eq!(p.into_arr(), [0,1]);
+    /// ```
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `var-dims`
+    ///
+    /// # Panics
+    ///
+    /// - If `M` is greater than the original dimensions of the point. Use `try_contract()`
+    ///   to return an error instead.
+    ///
+    #[cfg(feature = "var-dims")]
+    pub fn contract<const M: usize>(self) -> PointND<T, M> {
+        match self.try_contract() {
+            Ok(p) => p,
+            Err(_) => panic!("Attempted to contract() a PointND of {} dimensions into a PointND of {} dimensions",
+                             N, M),
+        }
+    }
+
+    ///
+    /// Consumes `self` and returns a new `PointND` which keeps only the last `M` items of the
+    /// original.
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p: PointND<_, 2> = PointND
+    ///     ::from([0,1,2,3])
+    ///     .contract_back();
+    /// assert_eq!(p.into_arr(), [2,3]);
+    /// ```
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `var-dims`
+    ///
+    /// # Panics
+    ///
+    /// - If `M` is greater than the original dimensions of the point. Use `try_contract_back()`
+    ///   to return an error instead.
+    ///
+    #[cfg(feature = "var-dims")]
+    pub fn contract_back<const M: usize>(self) -> PointND<T, M> {
+        match self.try_contract_back() {
+            Ok(p) => p,
+            Err(_) => panic!("Attempted to contract_back() a PointND of {} dimensions into a PointND of {} dimensions",
+                             N, M),
+        }
+    }
+
+    ///
+    /// Consumes `self` and returns a new `PointND` which keeps only the first `M` items of the
+    /// original, or an error if `M` is greater than the original dimensions
+    ///
+    /// ```
+    /// # use point_nd::{PointND, ContractError};
+    /// let p = PointND::from([0,1,2]);
+    /// assert_eq!(p.try_contract::<2>(), Ok(PointND::from([0,1])));
+    /// assert_eq!(p.try_contract::<4>(), Err(ContractError { dims: 3, requested: 4 }));
+    /// ```
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `var-dims`
+    ///
+    /// # Errors
+    ///
+    /// - `ContractError` if `M` is greater than the original dimensions of the point
+    ///
+    #[cfg(feature = "var-dims")]
+    pub fn try_contract<const M: usize>(self) -> Result<PointND<T, M>, ContractError> {
+        if M > N {
+            return Err(ContractError { dims: N, requested: M });
+        }
+
+        let mut arr = PartialArray::<T, M>::new();
+        for item in self.into_arr().into_iter().take(M) {
+            arr.push(item);
+        }
+
+        Ok(PointND::from(arr.into_inner("try_contract")))
+    }
+
+    ///
+    /// Consumes `self` and returns a new `PointND` which keeps only the last `M` items of the
+    /// original, or an error if `M` is greater than the original dimensions
+    ///
+    /// ```
+    /// # use point_nd::{PointND, ContractError};
+    /// let p = PointND::from([0,1,2]);
+    /// assert_eq!(p.try_contract_back::<2>(), Ok(PointND::from([1,2])));
+    /// assert_eq!(p.try_contract_back::<4>(), Err(ContractError { dims: 3, requested: 4 }));
+    /// ```
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `var-dims`
+    ///
+    /// # Errors
+    ///
+    /// - `ContractError` if `M` is greater than the original dimensions of the point
+    ///
+    #[cfg(feature = "var-dims")]
+    pub fn try_contract_back<const M: usize>(self) -> Result<PointND<T, M>, ContractError> {
+        if M > N {
+            return Err(ContractError { dims: N, requested: M });
+        }
+
+        let mut arr = PartialArray::<T, M>::new();
+        for item in self.into_arr().into_iter().skip(N - M) {
+            arr.push(item);
+        }
+
+        Ok(PointND::from(arr.into_inner("try_contract_back")))
+    }
+
+    ///
+    /// Consumes `self` and returns a new `PointND` with the rearmost `count` items of the
+    /// original removed.
+    ///
+    /// This is the counterpart of `retain()`, which is given the number of items to keep instead.
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND
+    ///     ::from([0,1,2,3])
+    ///     .contract_by(3);
+    /// assert_eq!(p.into_arr(), [0]);
+    /// ```
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `var-dims`
+    ///
+    /// # Panics
+    ///
+    /// - If `count` is greater than the original dimensions of the point.
+    ///
+    /// - If the dimensions of the returned point are not equal to the original dimensions minus `count`.
+    ///
+    /// ```should_panic
+    /// # use point_nd::PointND;
+    /// let p: PointND<_, 0> = PointND
+    ///     ::from([0,1,2])
+    ///     .contract_by(4);
+    /// ```
+    ///
+    #[cfg(feature = "var-dims")]
+    pub fn contract_by<const M: usize>(self, count: usize) -> PointND<T, M> {
+        Self::check_contract_dims(count, M, "contract_by");
+
+        let mut arr = PartialArray::<T, M>::new();
+        for item in self.into_arr().into_iter().take(M) {
+            arr.push(item);
+        }
+
+        PointND::from(arr.into_inner("contract_by"))
+    }
+
+    ///
+    /// Consumes `self` and returns a new `PointND` with the foremost `count` items of the
+    /// original removed.
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND
+    ///     ::from([0,1,2,3])
+    ///     .contract_front(1);
+    /// assert_eq!(p.into_arr(), [1,2,3]);
+    /// ```
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `var-dims`
+    ///
+    /// # Panics
+    ///
+    /// - If `count` is greater than the original dimensions of the point.
+    ///
+    /// - If the dimensions of the returned point are not equal to the original dimensions minus `count`.
+    ///
+    #[cfg(feature = "var-dims")]
+    pub fn contract_front<const M: usize>(self, count: usize) -> PointND<T, M> {
+        Self::check_contract_dims(count, M, "contract_front");
+
+        let mut arr = PartialArray::<T, M>::new();
+        for item in self.into_arr().into_iter().skip(count) {
+            arr.push(item);
+        }
+
+        PointND::from(arr.into_inner("contract_front"))
+    }
+
+    ///
+    /// Consumes `self` and returns a new `PointND` with the item at `index` removed, shifting
+    /// all items after it down by one dimension.
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// // Dropping the w value of a homogeneous point
+    /// let p: PointND<_, 3> = PointND
+    ///     ::from([2.0, 4.0, 6.0, 1.0])
+    ///     .remove_dim(3);
+    /// assert_eq!(p.into_arr(), [2.0, 4.0, 6.0]);
+    /// ```
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `var-dims`
+    ///
+    /// # Panics
+    ///
+    /// - If `index` is greater than or equal to the dimensions of the point.
+    ///
+    /// - If the dimensions of the returned point are not one less than the original.
+    ///
+    #[cfg(feature = "var-dims")]
+    pub fn remove_dim<const M: usize>(self, index: usize) -> PointND<T, M> {
+        if index >= N {
+            panic!("Attempted to remove_dim() at index {} of a PointND of {} dimensions", index, N);
+        }
+        if N != M + 1 {
+            panic!("Attempted to remove_dim() from a PointND of {} dimensions into a PointND of {} dimensions",
+                   N, M);
+        }
+
+        let mut arr = PartialArray::<T, M>::new();
+        for (i, item) in self.into_arr().into_iter().enumerate() {
+            if i != index {
+                arr.push(item);
+            }
+        }
+
+        PointND::from(arr.into_inner("remove_dim"))
+    }
+
+    ///
+    /// Consumes `self` and returns a new `PointND` with `value` inserted at `index`, shifting
+    /// all items after it up by one dimension.
+    ///
+    /// An `index` equal to the dimensions of the point appends `value` to the end.
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// // Lifting a 2D point into the xz plane
+    /// let p: PointND<_, 3> = PointND
+    ///     ::from([5, 7])
+    ///     .insert_dim(1, 0);
+    /// assert_eq!(p.into_arr(), [5, 0, 7]);
+    /// ```
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `var-dims`
+    ///
+    /// # Panics
+    ///
+    /// - If `index` is greater than the dimensions of the point.
+    ///
+    /// - If the dimensions of the returned point are not one more than the original.
+    ///
+    #[cfg(feature = "var-dims")]
+    pub fn insert_dim<const M: usize>(self, index: usize, value: T) -> PointND<T, M> {
+        if index > N {
+            panic!("Attempted to insert_dim() at index {} of a PointND of {} dimensions", index, N);
+        }
+        if N + 1 != M {
+            panic!("Attempted to insert_dim() into a PointND of {} dimensions from a PointND of {} dimensions",
+                   M, N);
+        }
+
+        let mut arr = PartialArray::<T, M>::new();
+        let mut value = Some(value);
+        for (i, item) in self.into_arr().into_iter().enumerate() {
+            if i == index {
+                arr.push(value.take().unwrap());
+            }
+            arr.push(item);
+        }
+        if let Some(value) = value {
+            arr.push(value);
+        }
+
+        PointND::from(arr.into_inner("insert_dim"))
+    }
+
+    ///
+    /// Consumes `self` and returns two new `PointND`'s, the first containing the foremost `A`
+    /// items of the original and the second containing the remaining `B` items.
+    ///
+    /// This is the inverse of `extend()`. As the items are moved, they need not be `Copy`.
+    ///
+    /// Note that this shadows the `split_at()` method of slices. It can still be called with
+    /// `p[..].split_at(mid)`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// // Position and velocity state
+    /// let state = PointND::from([0.0, 1.0, 2.0, -0.5, 0.0, 0.5]);
+    /// let (pos, vel): (PointND<_, 3>, PointND<_, 3>) = state.split_at();
+    ///
+    /// assert_eq!(pos.into_arr(), [0.0, 1.0, 2.0]);
+    /// assert_eq!(vel.into_arr(), [-0.5, 0.0, 0.5]);
+    /// ```
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `var-dims`
+    ///
+    /// # Panics
+    ///
+    /// - If the combined dimensions of the returned points are not equal to the dimensions of
+    ///   the original.
+    ///
+    #[cfg(feature = "var-dims")]
+    pub fn split_at<const A: usize, const B: usize>(self) -> (PointND<T, A>, PointND<T, B>) {
+        if A + B != N {
+            panic!("Attempted to split_at() a PointND of {} dimensions into PointND's of {} and {} dimensions",
+                   N, A, B);
+        }
+
+        let mut items = self.into_arr().into_iter();
+        let mut front = PartialArray::<T, A>::new();
+        for item in items.by_ref().take(A) { front.push(item); }
+        let mut back = PartialArray::<T, B>::new();
+        for item in items                  { back.push(item); }
+
+        (PointND::from(front.into_inner("split_at")), PointND::from(back.into_inner("split_at")))
+    }
+
+    ///
+    /// Consumes `self` and returns a new `PointND` of `M` dimensions, removing the rearmost
+    /// items if `M` is less than the original dimensions, or appending clones of `fill` if `M`
+    /// is greater.
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p: PointND<_, 4> = PointND::from([1,2]).resize(0);
+    /// assert_eq!(p.into_arr(), [1,2,0,0]);
+    ///
+    /// let p: PointND<_, 1> = PointND::from([1,2]).resize(0);
+    /// assert_eq!(p.into_arr(), [1]);
+    /// ```
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `var-dims`
+    ///
+    #[cfg(feature = "var-dims")]
+    pub fn resize<const M: usize>(self, fill: T) -> PointND<T, M>
+        where T: Clone {
+        self.resize_with(|_| fill.clone())
+    }
+
+    ///
+    /// Equivalent to `resize()`, except that any appended items are created by calling
+    /// `f` with their index in the returned point.
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p: PointND<_, 5> = PointND::from([10,20]).resize_with(|i| i * 100);
+    /// assert_eq!(p.into_arr(), [10,20,200,300,400]);
+    /// ```
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `var-dims`
+    ///
+    #[cfg(feature = "var-dims")]
+    pub fn resize_with<const M: usize>(self, mut f: impl FnMut(usize) -> T) -> PointND<T, M> {
+        let mut arr = PartialArray::<T, M>::new();
+        for item in self.into_arr().into_iter().take(M) {
+            arr.push(item);
+        }
+        for i in N..M {
+            arr.push(f(i));
+        }
+
+        PointND::from(arr.into_inner("resize_with"))
+    }
+
+    // Ensures the array built when removing count items is always exactly filled
+    #[cfg(feature = "var-dims")]
+    fn check_contract_dims(count: usize, dims: usize, method_name: &str) {
+        if count > N {
+            panic!("Attempted to remove {} dimensions from a PointND of {} dimensions in {}()",
+                   count, N, method_name);
+        }
+        if N - count != dims {
+            panic!("Attempted to remove {} dimensions from a PointND of {} dimensions into a PointND of {} dimensions in {}()",
+                   count, N, dims, method_name);
+        }
+    }
+
+}
+
+
+// Bounds
+impl<T, const N: usize> PointND<T, N>
+    where T: PartialOrd {
+
+    ///
+    /// Returns `true` if every item in `self` is between the respective items in `min` and `max`
+    ///
+    /// The `mode` decides whether the bounds themselves are considered to be within the range
+    ///
+    /// ```
+    /// # use point_nd::{PointND, BoundsMode};
+    /// let min = PointND::from([0, 0]);
+    /// let max = PointND::from([10, 10]);
+    ///
+    /// let p = PointND::from([0, 10]);
+    /// assert!(p.between(&min, &max, BoundsMode::Closed));
+    /// assert!(!p.between(&min, &max, BoundsMode::ClosedOpen));
+    /// ```
+    ///
+    pub fn between(&self, min: &Self, max: &Self, mode: BoundsMode) -> bool {
+        self.which_outside(min, max, mode).is_none()
+    }
+
+    ///
+    /// Returns the index of the first item in `self` which is not between the respective items in
+    /// `min` and `max`, or `None` if all items are in range
+    ///
+    /// Useful for finding out which dimension caused `between()` to return `false`
+    ///
+    /// ```
+    /// # use point_nd::{PointND, BoundsMode};
+    /// let min = PointND::from([0, 0, 0]);
+    /// let max = PointND::from([10, 10, 10]);
+    ///
+    /// let p = PointND::from([5, 11, -1]);
+    /// assert_eq!(p.which_outside(&min, &max, BoundsMode::Closed), Some(1));
+    /// ```
+    ///
+    pub fn which_outside(&self, min: &Self, max: &Self, mode: BoundsMode) -> Option<usize> {
+        self.iter()
+            .zip(min.iter().zip(max.iter()))
+            .position(|(item, (lo, hi))| !mode.contains(item, lo, hi))
+    }
+
+    ///
+    /// Consumes `self` and `other`, returning a new `PointND` containing the smaller of each of
+    /// their respective items
+    ///
+    /// If two items are equal (or cannot be compared), the item in `self` is kept
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p1 = PointND::from([0, 5, -2]);
+    /// let p2 = PointND::from([1, 3, -4]);
+    /// assert_eq!(p1.component_min(p2).into_arr(), [0, 3, -4]);
+    /// ```
+    ///
+    pub fn component_min(self, other: Self) -> Self {
+        let mut arr = PartialArray::<T, N>::new();
+        for (a, b) in self.into_arr().into_iter().zip(other.into_arr()) {
+            arr.push(if b < a { b } else { a });
+        }
+        PointND::from(arr.into_inner("component_min"))
+    }
+
+    ///
+    /// Consumes `self` and `other`, returning a new `PointND` containing the larger of each of
+    /// their respective items
+    ///
+    /// If two items are equal (or cannot be compared), the item in `self` is kept
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p1 = PointND::from([0, 5, -2]);
+    /// let p2 = PointND::from([1, 3, -4]);
+    /// assert_eq!(p1.component_max(p2).into_arr(), [1, 5, -2]);
+    /// ```
+    ///
+    pub fn component_max(self, other: Self) -> Self {
+        let mut arr = PartialArray::<T, N>::new();
+        for (a, b) in self.into_arr().into_iter().zip(other.into_arr()) {
+            arr.push(if b > a { b } else { a });
+        }
+        PointND::from(arr.into_inner("component_max"))
+    }
+
+    ///
+    /// Consumes `self` and returns a new `PointND` with each item restricted to the range of the
+    /// respective items in `lo` and `hi`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let lo = PointND::from([0, 0, 0]);
+    /// let hi = PointND::from([10, 10, 10]);
+    ///
+    /// let p = PointND::from([-5, 5, 15]).clamp(lo, hi);
+    /// assert_eq!(p.into_arr(), [0, 5, 10]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - If any item in `lo` is greater than the respective item in `hi`
+    ///
+    /// ```should_panic
+    /// # use point_nd::PointND;
+    /// let lo = PointND::from([0, 10]);
+    /// let hi = PointND::from([10, 0]);
+    /// let p = PointND::from([5, 5]).clamp(lo, hi);
+    /// ```
+    ///
+    pub fn clamp(self, lo: Self, hi: Self) -> Self {
+        if let Some(i) = lo.iter().zip(hi.iter()).position(|(l, h)| l > h) {
+            panic!("Attempted to clamp() a PointND with a lower bound greater than its upper bound at dimension {}", i);
+        }
+
+        self.component_max(lo).component_min(hi)
+    }
+
+    ///
+    /// Consumes `self` and returns the same point as `clamp()` along with a point of `bool`'s,
+    /// where each item is `true` if the respective item in `self` had to be clamped
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let min = PointND::from([0, 0, 0]);
+    /// let max = PointND::from([10, 10, 10]);
+    ///
+    /// let (p, clamped) = PointND::from([-5, 5, 15]).clamp_report(&min, &max);
+    /// assert_eq!(p.into_arr(), [0, 5, 10]);
+    /// assert_eq!(clamped.into_arr(), [true, false, true]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - If any item in `min` is greater than the respective item in `max`
+    ///
+    pub fn clamp_report(self, min: &Self, max: &Self) -> (Self, PointND<bool, N>)
+        where T: Clone {
+
+        if let Some(i) = min.iter().zip(max.iter()).position(|(l, h)| l > h) {
+            panic!("Attempted to clamp_report() a PointND with a lower bound greater than its upper bound at dimension {}", i);
+        }
+
+        let mut arr = PartialArray::<T, N>::new();
+        let mut mask = [false; N];
+        for (i, item) in self.into_arr().into_iter().enumerate() {
+            if item < min[i] {
+                arr.push(min[i].clone());
+                mask[i] = true;
+            } else if item > max[i] {
+                arr.push(max[i].clone());
+                mask[i] = true;
+            } else {
+                arr.push(item);
+            }
+        }
+
+        (PointND::from(arr.into_inner("clamp_report")), PointND::from(mask))
+    }
+
+    ///
+    /// Consumes `self` and returns the same point as `clamp()` along with a `bool` which is
+    /// `true` if any of the items in `self` had to be clamped
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let min = PointND::from([0, 0]);
+    /// let max = PointND::from([10, 10]);
+    ///
+    /// let (p, clamped) = PointND::from([5, 5]).clamped_any(&min, &max);
+    /// assert!(!clamped);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - If any item in `min` is greater than the respective item in `max`
+    ///
+    pub fn clamped_any(self, min: &Self, max: &Self) -> (Self, bool)
+        where T: Clone {
+
+        let (p, mask) = self.clamp_report(min, max);
+        (p, mask.contains(&true))
+    }
+
+}
+
+// Extremes
+impl<T, const N: usize> PointND<T, N>
+    where T: PartialOrd {
+
+    ///
+    /// Returns the index of and a reference to the smallest item in `self`, or `None` if the
+    /// point has zero dimensions
+    ///
+    /// If several items are equally small, the one with the lowest index is returned. Items which
+    /// cannot be compared (_e.g._ - `NaN`) are never returned unless they are the first item
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([3, -1, 4, -1]);
+    /// assert_eq!(p.argmin(), Some((1, &-1)));
+    /// ```
+    ///
+    pub fn argmin(&self) -> Option<(usize, &T)> {
+        self.iter()
+            .enumerate()
+            .reduce(|min, item| if item.1 < min.1 { item } else { min })
+    }
+
+    ///
+    /// Returns the index of and a reference to the largest item in `self`, or `None` if the
+    /// point has zero dimensions
+    ///
+    /// If several items are equally large, the one with the lowest index is returned. Items which
+    /// cannot be compared (_e.g._ - `NaN`) are never returned unless they are the first item
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([3, 5, 4, 5]);
+    /// assert_eq!(p.argmax(), Some((1, &5)));
+    /// ```
+    ///
+    pub fn argmax(&self) -> Option<(usize, &T)> {
+        self.iter()
+            .enumerate()
+            .reduce(|max, item| if item.1 > max.1 { item } else { max })
+    }
+
+    ///
+    /// Returns a reference to the smallest item in `self`, or `None` if the point has zero dimensions
+    ///
+    /// See `argmin()` for more info
+    ///
+    pub fn min_component(&self) -> Option<&T> {
+        self.argmin().map(|(_, item)| item)
+    }
+
+    ///
+    /// Returns a reference to the largest item in `self`, or `None` if the point has zero dimensions
+    ///
+    /// See `argmax()` for more info
+    ///
+    pub fn max_component(&self) -> Option<&T> {
+        self.argmax().map(|(_, item)| item)
+    }
+
+    ///
+    /// Returns the indexes of the smallest and largest items in `self` (in that order), or `None`
+    /// if the point has zero dimensions
+    ///
+    /// Unlike calling `argmin()` and `argmax()` separately, this only iterates over the point once.
+    /// Ties and incomparable items (_e.g._ - `NaN`) are handled in the same way as those methods
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([3, -1, 4, -1, 5]);
+    /// assert_eq!(p.arg_min_max(), Some((1, 4)));
+    /// ```
+    ///
+    pub fn arg_min_max(&self) -> Option<(usize, usize)> {
+        let first = self.first()?;
+        let (mut min, mut max) = ((0, first), (0, first));
+
+        for (i, item) in self.iter().enumerate().skip(1) {
+            if item < min.1 { min = (i, item); }
+            if item > max.1 { max = (i, item); }
+        }
+        Some((min.0, max.0))
+    }
+
+    ///
+    /// Returns references to the smallest and largest items in `self` (in that order), or `None`
+    /// if the point has zero dimensions
+    ///
+    /// See `arg_min_max()` for more info
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([2.0, -0.5, 8.25]);
+    /// assert_eq!(p.min_max_components(), Some((&-0.5, &8.25)));
+    /// ```
+    ///
+    pub fn min_max_components(&self) -> Option<(&T, &T)> {
+        self.arg_min_max().map(|(min, max)| (&self[min], &self[max]))
+    }
+
+}
+
+// Searching
+impl<T, const N: usize> PointND<T, N>
+    where T: PartialOrd {
+
+    ///
+    /// Returns `true` if the items of `self` are sorted in ascending order
+    ///
+    /// Points with zero or one dimensions are always sorted. Any items which cannot be compared
+    /// (_e.g._ - `NaN`) result in `false`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// assert!(PointND::from([1, 2, 2, 5]).is_sorted_components());
+    /// assert!(!PointND::from([1, 3, 2]).is_sorted_components());
+    /// ```
+    ///
+    pub fn is_sorted_components(&self) -> bool {
+        self.windows(2).all(|pair| pair[0] <= pair[1])
+    }
+
+    ///
+    /// Binary searches the items of `self` for `value`
+    ///
+    /// If found, returns `Ok` containing the index of the matching item. If several items match,
+    /// any one of their indices may be returned. Otherwise returns `Err` containing the index
+    /// `value` could be inserted at while keeping `self` sorted
+    ///
+    /// The items of `self` must be sorted in ascending order (see `is_sorted_components()`),
+    /// otherwise the result is unspecified and meaningless
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// // Cumulative weights of three buckets
+    /// let thresholds = PointND::from([10, 30, 60]);
+    ///
+    /// assert_eq!(thresholds.binary_search_component(&30), Ok(1));
+    /// assert_eq!(thresholds.binary_search_component(&45), Err(2));
+    /// ```
+    ///
+    pub fn binary_search_component(&self, value: &T) -> Result<usize, usize>
+        where T: Ord {
+        self.binary_search(value)
+    }
+
+}
+
+// Change Detection
+impl<T, const N: usize> PointND<T, N>
+    where T: PartialEq {
+
+    ///
+    /// Returns an iterator over the items of `self` which differ from the respective items of
+    /// `previous`, yielding the index of each along with the `previous` item and the item of
+    /// `self` (in that order)
+    ///
+    /// Items are compared with `PartialEq`, so `NaN` items are always reported as changed
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let previous = PointND::from([0, 5, 10]);
+    /// let current = PointND::from([0, 6, 10]);
+    ///
+    /// let mut changes = current.diff(&previous);
+    /// assert_eq!(changes.next(), Some((1, &5, &6)));
+    /// assert_eq!(changes.next(), None);
+    /// ```
+    ///
+    pub fn diff<'a>(&'a self, previous: &'a Self) -> impl Iterator<Item = (usize, &'a T, &'a T)> {
+        previous.iter()
+            .zip(self.iter())
+            .enumerate()
+            .filter(|(_, (old, new))| old != new)
+            .map(|(i, (old, new))| (i, old, new))
+    }
+
+    ///
+    /// Returns a point containing `true` for each item of `self` which differs from the
+    /// respective item of `previous`, and `false` otherwise
+    ///
+    /// See `diff()` for more info
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let previous = PointND::from([0.0, 5.0, 10.0]);
+    /// let current = PointND::from([0.0, 6.0, 10.0]);
+    /// assert_eq!(current.changed_mask(&previous).into_arr(), [false, true, false]);
+    /// ```
+    ///
+    pub fn changed_mask(&self, previous: &Self) -> PointND<bool, N> {
+        PointND::from_fn(|i| self[i] != previous[i])
+    }
+
+}
+
+// Math
+impl<T, const N: usize> PointND<T, N>
+    where T: Add<Output = T> + Mul<Output = T> + Copy + Default {
+
+    ///
+    /// Returns the dot product of `self` and `other`
+    ///
+    /// The `Default` value of `T` is used as the additive identity, meaning that the dot
+    /// product of two zero dimensional points will return `T::default()`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p1 = PointND::from([1, 2, 3]);
+    /// let p2 = PointND::from([4, -5, 6]);
+    /// assert_eq!(p1.dot(&p2), 12);
+    /// ```
+    ///
+    pub fn dot(&self, other: &PointND<T, N>) -> T {
+        self.iter()
+            .zip(other.iter())
+            .fold(T::default(), |acc, (a, b)| acc + *a * *b)
+    }
+
+    ///
+    /// Returns the squared magnitude (_a.k.a_ - length) of `self`
+    ///
+    /// Equivalent to calling `self.dot(&self)`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([3, 4]);
+    /// assert_eq!(p.magnitude_squared(), 25);
+    /// ```
+    ///
+    pub fn magnitude_squared(&self) -> T {
+        self.dot(self)
+    }
+
+    ///
+    /// Returns the sum of the items contained by `self`
+    ///
+    /// The `Default` value of `T` is used as the additive identity, meaning that zero
+    /// dimensional points will return `T::default()`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([1, -2, 3, 4]);
+    /// assert_eq!(p.component_sum(), 6);
+    /// ```
+    ///
+    pub fn component_sum(&self) -> T {
+        self.iter().fold(T::default(), |acc, item| acc + *item)
+    }
+
+    ///
+    /// Returns the product of the items contained by `self`
+    ///
+    /// The multiplicative identity is created with `T::from(1)`, meaning that zero
+    /// dimensional points will return `1`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([1, -2, 3, 4]);
+    /// assert_eq!(p.component_product(), -24);
+    /// ```
+    ///
+    pub fn component_product(&self) -> T
+        where T: From<u8> {
+        self.iter().fold(T::from(1), |acc, item| acc * *item)
+    }
+
+}
+
+///
+/// Returns the absolute difference between two items
+///
+/// Subtracts the smaller item from the larger so that unsigned types never underflow
+///
+fn abs_diff_item<T>(a: T, b: T) -> T
+    where T: Sub<Output = T> + PartialOrd {
+    if a > b { a - b } else { b - a }
+}
+
+impl<T, const N: usize> PointND<T, N>
+    where T: Sub<Output = T> + PartialOrd + Copy {
+
+    ///
+    /// Returns a new `PointND` containing the absolute differences between the respective
+    /// items of `self` and `other`
+    ///
+    /// The differences are computed by subtracting the smaller item from the larger, so
+    /// this method will not underflow when used on points with unsigned items
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p1 = PointND::from([1u8, 200, 7]);
+    /// let p2 = PointND::from([5u8, 100, 7]);
+    /// assert_eq!(p1.abs_diff(&p2).into_arr(), [4, 100, 0]);
+    /// ```
+    ///
+    pub fn abs_diff(&self, other: &Self) -> Self {
+        let mut arr = self.0;
+        for (item, b) in arr.iter_mut().zip(other.iter()) {
+            *item = abs_diff_item(*item, *b);
+        }
+        PointND::from(arr)
+    }
+
+    ///
+    /// Returns `true` if the absolute difference between each item of `self` and `other` is
+    /// within the respective item of `tolerances`
+    ///
+    /// Useful when the axes of a point have different scales. A tolerance of zero requires the
+    /// items on that axis to be exactly equal
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// // Millimeters and radians
+    /// let p1 = PointND::from([1000.0, 0.50]);
+    /// let p2 = PointND::from([1000.4, 0.51]);
+    ///
+    /// assert!(p1.eq_within_point(&p2, &PointND::from([0.5, 0.05])));
+    /// assert!(!p1.eq_within_point(&p2, &PointND::from([0.5, 0.001])));
+    /// ```
+    ///
+    pub fn eq_within_point(&self, other: &Self, tolerances: &Self) -> bool {
+        self.diff_exceeds(other, tolerances).is_none()
+    }
+
+    ///
+    /// Returns the index of the first item of `self` whose absolute difference from the
+    /// respective item of `other` exceeds the respective item of `tolerances`, or `None` if
+    /// every difference is within tolerance
+    ///
+    /// Differences which cannot be compared to their tolerance (_e.g._ - `NaN`) are counted
+    /// as exceeding it
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p1 = PointND::from([0, 10, 20]);
+    /// let p2 = PointND::from([1, 13, 20]);
+    /// assert_eq!(p1.diff_exceeds(&p2, &PointND::from([1, 2, 0])), Some(1));
+    /// ```
+    ///
+    pub fn diff_exceeds(&self, other: &Self, tolerances: &Self) -> Option<usize> {
+        self.iter()
+            .zip(other.iter())
+            .zip(tolerances.iter())
+            .position(|((a, b), tol)| {
+                let within = abs_diff_item(*a, *b) <= *tol;
+                !within
+            })
+    }
+
+}
+
+impl<T, const N: usize> PointND<T, N>
+    where T: Add<Output = T> + Sub<Output = T> + Ord + Copy + Default {
+
+    ///
+    /// Returns the manhattan (_a.k.a_ - taxicab) distance between `self` and `other`,
+    /// being the sum of the absolute differences of each item
+    ///
+    /// Equivalent to summing the items returned by `abs_diff()`, so this method will not
+    /// underflow when used on points with unsigned items
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p1 = PointND::from([1u32, 5, 2]);
+    /// let p2 = PointND::from([4u32, 1, 2]);
+    /// assert_eq!(p1.manhattan_distance(&p2), 7);
+    /// ```
+    ///
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        self.iter()
+            .zip(other.iter())
+            .fold(T::default(), |acc, (a, b)| acc + abs_diff_item(*a, *b))
+    }
+
+    ///
+    /// Returns the chebyshev (_a.k.a_ - chessboard) distance between `self` and `other`,
+    /// being the largest absolute difference between any two items
+    ///
+    /// Like `manhattan_distance()`, this will not underflow when used on points with unsigned items
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p1 = PointND::from([1u32, 5, 2]);
+    /// let p2 = PointND::from([4u32, 1, 2]);
+    /// assert_eq!(p1.chebyshev_distance(&p2), 4);
+    /// ```
+    ///
+    pub fn chebyshev_distance(&self, other: &Self) -> T {
+        self.iter()
+            .zip(other.iter())
+            .map(|(a, b)| abs_diff_item(*a, *b))
+            .fold(T::default(), |acc, diff| if diff > acc { diff } else { acc })
+    }
+
+}
+
+// In Place Math
+impl<T, const N: usize> PointND<T, N>
+    where T: Clone {
+
+    ///
+    /// Adds each item of `other` to the respective item of `self` in place
+    ///
+    /// Available for points of any dimensions and items which can be cloned. Items which are
+    /// `Copy` are simply copied
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let mut total = PointND::from([0, 0, 0, 0, 0]);
+    /// for sample in [[1, 2, 3, 4, 5], [10, 20, 30, 40, 50]] {
+    ///     total.add_assign_point(&PointND::from(sample));
+    /// }
+    /// assert_eq!(total.into_arr(), [11, 22, 33, 44, 55]);
+    /// ```
+    ///
+    pub fn add_assign_point(&mut self, other: &Self)
+        where T: AddAssign {
+        for (item, b) in self.iter_mut().zip(other.iter()) {
+            *item += b.clone();
+        }
+    }
+
+    ///
+    /// Adds `delta` to each item of `self` in place
+    ///
+    /// Unlike `shift_x()` and friends, this is available for points of any dimensions
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let mut p = PointND::from([0, 1, 2, 3, 4, 5]);
+    /// p.shift_all(10);
+    /// assert_eq!(p.into_arr(), [10, 11, 12, 13, 14, 15]);
+    /// ```
+    ///
+    pub fn shift_all(&mut self, delta: T)
+        where T: AddAssign {
+        for item in self.iter_mut() {
+            *item += delta.clone();
+        }
+    }
+
+    ///
+    /// Adds each of the `deltas` to the respective item of `self` in place
+    ///
+    /// Equivalent to `add_assign_point()`, but takes an array rather than a point
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let mut p = PointND::from([0, 1, 2, 3, 4, 5]);
+    /// p.shift_by(&[1, 0, -1, 0, 1, 0]);
+    /// assert_eq!(p.into_arr(), [1, 1, 1, 3, 5, 5]);
+    /// ```
+    ///
+    pub fn shift_by(&mut self, deltas: &[T; N])
+        where T: AddAssign {
+        self.add_assign_point(PointND::from_ref(deltas));
+    }
+
+    ///
+    /// Subtracts each item of `other` from the respective item of `self` in place
+    ///
+    /// See `add_assign_point()` for more info
+    ///
+    pub fn sub_assign_point(&mut self, other: &Self)
+        where T: SubAssign {
+        for (item, b) in self.iter_mut().zip(other.iter()) {
+            *item -= b.clone();
+        }
+    }
+
+    ///
+    /// Multiplies each item of `self` by the respective item of `other` in place
+    ///
+    /// See `add_assign_point()` for more info
+    ///
+    pub fn mul_assign_point(&mut self, other: &Self)
+        where T: MulAssign {
+        for (item, b) in self.iter_mut().zip(other.iter()) {
+            *item *= b.clone();
+        }
+    }
+
+    ///
+    /// Divides each item of `self` by the respective item of `other` in place
+    ///
+    /// See `add_assign_point()` for more info
+    ///
+    /// # Panics
+    ///
+    /// - If `T` panics when divided by an item of `other` (_e.g._ - integers divided by zero)
+    ///
+    pub fn div_assign_point(&mut self, other: &Self)
+        where T: DivAssign {
+        for (item, b) in self.iter_mut().zip(other.iter()) {
+            *item /= b.clone();
+        }
+    }
+
+}
+
+// Unit Conversion
+impl<T, const N: usize> PointND<T, N>
+    where T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Copy {
+
+    ///
+    /// Consumes `self` and returns a new point with each item multiplied by the respective item
+    /// of `scale`, then added to the respective item of `offset` (_i.e._ - `x * scale + offset`)
+    ///
+    /// Useful for converting points whose axes are in different units. The conversion can be
+    /// reversed with `convert_units_inv()`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// // Kilometers to meters, celsius to fahrenheit
+    /// let p = PointND::from([1.5, 100.0]);
+    /// let p = p.convert_units(&PointND::from([1000.0, 1.8]), &PointND::from([0.0, 32.0]));
+    /// assert_eq!(p.into_arr(), [1500.0, 212.0]);
+    /// ```
+    ///
+    pub fn convert_units(self, scale: &Self, offset: &Self) -> Self {
+        let mut arr = self.0;
+        for ((item, s), o) in arr.iter_mut().zip(scale.iter()).zip(offset.iter()) {
+            *item = *item * *s + *o;
+        }
+        PointND::from(arr)
+    }
+
+    ///
+    /// Consumes `self` and returns a new point with the conversion made by `convert_units()`
+    /// reversed, subtracting the respective item of `offset` from each item before dividing
+    /// it by the respective item of `scale` (_i.e._ - `(x - offset) / scale`)
+    ///
+    /// Scales containing zero give infinite or `NaN` items for floats, and panic for integers.
+    /// Use `try_convert_units_inv()` to check for these instead
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let scale = PointND::from([1000.0, 1.8]);
+    /// let offset = PointND::from([0.0, 32.0]);
+    ///
+    /// let p = PointND::from([1500.0, 212.0]).convert_units_inv(&scale, &offset);
+    /// assert_eq!(p.into_arr(), [1.5, 100.0]);
+    /// ```
+    ///
+    pub fn convert_units_inv(self, scale: &Self, offset: &Self) -> Self {
+        let mut arr = self.0;
+        for ((item, s), o) in arr.iter_mut().zip(scale.iter()).zip(offset.iter()) {
+            *item = (*item - *o) / *s;
+        }
+        PointND::from(arr)
+    }
+
+    ///
+    /// Consumes `self` and returns the result of `convert_units_inv()`, or `None` if any item
+    /// of `scale` is zero
+    ///
+    /// The `Default` value of `T` is used as zero
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([10, 20]);
+    /// let offset = PointND::from([0, 0]);
+    ///
+    /// assert_eq!(p.try_convert_units_inv(&PointND::from([2, 5]), &offset), Some(PointND::from([5, 4])));
+    /// assert_eq!(p.try_convert_units_inv(&PointND::from([2, 0]), &offset), None);
+    /// ```
+    ///
+    pub fn try_convert_units_inv(self, scale: &Self, offset: &Self) -> Option<Self>
+        where T: Default + PartialEq {
+
+        if scale.contains(&T::default()) {
+            return None;
+        }
+        Some(self.convert_units_inv(scale, offset))
+    }
+
+}
+
+// Aggregates
+impl<T, const N: usize> PointND<T, N> {
+
+    ///
+    /// Returns the componentwise mean of `points`, or `None` if there are no points
+    ///
+    /// Equivalent to `centroid()` in `PointIterExt`, so points with integer items give a
+    /// centroid rounded towards zero, and only the sum of the points has to fit in `T`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let points = [PointND::from([-1.0, 2.0]), PointND::from([3.0, -4.0])];
+    /// let centroid = PointND::centroid(points).unwrap();
+    /// assert_eq!(centroid.into_arr(), [1.0, -1.0]);
+    /// ```
+    ///
+    pub fn centroid<I>(points: I) -> Option<Self>
+        where I: IntoIterator<Item = Self>,
+              T: Add<Output = T> + CentroidItem + Copy {
+        points.into_iter().centroid()
+    }
+
+    ///
+    /// Returns the componentwise minimum and maximum of `points`, being the opposite corners
+    /// of the smallest box containing all of them, or `None` if there are no points
+    ///
+    /// Equivalent to `bounding_box()` in `PointIterExt`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let points = [PointND::from([-1, 5]), PointND::from([3, -4]), PointND::from([0, 0])];
+    /// let (min, max) = PointND::bounding_box(points).unwrap();
+    /// assert_eq!(min.into_arr(), [-1, -4]);
+    /// assert_eq!(max.into_arr(), [3, 5]);
+    /// ```
+    ///
+    pub fn bounding_box<I>(points: I) -> Option<(Self, Self)>
+        where I: IntoIterator<Item = Self>,
+              T: PartialOrd + Clone {
+        points.into_iter().bounding_box()
+    }
+
+}
+
+// Casting
+impl<T, const N: usize> PointND<T, N> {
+
+    ///
+    /// Consumes `self` and returns a new point with each item converted into `U`
+    ///
+    /// Only lossless conversions (those implementing `Into`) are allowed. See `try_cast()` for
+    /// conversions which may fail
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([1i32, -2, 3]);
+    /// let p: PointND<f64, 3> = p.cast();
+    /// assert_eq!(p.into_arr(), [1.0, -2.0, 3.0]);
+    /// ```
+    ///
+    pub fn cast<U>(self) -> PointND<U, N>
+        where T: Into<U> {
+        PointND::from(self.0.map(Into::into))
+    }
+
+    ///
+    /// Consumes `self` and returns a new point with each item converted into `U`, or the error
+    /// of the first item which could not be converted
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p: Result<PointND<u8, 2>, _> = PointND::from([10i64, 200]).try_cast();
+    /// assert_eq!(p.unwrap().into_arr(), [10, 200]);
+    ///
+    /// let p: Result<PointND<u8, 2>, _> = PointND::from([10i64, 300]).try_cast();
+    /// assert!(p.is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - The error returned by `U::try_from()` for the first item which could not be converted
+    ///
+    pub fn try_cast<U>(self) -> Result<PointND<U, N>, <U as TryFrom<T>>::Error>
+        where U: TryFrom<T> {
+
+        let mut arr = PartialArray::<U, N>::new();
+        for item in self {
+            arr.push(U::try_from(item)?);
+        }
+        Ok(PointND::from(arr.into_inner("try_cast")))
+    }
+
+    ///
+    /// Consumes `self` and returns a new point with each item converted into `U` with the `as`
+    /// operator, meaning that the conversion may lose information
+    ///
+    /// Floats converted to integers are truncated towards zero and saturate at the bounds of the
+    /// integer type, with `NaN` converted to zero. Integers converted to narrower integers wrap
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p: PointND<i32, 3> = PointND::from([1.9, -1.9, 1e20]).as_cast();
+    /// assert_eq!(p.into_arr(), [1, -1, i32::MAX]);
+    /// ```
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `num-traits`
+    ///
+    #[cfg(feature = "num-traits")]
+    pub fn as_cast<U>(self) -> PointND<U, N>
+        where T: AsPrimitive<U>, U: Copy + 'static {
+        PointND::from(self.0.map(AsPrimitive::as_))
+    }
+
+}
+
+// Zipping
+impl<T, const N: usize> PointND<T, N> {
+
+    ///
+    /// Consumes `self` and `other`, returning a new point containing pairs of their respective items
+    ///
+    /// This is the inverse of `unzip()`. Items are moved, so they need not be `Copy` or `Clone`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let positions = PointND::from([1.0, 2.0, 3.0]);
+    /// let weights = PointND::from([0.5, 2.0, 0.0]);
+    ///
+    /// let weighted = positions
+    ///     .zip(weights)
+    ///     .apply(|(pos, weight)| pos * weight);
+    /// assert_eq!(weighted.into_arr(), [0.5, 4.0, 0.0]);
+    /// ```
+    ///
+    pub fn zip<U>(self, other: PointND<U, N>) -> PointND<(T, U), N> {
+        let mut others = other.into_arr().into_iter();
+        let mut arr = PartialArray::<(T, U), N>::new();
+        for item in self.into_arr() {
+            arr.push((item, others.next().unwrap()));
+        }
+        PointND::from(arr.into_inner("zip"))
+    }
+
+}
+
+impl<T, U, const N: usize> PointND<(T, U), N> {
+
+    ///
+    /// Consumes `self`, returning two new points containing the first and second items of
+    /// each pair respectively
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let interleaved = PointND::from([(0, 'a'), (1, 'b')]);
+    /// let (nums, chars) = interleaved.unzip();
+    ///
+    /// assert_eq!(nums.into_arr(), [0, 1]);
+    /// assert_eq!(chars.into_arr(), ['a', 'b']);
+    /// ```
+    ///
+    pub fn unzip(self) -> (PointND<T, N>, PointND<U, N>) {
+        let mut firsts = PartialArray::<T, N>::new();
+        let mut seconds = PartialArray::<U, N>::new();
+        for (a, b) in self.into_arr() {
+            firsts.push(a);
+            seconds.push(b);
+        }
+        (PointND::from(firsts.into_inner("unzip")), PointND::from(seconds.into_inner("unzip")))
+    }
+
+}
+
+// Arrays of Points
+///
+/// Methods for converting arrays of `PointND`'s to and from arrays of items
+///
+/// As neither type is defined in this crate, conversions between `[PointND<T, N>; K]` and
+/// `[[T; N]; K]` cannot be provided with the `From` trait
+///
+impl<T, const N: usize> PointND<T, N> {
+
+    ///
+    /// Consumes an array of points and returns an array of the arrays they contained, in the
+    /// same order
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let quad = [
+    ///     PointND::from([0, 0]), PointND::from([1, 0]),
+    ///     PointND::from([1, 1]), PointND::from([0, 1]),
+    /// ];
+    /// assert_eq!(PointND::into_nested(quad), [[0, 0], [1, 0], [1, 1], [0, 1]]);
+    /// ```
+    ///
+    pub fn into_nested<const K: usize>(points: [Self; K]) -> [[T; N]; K] {
+        points.map(PointND::into_arr)
+    }
+
+    ///
+    /// Consumes an array of arrays and returns an array of points containing them, in the
+    /// same order
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let quad = PointND::from_nested([[0, 0], [1, 0], [1, 1], [0, 1]]);
+    /// assert_eq!(*quad[2], [1, 1]);
+    /// ```
+    ///
+    pub fn from_nested<const K: usize>(arrays: [[T; N]; K]) -> [Self; K] {
+        arrays.map(PointND::from)
+    }
+
+    ///
+    /// Consumes an array of points and returns a single point containing the items of every
+    /// point, in the same order
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let quad = PointND::from_nested([[0, 0], [1, 0], [1, 1], [0, 1]]);
+    /// let flat: PointND<_, 8> = PointND::flatten_array(quad);
+    /// assert_eq!(flat.into_arr(), [0, 0, 1, 0, 1, 1, 0, 1]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - If the dimensions of the returned point are not equal to the combined dimensions of
+    ///   every point
+    ///
+    pub fn flatten_array<const K: usize, const L: usize>(points: [Self; K]) -> PointND<T, L> {
+        if N * K != L {
+            panic!("Attempted to flatten {} PointND's of {} dimensions into a PointND of {} dimensions",
+                   K, N, L);
+        }
+
+        let mut arr = PartialArray::<T, L>::new();
+        for point in points {
+            for item in point.into_arr() {
+                arr.push(item);
+            }
+        }
+
+        PointND::from(arr.into_inner("flatten_array"))
+    }
+
+    ///
+    /// Consumes a point and returns an array of points, each containing the next `N` items
+    /// of the original
+    ///
+    /// This is the inverse of `flatten_array()`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let flat = PointND::from([0, 0, 1, 0, 1, 1, 0, 1]);
+    /// let quad: [PointND<_, 2>; 4] = PointND::unflatten_array(flat);
+    /// assert_eq!(PointND::into_nested(quad), [[0, 0], [1, 0], [1, 1], [0, 1]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - If the dimensions of `flat` are not equal to the combined dimensions of the
+    ///   returned points
+    ///
+    pub fn unflatten_array<const K: usize, const L: usize>(flat: PointND<T, L>) -> [Self; K] {
+        if N * K != L {
+            panic!("Attempted to unflatten a PointND of {} dimensions into {} PointND's of {} dimensions",
+                   L, K, N);
+        }
+
+        let mut items = flat.into_arr().into_iter();
+        let mut points = PartialArray::<Self, K>::new();
+        for _ in 0..K {
+            let mut arr = PartialArray::<T, N>::new();
+            for item in items.by_ref().take(N) {
+                arr.push(item);
+            }
+            points.push(PointND::from(arr.into_inner("unflatten_array")));
+        }
+
+        points.into_inner("unflatten_array")
+    }
+
+}
+
+// References
+///
+/// Methods for `PointND`'s containing references
+///
+impl<T, const N: usize> PointND<&T, N> {
+
+    ///
+    /// Consumes `self` and returns a new point containing clones of the referenced values
+    ///
+    /// Useful for building points from borrowed views, such as those created from an
+    /// array of references
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let (a, b, c) = (1, 2, 3);
+    /// let view = PointND::from([&c, &a, &b]);
+    /// assert_eq!(view.cloned().into_arr(), [3, 1, 2]);
+    /// ```
+    ///
+    pub fn cloned(self) -> PointND<T, N>
+        where T: Clone {
+        let arr = self.into_arr();
+        PointND::from_fn(|i| arr[i].clone())
+    }
+
+}
+
+// Deref
+impl<T, const N: usize> Deref for PointND<T, N> {
+
+    type Target = [T; N];
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+
+}
+
+impl<T, const N: usize> DerefMut for PointND<T, N> {
+
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+
+}
+
+// Indexing
+//
+// Indexing was previously done through Deref, but implementing Index<Axis> stops the compiler
+//  from dereferencing to find the slice impls. These forward numeric indexes and ranges to
+//  the contained array instead
+impl<T, I, const N: usize> Index<I> for PointND<T, N>
+    where I: SliceIndex<[T]> {
+
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.0[..][index]
+    }
+
+}
+
+impl<T, I, const N: usize> IndexMut<I> for PointND<T, N>
+    where I: SliceIndex<[T]> {
+
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.0[..][index]
+    }
+
+}
+
+// Conversion References
+impl<T, const N: usize> AsRef<[T]> for PointND<T, N> {
+
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+
+}
+
+impl<T, const N: usize> AsMut<[T]> for PointND<T, N> {
+
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+
+}
+
+impl<T, const N: usize> AsRef<[T; N]> for PointND<T, N> {
+
+    fn as_ref(&self) -> &[T; N] {
+        &self.0
+    }
+
+}
+
+impl<T, const N: usize> AsMut<[T; N]> for PointND<T, N> {
+
+    fn as_mut(&mut self) -> &mut [T; N] {
+        &mut self.0
+    }
+
+}
+
+// Borrow requires points to hash, compare and order the same as what they are borrowed as.
+//  This holds as PointND derives or delegates all of these to the contained array, which
+//  in turn delegates them to its slice
+impl<T, const N: usize> Borrow<[T]> for PointND<T, N> {
+
+    fn borrow(&self) -> &[T] {
+        &self.0
+    }
+
+}
+
+impl<T, const N: usize> BorrowMut<[T]> for PointND<T, N> {
+
+    fn borrow_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+
+}
+
+impl<T, const N: usize> Borrow<[T; N]> for PointND<T, N> {
+
+    fn borrow(&self) -> &[T; N] {
+        &self.0
+    }
+
+}
+
+impl<T, const N: usize> BorrowMut<[T; N]> for PointND<T, N> {
+
+    fn borrow_mut(&mut self) -> &mut [T; N] {
+        &mut self.0
+    }
+
+}
+
+// Iterators
+impl<T, const N: usize> IntoIterator for PointND<T, N> {
+
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, N>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a PointND<T, N> {
+
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut PointND<T, N> {
+
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+
+}
+
+// Sums start from zero and products from one, being the same as in zero() and one()
+impl<T, const N: usize> Sum for PointND<T, N>
+    where T: Add<Output = T> + Default {
+
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(PointND::zero(), |total, point| {
+            PointND(zip_with(total.0, point.0, T::add, "sum"))
+        })
+    }
+
+}
+
+impl<'a, T, const N: usize> Sum<&'a PointND<T, N>> for PointND<T, N>
+    where T: Add<Output = T> + Default + Copy {
+
+    fn sum<I: Iterator<Item = &'a PointND<T, N>>>(iter: I) -> Self {
+        iter.cloned().sum()
+    }
+
+}
+
+impl<T, const N: usize> Product for PointND<T, N>
+    where T: Mul<Output = T> + From<u8> {
+
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(PointND::from_fn(|_| T::from(1)), |total, point| {
+            PointND(zip_with(total.0, point.0, T::mul, "product"))
+        })
+    }
+
+}
+
+impl<'a, T, const N: usize> Product<&'a PointND<T, N>> for PointND<T, N>
+    where T: Mul<Output = T> + From<u8> + Copy {
+
+    fn product<I: Iterator<Item = &'a PointND<T, N>>>(iter: I) -> Self {
+        iter.cloned().product()
+    }
+
+}
+
+
+// Convenience Getters and Setters
+///
+/// Methods for safely getting and setting the value contained by a 1D `PointND`
+///
+/// # Enabled by features:
+///
+/// - `default`
+///
+/// - `conv_methods`
+///
+/// - `x`
+///
+#[cfg(feature = "x")]
+impl<T> PointND<T, 1> {
+
+    pub const fn x(&self) -> &T { &self.0[0] }
+
+    pub fn x_mut(&mut self) -> &mut T { &mut self[0] }
+
+    pub fn set_x(&mut self, new_value: T) { self[0] = new_value; }
+
+    pub fn with_x(mut self, new_value: T) -> Self { self[0] = new_value; self }
+
+}
+///
+/// Methods for safely getting and setting the values contained by a 2D `PointND`
+///
+/// # Enabled by features:
+///
+/// - `default`
+///
+/// - `conv_methods`
+///
+/// - `y`
+///
+#[cfg(feature = "y")]
+impl<T> PointND<T, 2> {
+
+    pub const fn x(&self) -> &T { &self.0[0] }
+    pub const fn y(&self) -> &T { &self.0[1] }
+
+    pub fn x_mut(&mut self) -> &mut T { &mut self[0] }
+    pub fn y_mut(&mut self) -> &mut T { &mut self[1] }
+
+    pub fn set_x(&mut self, new_value: T) { self[0] = new_value; }
+    pub fn set_y(&mut self, new_value: T) { self[1] = new_value; }
+
+    pub fn with_x(mut self, new_value: T) -> Self { self[0] = new_value; self }
+    pub fn with_y(mut self, new_value: T) -> Self { self[1] = new_value; self }
+
+}
+///
 /// Methods for safely getting and setting the values contained by a 3D `PointND`
 ///
-/// # Enabled by features:
+/// # Enabled by features:
+///
+/// - `default`
+///
+/// - `conv_methods`
+///
+/// - `z`
+///
+#[cfg(feature = "z")]
+impl<T> PointND<T, 3>  {
+
+    pub const fn x(&self) -> &T { &self.0[0] }
+    pub const fn y(&self) -> &T { &self.0[1] }
+    pub const fn z(&self) -> &T { &self.0[2] }
+
+    pub fn x_mut(&mut self) -> &mut T { &mut self[0] }
+    pub fn y_mut(&mut self) -> &mut T { &mut self[1] }
+    pub fn z_mut(&mut self) -> &mut T { &mut self[2] }
+
+    pub fn set_x(&mut self, new_value: T) { self[0] = new_value; }
+    pub fn set_y(&mut self, new_value: T) { self[1] = new_value; }
+    pub fn set_z(&mut self, new_value: T) { self[2] = new_value; }
+
+    pub fn with_x(mut self, new_value: T) -> Self { self[0] = new_value; self }
+    pub fn with_y(mut self, new_value: T) -> Self { self[1] = new_value; self }
+    pub fn with_z(mut self, new_value: T) -> Self { self[2] = new_value; self }
+
+}
+///
+/// Methods for safely getting and setting the values contained by a 4D `PointND`
+///
+/// # Enabled by features:
+///
+/// - `default`
+///
+/// - `conv_methods`
+///
+/// - `w`
+///
+#[cfg(feature = "w")]
+impl<T> PointND<T, 4>  {
+
+    pub const fn x(&self) -> &T { &self.0[0] }
+    pub const fn y(&self) -> &T { &self.0[1] }
+    pub const fn z(&self) -> &T { &self.0[2] }
+    pub const fn w(&self) -> &T { &self.0[3] }
+
+    pub fn x_mut(&mut self) -> &mut T { &mut self[0] }
+    pub fn y_mut(&mut self) -> &mut T { &mut self[1] }
+    pub fn z_mut(&mut self) -> &mut T { &mut self[2] }
+    pub fn w_mut(&mut self) -> &mut T { &mut self[3] }
+
+    pub fn set_x(&mut self, new_value: T) { self[0] = new_value; }
+    pub fn set_y(&mut self, new_value: T) { self[1] = new_value; }
+    pub fn set_z(&mut self, new_value: T) { self[2] = new_value; }
+    pub fn set_w(&mut self, new_value: T) { self[3] = new_value; }
+
+    pub fn with_x(mut self, new_value: T) -> Self { self[0] = new_value; self }
+    pub fn with_y(mut self, new_value: T) -> Self { self[1] = new_value; self }
+    pub fn with_z(mut self, new_value: T) -> Self { self[2] = new_value; self }
+    pub fn with_w(mut self, new_value: T) -> Self { self[3] = new_value; self }
+
+}
+
+// Convenience Shifters
+///
+/// Method for safely transforming the value contained by a 1D `PointND`
+///
+/// # Enabled by features:
+///
+/// - `default`
+///
+/// - `conv_methods`
+///
+/// - `x`
+/// 
+#[cfg(feature = "x")]
+impl<T> PointND<T, 1>
+    where T: AddAssign {
+
+    pub fn shift_x(&mut self, delta: T) { self[0] += delta; }
+
+}
+///
+/// Methods for safely transforming the values contained by a 2D `PointND`
+///
+/// # Enabled by features:
+///
+/// - `default`
+///
+/// - `conv_methods`
+///
+/// - `y`
+///
+#[cfg(feature = "y")]
+impl<T> PointND<T, 2>
+    where T: AddAssign {
+
+    pub fn shift_x(&mut self, delta: T) { self[0] += delta; }
+    pub fn shift_y(&mut self, delta: T) { self[1] += delta; }
+
+}
+///
+/// Methods for safely transforming the values contained by a 3D `PointND`
+///
+/// # Enabled by features:
+///
+/// - `default`
+///
+/// - `conv_methods`
+///
+/// - `z`
+///
+#[cfg(feature = "z")]
+impl<T> PointND<T, 3>
+    where T: AddAssign {
+
+    pub fn shift_x(&mut self, delta: T) { self[0] += delta; }
+    pub fn shift_y(&mut self, delta: T) { self[1] += delta; }
+    pub fn shift_z(&mut self, delta: T) { self[2] += delta; }
+
+}
+///
+/// Methods for safely transforming the values contained by a 4D `PointND`
+///
+/// # Enabled by features:
+///
+/// - `default`
+///
+/// - `conv_methods`
+///
+/// - `w`
+///
+#[cfg(feature = "w")]
+impl<T> PointND<T, 4>
+    where T: AddAssign {
+
+    pub fn shift_x(&mut self, delta: T) { self[0] += delta; }
+    pub fn shift_y(&mut self, delta: T) { self[1] += delta; }
+    pub fn shift_z(&mut self, delta: T) { self[2] += delta; }
+    pub fn shift_w(&mut self, delta: T) { self[3] += delta; }
+
+}
+
+// Convenience Widening and Narrowing
+///
+/// Method for moving 1D `PointND`'s to and from neighbouring dimensions
+///
+/// # Enabled by features:
+///
+/// - `default`
+///
+/// - `conv_methods`
+///
+/// - `x`
+///
+#[cfg(feature = "x")]
+impl<T> PointND<T, 1> {
+
+    ///
+    /// Consumes `self` and returns a 2D point with `value` appended
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([1]).widened(2);
+    /// assert_eq!(p.into_arr(), [1, 2]);
+    /// ```
+    ///
+    pub fn widened(self, value: T) -> PointND<T, 2> {
+        let [x] = self.into_arr();
+        PointND::from([x, value])
+    }
+
+}
+///
+/// Methods for moving 2D `PointND`'s to and from neighbouring dimensions
+///
+/// # Enabled by features:
+///
+/// - `default`
+///
+/// - `conv_methods`
+///
+/// - `y`
+///
+#[cfg(feature = "y")]
+impl<T> PointND<T, 2> {
+
+    ///
+    /// Consumes `self` and returns a 3D point with `value` appended
+    ///
+    /// Useful for moving between 2D points and their homogeneous coordinates
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([1.5, 2.0]).widened(1.0);
+    /// assert_eq!(p.into_arr(), [1.5, 2.0, 1.0]);
+    /// ```
+    ///
+    pub fn widened(self, value: T) -> PointND<T, 3> {
+        let [x, y] = self.into_arr();
+        PointND::from([x, y, value])
+    }
+
+    ///
+    /// Consumes `self` and returns a 1D point of the first item, along with the removed last item
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let (p, y) = PointND::from([1, 2]).narrowed();
+    /// assert_eq!((p.into_arr(), y), ([1], 2));
+    /// ```
+    ///
+    pub fn narrowed(self) -> (PointND<T, 1>, T) {
+        let [x, y] = self.into_arr();
+        (PointND::from([x]), y)
+    }
+
+}
+///
+/// Methods for moving 3D `PointND`'s to and from neighbouring dimensions
+///
+/// # Enabled by features:
+///
+/// - `default`
+///
+/// - `conv_methods`
+///
+/// - `z`
+///
+#[cfg(feature = "z")]
+impl<T> PointND<T, 3> {
+
+    ///
+    /// Consumes `self` and returns a 4D point with `value` appended
+    ///
+    /// Useful for moving between 3D points and their homogeneous coordinates
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([1.5, 2.0, -3.0]).widened(1.0);
+    /// assert_eq!(p.into_arr(), [1.5, 2.0, -3.0, 1.0]);
+    /// ```
+    ///
+    pub fn widened(self, value: T) -> PointND<T, 4> {
+        let [x, y, z] = self.into_arr();
+        PointND::from([x, y, z, value])
+    }
+
+    ///
+    /// Consumes `self` and returns a 2D point of the first two items, along with the removed
+    /// last item
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// // Drops the depth of a 3D position
+    /// let (p, depth) = PointND::from([4, 5, 6]).narrowed();
+    /// assert_eq!((p.into_arr(), depth), ([4, 5], 6));
+    /// ```
+    ///
+    pub fn narrowed(self) -> (PointND<T, 2>, T) {
+        let [x, y, z] = self.into_arr();
+        (PointND::from([x, y]), z)
+    }
+
+}
+///
+/// Method for moving 4D `PointND`'s to and from neighbouring dimensions
+///
+/// # Enabled by features:
+///
+/// - `default`
+///
+/// - `conv_methods`
+///
+/// - `w`
+///
+#[cfg(feature = "w")]
+impl<T> PointND<T, 4> {
+
+    ///
+    /// Consumes `self` and returns a 3D point of the first three items, along with the removed
+    /// last item
+    ///
+    /// For homogeneous coordinates, the removed item is the `w` to divide the others by
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let (p, w) = PointND::from([2.0, 4.0, 6.0, 2.0]).narrowed();
+    /// assert_eq!((p.into_arr(), w), ([2.0, 4.0, 6.0], 2.0));
+    /// ```
+    ///
+    pub fn narrowed(self) -> (PointND<T, 3>, T) {
+        let [x, y, z, w] = self.into_arr();
+        (PointND::from([x, y, z]), w)
+    }
+
+}
+
+// Convenience Cross Products
+///
+/// Method for computing the scalar cross product of 2D `PointND`'s
+///
+/// # Enabled by features:
+///
+/// - `default`
+///
+/// - `conv_methods`
+///
+/// - `y`
+///
+#[cfg(feature = "y")]
+impl<T> PointND<T, 2>
+    where T: Mul<Output = T> + Sub<Output = T> + Copy {
+
+    ///
+    /// Returns the scalar cross product (_a.k.a_ - perpendicular dot product) of `self` and `other`
+    ///
+    /// The result is positive if `other` is counter-clockwise from `self`, negative if it is
+    /// clockwise and zero if the points are collinear
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p1 = PointND::from([1, 0]);
+    /// let p2 = PointND::from([0, 1]);
+    /// assert_eq!(p1.cross_2d(&p2), 1);
+    /// assert_eq!(p2.cross_2d(&p1), -1);
+    /// ```
+    ///
+    pub fn cross_2d(&self, other: &PointND<T, 2>) -> T {
+        self[0] * other[1] - self[1] * other[0]
+    }
+
+}
+///
+/// Method for computing the cross product of 3D `PointND`'s
+///
+/// # Enabled by features:
+///
+/// - `default`
+///
+/// - `conv_methods`
+///
+/// - `z`
+///
+#[cfg(feature = "z")]
+impl<T> PointND<T, 3>
+    where T: Mul<Output = T> + Sub<Output = T> + Copy {
+
+    ///
+    /// Consumes `self` and returns the cross product of it and `other`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let x = PointND::from([1, 0, 0]);
+    /// let y = PointND::from([0, 1, 0]);
+    /// assert_eq!(x.cross(y).into_arr(), [0, 0, 1]);
+    /// ```
+    ///
+    pub fn cross(self, other: PointND<T, 3>) -> PointND<T, 3> {
+        let [ax, ay, az] = self.into_arr();
+        let [bx, by, bz] = other.into_arr();
+        PointND::from([
+            ay * bz - az * by,
+            az * bx - ax * bz,
+            ax * by - ay * bx,
+        ])
+    }
+
+}
+
+// Convenience Axis Conventions
+///
+/// Methods for converting 3D `PointND`'s between axis conventions
+///
+/// Both conventions are right-handed and share the same X axis:
+///
+/// - **Y-up**: +X is right, +Y is up and +Z points towards the viewer (_e.g._ - OpenGL, glTF)
+///
+/// - **Z-up**: +X is right, +Y points away from the viewer and +Z is up (_e.g._ - Blender)
+///
+/// # Enabled by features:
+///
+/// - `default`
+///
+/// - `conv_methods`
+///
+/// - `z`
+///
+#[cfg(feature = "z")]
+impl<T> PointND<T, 3>
+    where T: Neg<Output = T> {
+
+    ///
+    /// Consumes `self`, converting it from Y-up to Z-up coordinates
+    ///
+    /// Maps `[x, y, z]` to `[x, -z, y]`, which is a rotation about the X axis and so
+    /// preserves handedness
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let up = PointND::from([0, 1, 0]);
+    /// assert_eq!(up.to_z_up().into_arr(), [0, 0, 1]);
+    /// ```
+    ///
+    pub fn to_z_up(self) -> Self {
+        let [x, y, z] = self.into_arr();
+        PointND::from([x, -z, y])
+    }
+
+    ///
+    /// Consumes `self`, converting it from Z-up to Y-up coordinates
+    ///
+    /// Maps `[x, y, z]` to `[x, z, -y]`, reversing `to_z_up()`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let up = PointND::from([0, 0, 1]);
+    /// assert_eq!(up.to_y_up().into_arr(), [0, 1, 0]);
+    /// ```
+    ///
+    pub fn to_y_up(self) -> Self {
+        let [x, y, z] = self.into_arr();
+        PointND::from([x, z, -y])
+    }
+
+    ///
+    /// Consumes `self`, converting it between right-handed and left-handed coordinates
+    ///
+    /// The Z axis is negated and the others are left as is. This converts between Y-up
+    /// conventions which only differ by the direction of Z (_e.g._ - OpenGL and Direct3D)
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([1, 2, 3]);
+    /// assert_eq!(p.flip_handedness().into_arr(), [1, 2, -3]);
+    /// ```
+    ///
+    pub fn flip_handedness(self) -> Self {
+        let [x, y, z] = self.into_arr();
+        PointND::from([x, y, -z])
+    }
+
+}
+
+// Convenience Swizzles
+// Generates methods returning new points from copies of the values at the given dimensions
+#[cfg(any(feature = "y", feature = "z", feature = "w"))]
+macro_rules! impl_swizzles {
+    ($($name:ident: $m:literal => [$($i:literal),+]),* $(,)?) => {$(
+        #[doc = concat!("Returns a new point containing copies of the `", stringify!($name), "` values of `self`")]
+        pub fn $name(&self) -> PointND<T, $m> {
+            PointND([$(self[$i]),+])
+        }
+    )*};
+}
+
+///
+/// Swizzle methods for 2D `PointND`'s
+///
+/// ```
+/// # use point_nd::PointND;
+/// let p = PointND::from([1, 2]);
+/// assert_eq!(p.yx().into_arr(), [2, 1]);
+/// ```
+///
+/// # Enabled by features:
+///
+/// - `default`
+///
+/// - `conv_methods`
+///
+/// - `y`
+///
+#[cfg(feature = "y")]
+impl<T> PointND<T, 2>
+    where T: Copy {
+
+    impl_swizzles!(
+        xy: 2 => [0, 1],
+        yx: 2 => [1, 0],
+    );
+
+}
+///
+/// Swizzle methods for 3D `PointND`'s
+///
+/// ```
+/// # use point_nd::PointND;
+/// let p = PointND::from([1, 2, 3]);
+/// assert_eq!(p.xz().into_arr(), [1, 3]);
+/// assert_eq!(p.zyx().into_arr(), [3, 2, 1]);
+/// ```
+///
+/// # Enabled by features:
+///
+/// - `default`
+///
+/// - `conv_methods`
+///
+/// - `z`
+///
+#[cfg(feature = "z")]
+impl<T> PointND<T, 3>
+    where T: Copy {
+
+    impl_swizzles!(
+        xy: 2 => [0, 1], xz: 2 => [0, 2],
+        yx: 2 => [1, 0], yz: 2 => [1, 2],
+        zx: 2 => [2, 0], zy: 2 => [2, 1],
+
+        xyz: 3 => [0, 1, 2],
+        zyx: 3 => [2, 1, 0],
+    );
+
+}
+///
+/// Swizzle methods for 4D `PointND`'s
+///
+/// ```
+/// # use point_nd::PointND;
+/// let p = PointND::from([1, 2, 3, 4]);
+/// assert_eq!(p.wy().into_arr(), [4, 2]);
+/// assert_eq!(p.xyz().into_arr(), [1, 2, 3]);
+/// assert_eq!(p.wzyx().into_arr(), [4, 3, 2, 1]);
+/// ```
+///
+/// # Enabled by features:
+///
+/// - `default`
+///
+/// - `conv_methods`
+///
+/// - `w`
+///
+#[cfg(feature = "w")]
+impl<T> PointND<T, 4>
+    where T: Copy {
+
+    impl_swizzles!(
+        xy: 2 => [0, 1], xz: 2 => [0, 2], xw: 2 => [0, 3],
+        yx: 2 => [1, 0], yz: 2 => [1, 2], yw: 2 => [1, 3],
+        zx: 2 => [2, 0], zy: 2 => [2, 1], zw: 2 => [2, 3],
+        wx: 2 => [3, 0], wy: 2 => [3, 1], wz: 2 => [3, 2],
+
+        xyz: 3 => [0, 1, 2],
+        zyx: 3 => [2, 1, 0],
+
+        xyzw: 4 => [0, 1, 2, 3],
+        wzyx: 4 => [3, 2, 1, 0],
+    );
+
+}
+///
+/// Methods for selecting and swizzling the values of `PointND`'s of any dimensions
+///
+impl<T, const N: usize> PointND<T, N>
+    where T: Copy {
+
+    ///
+    /// Returns a new point containing copies of the values at each of the specified `dims`,
+    /// in the order given
+    ///
+    /// Dimensions may be repeated or left out, so the returned point may have more dimensions
+    /// than `self`. Prefer the named swizzles (such as `xy()`) on 2D, 3D and 4D points where
+    /// possible, as those are checked at compile time
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([0, 10, 20, 30, 40]);
+    /// assert_eq!(p.select([4, 0, 0]).into_arr(), [40, 0, 0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - If any of the specified `dims` are out of bounds
+    ///
+    /// ```should_panic
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([0, 1]);
+    /// let _ = p.select([2]);
+    /// ```
+    ///
+    pub fn select<const M: usize>(&self, dims: [usize; M]) -> PointND<T, M> {
+        PointND::from_fn(|i| {
+            let dim = dims[i];
+            if dim >= N {
+                panic!("Attempted to select dimension {} of a PointND with {} dimensions", dim, N);
+            }
+            self[dim]
+        })
+    }
+
+    ///
+    /// Equivalent to `select()`, provided for familiarity with graphics libraries
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([0, 10, 20, 30, 40]);
+    /// assert_eq!(p.swizzle([4, 0, 0]).into_arr(), [40, 0, 0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - If any of the specified `dims` are out of bounds
+    ///
+    pub fn swizzle<const M: usize>(&self, dims: [usize; M]) -> PointND<T, M> {
+        self.select(dims)
+    }
+
+}
+
+// Gathering and Scattering
+///
+/// Methods for gathering and scattering the values of `PointND`'s of any dimensions at
+/// runtime indices
+///
+impl<T, const N: usize> PointND<T, N> {
+
+    ///
+    /// Returns a new point containing clones of the values at each of the `indices`, in the
+    /// order given
+    ///
+    /// This is the same as `select()`, except that the indices are themselves a point and the
+    /// values only need to implement `Clone`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let channels = PointND::from([0.1, 0.2, 0.3, 0.4]);
+    /// let order = PointND::from([3, 1]);
+    /// assert_eq!(channels.gather(&order).into_arr(), [0.4, 0.2]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - If any of the `indices` are out of bounds
+    ///
+    pub fn gather<const M: usize>(&self, indices: &PointND<usize, M>) -> PointND<T, M>
+        where T: Clone {
+
+        PointND::from_fn(|i| {
+            let index = indices[i];
+            if index >= N {
+                panic!("Attempted to gather dimension {} of a PointND with {} dimensions", index, N);
+            }
+            self[index].clone()
+        })
+    }
+
+    ///
+    /// Writes each of the `values` into the dimension of `self` at the respective index in
+    /// `indices`, reversing `gather()`
+    ///
+    /// If an index is repeated, the value written last (the one furthest along in `values`)
+    /// is kept. Dimensions which aren't in `indices` are left unchanged
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let mut p = PointND::from([0, 0, 0, 0]);
+    /// p.scatter(&PointND::from([3, 1, 3]), PointND::from([10, 20, 30]));
+    /// assert_eq!(p.into_arr(), [0, 20, 0, 30]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - If any of the `indices` are out of bounds. All indices are checked before any values
+    ///   are written, so `self` is left unchanged
+    ///
+    pub fn scatter<const M: usize>(&mut self, indices: &PointND<usize, M>, values: PointND<T, M>) {
+        if let Some(index) = indices.iter().find(|index| **index >= N) {
+            panic!("Attempted to scatter to dimension {} of a PointND with {} dimensions", index, N);
+        }
+        for (index, value) in indices.iter().zip(values) {
+            self[*index] = value;
+        }
+    }
+
+}
+
+///
+/// Methods for reordering the values of `PointND`'s of any dimensions
+///
+impl<T, const N: usize> PointND<T, N> {
+
+    ///
+    /// Consumes `self` and returns a new point with its values reordered, so that dimension
+    /// `i` of the new point holds the value at dimension `dims[i]` of `self`
+    ///
+    /// As values are moved rather than copied, each dimension must appear in `dims` exactly once
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from(["a", "b", "c"]);
+    /// assert_eq!(p.permute([2, 0, 1]).into_arr(), ["c", "a", "b"]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - If any of the specified `dims` are out of bounds
+    ///
+    /// - If any of the specified `dims` are repeated
+    ///
+    /// ```should_panic
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([0, 1, 2]);
+    /// let _ = p.permute([0, 0, 1]);
+    /// ```
+    ///
+    pub fn permute(self, dims: [usize; N]) -> Self {
+        let mut seen = [false; N];
+        for &dim in dims.iter() {
+            if dim >= N {
+                panic!("Attempted to permute dimension {} of a PointND with {} dimensions", dim, N);
+            }
+            if seen[dim] {
+                panic!("Attempted to permute a PointND with dimension {} repeated", dim);
+            }
+            seen[dim] = true;
+        }
+
+        // Each dimension is taken exactly once, as checked above
+        let mut items = self.into_arr().map(Some);
+        PointND::from_fn(|i| items[dims[i]].take().unwrap())
+    }
+
+    ///
+    /// Swaps the values at dimensions `a` and `b`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let mut p = PointND::from([0, 1, 2]);
+    /// p.swap_dims(0, 2);
+    /// assert_eq!(p.into_arr(), [2, 1, 0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - If either `a` or `b` are out of bounds
+    ///
+    pub fn swap_dims(&mut self, a: usize, b: usize) {
+        if a >= N || b >= N {
+            panic!("Attempted to swap dimensions {} and {} of a PointND with {} dimensions", a, b, N);
+        }
+        self.0.swap(a, b);
+    }
+
+    ///
+    /// Swaps the values at dimensions `a` and `b`, or returns an error (leaving `self`
+    /// unchanged) if either are out of bounds
+    ///
+    /// ```
+    /// # use point_nd::{PointND, Error};
+    /// let mut p = PointND::from([0, 1, 2]);
+    /// assert_eq!(p.try_swap_dims(0, 2), Ok(()));
+    /// assert_eq!(p.try_swap_dims(0, 3), Err(Error::IndexOutOfBounds { index: 3, dims: 3 }));
+    /// assert_eq!(p.into_arr(), [2, 1, 0]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - `Error::IndexOutOfBounds` if either `a` or `b` are out of bounds
+    ///
+    pub fn try_swap_dims(&mut self, a: usize, b: usize) -> Result<(), Error> {
+        if let Some(index) = [a, b].into_iter().find(|index| *index >= N) {
+            return Err(Error::IndexOutOfBounds { index, dims: N });
+        }
+        self.swap_dims(a, b);
+        Ok(())
+    }
+
+    ///
+    /// Reverses the order of the values in place
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let mut p = PointND::from([0, 1, 2]);
+    /// p.reverse();
+    /// assert_eq!(p.into_arr(), [2, 1, 0]);
+    /// ```
+    ///
+    pub fn reverse(&mut self) {
+        self.0.reverse();
+    }
+
+    ///
+    /// Consumes `self` and returns it with the order of its values reversed
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from(["row", "col"]).reversed();
+    /// assert_eq!(p.into_arr(), ["col", "row"]);
+    /// ```
+    ///
+    pub fn reversed(mut self) -> Self {
+        self.reverse();
+        self
+    }
+
+    ///
+    /// Rotates the values in place so that the value at dimension `n` becomes the first
+    ///
+    /// Rotating by more than the dimensions of the point wraps around, so `n` is effectively
+    /// taken modulo the dimensions
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let mut p = PointND::from([0, 1, 2, 3]);
+    /// p.rotate_dims_left(1);
+    /// assert_eq!(p.into_arr(), [1, 2, 3, 0]);
+    /// ```
+    ///
+    pub fn rotate_dims_left(&mut self, n: usize) {
+        if N > 0 {
+            self.0.rotate_left(n % N);
+        }
+    }
+
+    ///
+    /// Rotates the values in place so that the value at dimension `N - n` becomes the first
+    ///
+    /// As with `rotate_dims_left()`, rotating by more than the dimensions of the point wraps around
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let mut p = PointND::from([0, 1, 2, 3]);
+    /// p.rotate_dims_right(1);
+    /// assert_eq!(p.into_arr(), [3, 0, 1, 2]);
+    /// ```
+    ///
+    pub fn rotate_dims_right(&mut self, n: usize) {
+        if N > 0 {
+            self.0.rotate_right(n % N);
+        }
+    }
+
+}
+
+///
+/// Methods for sorting the values of `PointND`'s of any dimensions
+///
+/// Sorting is stable, meaning that equal values keep their original order. This is done
+/// without allocating
+///
+impl<T, const N: usize> PointND<T, N>
+    where T: Ord {
+
+    ///
+    /// Returns the dimensions of `self` in the order which would sort its values
+    ///
+    /// Passing the returned array to `permute()` gives the same point as `sorted()`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([30, 10, 20, 10]);
+    /// assert_eq!(p.argsort(), [1, 3, 2, 0]);
+    /// ```
+    ///
+    pub fn argsort(&self) -> [usize; N] {
+        let mut dims: [usize; N] = core::array::from_fn(|i| i);
+        // Breaking ties by dimension makes the unstable sort stable
+        dims.sort_unstable_by(|a, b| self[*a].cmp(&self[*b]).then(a.cmp(b)));
+        dims
+    }
+
+    ///
+    /// Consumes `self` and returns it with its values sorted in ascending order
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([30, 10, 20]);
+    /// assert_eq!(p.sorted().into_arr(), [10, 20, 30]);
+    /// ```
+    ///
+    pub fn sorted(self) -> Self {
+        let dims = self.argsort();
+        self.permute(dims)
+    }
+
+    ///
+    /// Returns a new point with the values of `self` rearranged into the rank order of
+    /// `template`, so that the smallest value is at the dimension of the smallest value in
+    /// `template`, the second smallest at that of the second smallest, _etc_
+    ///
+    /// Equal values in `template` are ranked by dimension (_i.e._ - the first is ranked lowest),
+    /// making the result deterministic
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([5, 1, 9]);
+    /// let template = PointND::from([200, 300, 100]);
+    /// assert_eq!(p.clamp_rank(&template).into_arr(), [5, 9, 1]);
+    /// ```
+    ///
+    pub fn clamp_rank(&self, template: &Self) -> Self
+        where T: Clone {
+
+        // Inverts the argsort of template, giving the rank of each of its dimensions
+        let mut ranks = [0; N];
+        for (rank, dim) in template.argsort().into_iter().enumerate() {
+            ranks[dim] = rank;
+        }
+        self.clone().sorted().permute(ranks)
+    }
+
+}
+
+
+
+// Formatting
+///
+/// The label of a dimension in the alternate `Debug` format of `PointND`'s
+///
+struct DimLabel {
+    index: usize,
+    dims: usize,
+}
+
+impl fmt::Debug for DimLabel {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.dims <= 4 {
+            f.write_str(["x", "y", "z", "w"][self.index])
+        } else {
+            write!(f, "dim{}", self.index)
+        }
+    }
+
+}
+
+///
+/// The compact format (`{:?}`) prints the point like a tuple struct containing an array.
+///
+/// The alternate format (`{:#?}`) labels each dimension. Points with **1..=4** dimensions are
+/// labeled `x`, `y`, `z` and `w`, while points with more dimensions are labeled `dim0`, `dim1`,
+/// _etc_.
+///
+/// ```
+/// # use point_nd::PointND;
+/// let p = PointND::from([1.0, 2.0]);
+/// assert_eq!(format!("{:?}", p), "PointND([1.0, 2.0])");
+/// assert_eq!(format!("{:#?}", p), "PointND {\n    x: 1.0,\n    y: 2.0,\n}");
+/// ```
+///
+impl<T, const N: usize> fmt::Debug for PointND<T, N>
+    where T: fmt::Debug {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !f.alternate() {
+            return f.debug_tuple("PointND").field(&self.0).finish();
+        }
+
+        f.write_str("PointND ")?;
+        f.debug_map()
+            .entries(self.iter().enumerate().map(|(index, item)| {
+                (DimLabel { index, dims: N }, item)
+            }))
+            .finish()
+    }
+
+}
+
+// Hashes the same as the contained array, which passes every item to hash_slice()
+//  at once. The integer types implement this with a single write to the hasher
+impl<T, const N: usize> Hash for PointND<T, N>
+    where T: Hash {
+
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+
+}
+
+///
+/// Returns a point with every item set to `T::default()`, the same as `zero()`
 ///
-/// - `default`
+/// Unlike arrays, this is implemented for points of any number of dimensions. Points of items
+/// which don't implement `Default` can't be created this way
 ///
-/// - `conv_methods`
+/// ```compile_fail
+/// # use point_nd::PointND;
+/// struct NotDefault;
 ///
-/// - `z`
+/// // ERROR: NotDefault does not implement Default
+/// let p = PointND::<NotDefault, 2>::default();
+/// ```
+///
+impl<T, const N: usize> Default for PointND<T, N>
+    where T: Default {
+
+    fn default() -> Self {
+        PointND::zero()
+    }
+
+}
+
+impl<T, const N: usize> From<[T; N]> for PointND<T, N> {
+
+    fn from(array: [T; N]) -> Self {
+        PointND(array)
+    }
+
+}
+
+impl<T, const N: usize> From<PointND<T, N>> for [T; N] {
+
+    fn from(point: PointND<T, N>) -> Self {
+        point.into_arr()
+    }
+
+}
+
+impl<T, const N: usize> TryFrom<&[T]> for PointND<T, N>
+    where T: Clone {
+
+    type Error = TryFromSliceError;
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+
+        let arr: &[T; N] = slice.try_into()?;
+        Ok( PointND(arr.clone()) )
+    }
+
+}
+
+impl<T, const N: usize> TryFrom<&mut [T]> for PointND<T, N>
+    where T: Clone {
+
+    type Error = TryFromSliceError;
+    fn try_from(slice: &mut [T]) -> Result<Self, Self::Error> {
+
+        let arr: &[T; N] = (&*slice).try_into()?;
+        Ok( PointND(arr.clone()) )
+    }
+
+}
+
+// Tuple Conversions
+#[cfg(feature = "x")]
+impl<T> From<(T,)> for PointND<T, 1> {
+
+    fn from(tuple: (T,)) -> Self {
+        PointND([tuple.0])
+    }
+
+}
+
+#[cfg(feature = "x")]
+impl<T> From<PointND<T, 1>> for (T,) {
+
+    fn from(point: PointND<T, 1>) -> Self {
+        let [x] = point.into_arr();
+        (x,)
+    }
+
+}
+
+#[cfg(feature = "y")]
+impl<T> From<(T, T)> for PointND<T, 2> {
+
+    fn from(tuple: (T, T)) -> Self {
+        PointND([tuple.0, tuple.1])
+    }
+
+}
+
+#[cfg(feature = "y")]
+impl<T> From<PointND<T, 2>> for (T, T) {
+
+    fn from(point: PointND<T, 2>) -> Self {
+        let [x, y] = point.into_arr();
+        (x, y)
+    }
+
+}
+
+///
+/// ```
+/// # use point_nd::PointND;
+/// let p: PointND<_, 3> = (1, 2, 3).into();
+/// assert_eq!(p.into_arr(), [1, 2, 3]);
+///
+/// let (x, y, z) = PointND::from([4, 5, 6]).into();
+/// assert_eq!((x, y, z), (4, 5, 6));
+/// ```
 ///
 #[cfg(feature = "z")]
-impl<T> PointND<T, 3>  {
+impl<T> From<(T, T, T)> for PointND<T, 3> {
+
+    fn from(tuple: (T, T, T)) -> Self {
+        PointND([tuple.0, tuple.1, tuple.2])
+    }
+
+}
+
+#[cfg(feature = "z")]
+impl<T> From<PointND<T, 3>> for (T, T, T) {
+
+    fn from(point: PointND<T, 3>) -> Self {
+        let [x, y, z] = point.into_arr();
+        (x, y, z)
+    }
+
+}
+
+#[cfg(feature = "w")]
+impl<T> From<(T, T, T, T)> for PointND<T, 4> {
+
+    fn from(tuple: (T, T, T, T)) -> Self {
+        PointND([tuple.0, tuple.1, tuple.2, tuple.3])
+    }
+
+}
+
+#[cfg(feature = "w")]
+impl<T> From<PointND<T, 4>> for (T, T, T, T) {
+
+    fn from(point: PointND<T, 4>) -> Self {
+        let [x, y, z, w] = point.into_arr();
+        (x, y, z, w)
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(test)]
+    mod iterating {
+        use super::*;
+        use std::string::String;
+        use std::vec::Vec;
+
+        #[test]
+        fn can_iter() {
+
+            let arr = [0, 1, 2, 3];
+
+            let p = PointND::<u8, 4>::from_slice(&arr);
+            for (i, item) in p.iter().enumerate() {
+                assert_eq!(arr[i], *item);
+            }
+
+            let mut p = PointND::<u8, 4>::from_slice(&arr);
+            for item in p.iter_mut() {
+                *item = 10;
+            }
+
+            for i in p.into_iter() {
+                assert_eq!(i, 10u8);
+            }
+
+        }
+
+        #[test]
+        fn into_iter_moves_items() {
+            let p = PointND::from([String::from("a"), String::from("b")]);
+            let items: Vec<String> = p.into_iter().collect();
+            assert_eq!(items, ["a", "b"]);
+
+            let p = PointND::from([Vec::from([1]), Vec::new()]);
+            let mut lens = [0; 2];
+            for (i, item) in p.into_iter().enumerate() {
+                lens[i] = item.len();
+            }
+            assert_eq!(lens, [1, 0]);
+        }
+
+        #[test]
+        fn can_iter_by_reference() {
+            let mut p = PointND::from([1, 2, 3]);
+
+            let mut sum = 0;
+            for item in &p {
+                sum += *item;
+            }
+            assert_eq!(sum, 6);
+
+            for item in &mut p {
+                *item *= 2;
+            }
+            assert_eq!(p.into_arr(), [2, 4, 6]);
+
+            let p = PointND::from([String::from("a")]);
+            for item in &p {
+                assert_eq!(item, "a");
+            }
+            assert_eq!(p.dims(), 1);
+        }
+
+        #[test]
+        fn can_sum_points() {
+            let deltas = [[1i64, 2, 3], [-4, 0, 10], [2, 2, 2]].map(PointND::from);
+
+            let total: PointND<i64, 3> = deltas.iter().sum();
+            assert_eq!(total.into_arr(), [-1, 4, 15]);
+
+            let total: PointND<i64, 3> = deltas.into_iter().sum();
+            assert_eq!(total.into_arr(), [-1, 4, 15]);
+
+            let floats = Vec::from([PointND::from([0.5, 1.0]), PointND::from([0.25, -1.0])]);
+            let total: PointND<f64, 2> = floats.into_iter().sum();
+            assert_eq!(total.into_arr(), [0.75, 0.0]);
+        }
+
+        #[test]
+        fn can_multiply_points() {
+            let factors = [[2, 3], [-1, 4], [5, 0]].map(PointND::from);
+
+            let product: PointND<i32, 2> = factors.iter().product();
+            assert_eq!(product.into_arr(), [-10, 0]);
+
+            let product: PointND<i32, 2> = factors.into_iter().product();
+            assert_eq!(product.into_arr(), [-10, 0]);
+        }
+
+        #[test]
+        fn empty_sums_and_products_are_zero_and_one() {
+            let total: PointND<u32, 4> = core::iter::empty::<PointND<u32, 4>>().sum();
+            assert_eq!(total, PointND::zero());
+
+            let total: PointND<f32, 2> = core::iter::empty::<&PointND<f32, 2>>().sum();
+            assert_eq!(total.into_arr(), [0.0; 2]);
+
+            let product: PointND<u32, 4> = core::iter::empty::<PointND<u32, 4>>().product();
+            assert_eq!(product, PointND::one());
+
+            let product: PointND<f64, 2> = core::iter::empty::<&PointND<f64, 2>>().product();
+            assert_eq!(product.into_arr(), [1.0; 2]);
+        }
+
+    }
+
+    #[cfg(test)]
+    mod constructors {
+        use super::*;
+
+        // The from() constructor is under tests::from_and_into
+
+        #[test]
+        fn from_slice_works() {
+            let arr = [0.0, 0.1, 0.2];
+            let p = PointND::<f64, 3>::from_slice(&arr);
+            for i in 0..p.dims() {
+                assert_eq!(arr[i], p[i]);
+            }
+        }
+
+        #[test]
+        fn can_construct_clone_only_items() {
+            use std::boxed::Box;
+
+            let v = std::vec![Some(Box::new(1)), None, Some(Box::new(3))];
+
+            let p = PointND::<_, 3>::from_slice(&v);
+            assert_eq!(p.into_arr(), [Some(Box::new(1)), None, Some(Box::new(3))]);
+
+            let p = PointND::<_, 3>::try_from_slice(&v).unwrap();
+            assert_eq!(p[2], Some(Box::new(3)));
+
+            let p: PointND<_, 3> = v.as_slice().try_into().unwrap();
+            assert_eq!(p[0], Some(Box::new(1)));
+
+            let p = PointND::<_, 4>::fill(Some(Box::new(7)));
+            assert!(p.iter().all(|item| *item == Some(Box::new(7))));
+        }
+
+        #[test]
+        fn fill_clones_all_but_last_item() {
+            use core::sync::atomic::{AtomicUsize, Ordering};
+            static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+            #[derive(Debug)]
+            struct Counted;
+
+            impl Clone for Counted {
+                fn clone(&self) -> Self {
+                    CLONES.fetch_add(1, Ordering::SeqCst);
+                    Counted
+                }
+            }
+
+            let _p = PointND::<_, 5>::fill(Counted);
+            assert_eq!(CLONES.load(Ordering::SeqCst), 4);
+
+            let _p = PointND::<_, 0>::fill(Counted);
+            assert_eq!(CLONES.load(Ordering::SeqCst), 4);
+        }
+
+        #[test]
+        fn cannot_try_from_slice_of_different_length() {
+            use std::string::String;
+
+            let v = std::vec![String::from("a"), String::from("b")];
+            assert_eq!(PointND::<_, 3>::try_from_slice(&v), Err(DimensionMismatchError { expected: 3, found: 2 }));
+            assert_eq!(PointND::<_, 1>::try_from_slice(&v), Err(DimensionMismatchError { expected: 1, found: 2 }));
+            assert!(PointND::<String, 0>::try_from_slice(&[]).is_ok());
+        }
+
+        #[test]
+        #[should_panic(expected = "PointND of 4 dimensions from a slice of 6 items")]
+        fn cannot_get_from_slice_of_different_length() {
+            let _p = PointND::<u8, 4>::from_slice(&[0; 6]);
+        }
+
+        #[test]
+        fn zero_and_one_work() {
+            assert_eq!(PointND::<u8, 3>::zero().into_arr(), [0; 3]);
+            assert_eq!(PointND::<f64, 2>::one().into_arr(), [1.0; 2]);
+            assert_eq!(PointND::<i32, 0>::one().dims(), 0);
+        }
+
+        #[test]
+        fn unit_axis_works() {
+            assert_eq!(PointND::<i64, 5>::unit_axis(0).into_arr(), [1, 0, 0, 0, 0]);
+            assert_eq!(PointND::<f32, 3>::unit_axis(2).into_arr(), [0.0, 0.0, 1.0]);
+        }
+
+        #[test]
+        #[should_panic(expected = "at axis 3 on a PointND of 3 dimensions")]
+        fn cannot_get_out_of_range_unit_axis() {
+            let _p = PointND::<i32, 3>::unit_axis(3);
+        }
+
+        #[test]
+        #[cfg(feature = "y")]
+        fn unit_consts_match_unit_axis_2d() {
+            assert_eq!(PointND::<i32, 2>::UNIT_X, PointND::unit_axis(0));
+            assert_eq!(PointND::<i32, 2>::UNIT_Y, PointND::unit_axis(1));
+        }
+
+        #[test]
+        #[cfg(feature = "z")]
+        fn unit_consts_match_unit_axis_3d() {
+            assert_eq!(PointND::<f64, 3>::UNIT_Y, PointND::unit_axis(1));
+            assert_eq!(PointND::<f64, 3>::UNIT_Z, PointND::unit_axis(2));
+        }
+
+        #[test]
+        #[cfg(feature = "w")]
+        fn unit_consts_match_unit_axis_4d() {
+            assert_eq!(PointND::<u8, 4>::UNIT_X, PointND::unit_axis(0));
+            assert_eq!(PointND::<u8, 4>::UNIT_W, PointND::unit_axis(3));
+        }
+
+        #[test]
+        #[cfg(feature = "y")]
+        fn directions_8_are_in_documented_order() {
+            let dirs = PointND::DIRECTIONS_8.map(PointND::into_arr);
+            assert_eq!(dirs, [[1, 0], [1, 1], [0, 1], [-1, 1], [-1, 0], [-1, -1], [0, -1], [1, -1]]);
+        }
+
+        #[test]
+        #[cfg(all(feature = "y", feature = "z"))]
+        fn i8_unit_consts_work_without_unit_axis() {
+            assert_eq!(PointND::<i8, 2>::UNIT_Y.into_arr(), [0, 1]);
+            assert_eq!(PointND::<i8, 3>::UNIT_Z.into_arr(), [0, 0, 1]);
+        }
+
+        #[test]
+        fn from_fn_works() {
+            let p = PointND::<_, 5>::from_fn(|i| i * 10);
+            assert_eq!(p.into_arr(), [0, 10, 20, 30, 40]);
+
+            let p = PointND::<i32, 0>::from_fn(|_| unreachable!());
+            assert_eq!(p.dims(), 0);
+        }
+
+        #[test]
+        fn from_fn_works_with_non_copy_items() {
+            #[derive(Debug, PartialEq)]
+            enum Label { Even(usize), Odd(usize) }
+
+            let p = PointND::<_, 3>::from_fn(|i| if i % 2 == 0 { Label::Even(i) } else { Label::Odd(i) });
+            assert_eq!(p.into_arr(), [Label::Even(0), Label::Odd(1), Label::Even(2)]);
+        }
+
+        #[test]
+        fn try_from_iter_works() {
+            let p = PointND::<_, 4>::try_from_iter(0..4);
+            assert_eq!(p.unwrap().into_arr(), [0, 1, 2, 3]);
+
+            let p = PointND::<i32, 0>::try_from_iter(core::iter::empty());
+            assert_eq!(p.unwrap().dims(), 0);
+        }
+
+        #[test]
+        fn cannot_try_from_short_iter() {
+            let p = PointND::<_, 4>::try_from_iter(0..2);
+            assert_eq!(p, Err(FromIterError::TooFew { expected: 4, found: 2 }));
+        }
+
+        #[test]
+        fn cannot_try_from_long_iter() {
+            let mut iter = 0..10;
+            let p = PointND::<_, 4>::try_from_iter(&mut iter);
+            assert_eq!(p, Err(FromIterError::TooMany { expected: 4 }));
+            // Only one extra item is consumed
+            assert_eq!(iter.next(), Some(5));
+
+            let p = PointND::<i32, 0>::try_from_iter(0..1);
+            assert_eq!(p, Err(FromIterError::TooMany { expected: 0 }));
+        }
+
+        #[test]
+        fn fill_works() {
+            let fill_val = 21u8;
+            let p = PointND::<u8, 5>::fill(fill_val);
+            for i in p.into_iter() {
+                assert_eq!(i, fill_val);
+            }
+        }
+
+    }
+
+    #[cfg(test)]
+    mod optional_getters {
+        use super::*;
+
+        // A function which couldn't be written for both 2D and 3D points using z()
+        fn z_or_zero<const N: usize>(p: &PointND<i32, N>) -> i32 {
+            *p.dim_z().unwrap_or(&0)
+        }
+
+        #[test]
+        fn dim_z_of_2d_and_3d_points() {
+            let p2 = PointND::from([1, 2]);
+            let p3 = PointND::from([1, 2, 3]);
+
+            assert_eq!(p2.dim_z(), None);
+            assert_eq!(p3.dim_z(), Some(&3));
+            assert_eq!(z_or_zero(&p2), 0);
+            assert_eq!(z_or_zero(&p3), 3);
+        }
+
+        #[test]
+        fn can_get_all_on_6d_points() {
+            let p = PointND::from([0, 1, 2, 3, 4, 5]);
+            assert_eq!(p.dim_x(), Some(&0));
+            assert_eq!(p.dim_y(), Some(&1));
+            assert_eq!(p.dim_z(), Some(&2));
+            assert_eq!(p.dim_w(), Some(&3));
+        }
+
+        #[test]
+        fn cannot_get_any_on_zero_dimensional_points() {
+            let p: PointND<i32, 0> = PointND::from([]);
+            assert_eq!(p.dim_x(), None);
+            assert_eq!(p.dim_w(), None);
+        }
+
+    }
+
+    #[cfg(test)]
+    mod raw_parts {
+        use super::*;
+        use core::mem::{align_of, size_of};
+
+        // Shaped like a function which would be exported from C
+        extern "C" fn sum_floats(ptr: *const f64, len: usize) -> f64 {
+            let mut sum = 0.0;
+            for i in 0..len {
+                sum += unsafe { *ptr.add(i) };
+            }
+            sum
+        }
+
+        extern "C" fn double_ints(ptr: *mut i32, len: usize) {
+            for i in 0..len {
+                unsafe { *ptr.add(i) *= 2; }
+            }
+        }
+
+        #[test]
+        fn has_same_layout_as_array() {
+            assert_eq!(size_of::<PointND<u8, 3>>(), size_of::<[u8; 3]>());
+            assert_eq!(align_of::<PointND<u64, 5>>(), align_of::<[u64; 5]>());
+            assert_eq!(size_of::<PointND<f32, 0>>(), 0);
+        }
+
+        #[test]
+        fn can_pass_pointers_to_extern_fns() {
+            let p = PointND::from([0.5, 1.5, 2.0, -1.0]);
+            assert_eq!(sum_floats(p.as_ptr(), p.dims()), 3.0);
+
+            let mut p = PointND::from([1, -2, 3]);
+            double_ints(p.as_mut_ptr(), p.dims());
+            assert_eq!(p.into_arr(), [2, -4, 6]);
+        }
+
+        #[test]
+        fn can_round_trip_through_pointers() {
+            let mut arr = [10, 20, 30];
+
+            let p: &PointND<i32, 3> = unsafe { PointND::from_ptr(arr.as_ptr()) };
+            assert_eq!(p.as_ptr(), arr.as_ptr());
+            assert_eq!(p[1], 20);
+
+            let p: &mut PointND<i32, 3> = unsafe { PointND::from_mut_ptr(arr.as_mut_ptr()) };
+            p[1] = -20;
+            assert_eq!(arr, [10, -20, 30]);
+        }
+
+        #[test]
+        fn can_borrow_arrays_as_points() {
+            let mut arr = [10, 20, 30];
+
+            let p = PointND::from_ref(&arr);
+            assert_eq!(p.as_ptr(), arr.as_ptr());
+            assert_eq!(p.as_array(), &[10, 20, 30]);
+
+            let p = PointND::from_mut_ref(&mut arr);
+            p[0] = -10;
+            p.as_mut_array()[2] = -30;
+            assert_eq!(arr, [-10, 20, -30]);
+        }
+
+        #[test]
+        fn can_cast_slices_of_arrays() {
+            let mut rows = [[0, 1], [2, 3], [4, 5]];
+
+            let points = PointND::cast_slice(&rows);
+            assert_eq!(points.len(), 3);
+            assert_eq!(points[1], PointND::from([2, 3]));
+
+            for p in PointND::cast_slice_mut(&mut rows) {
+                p[1] = p[0] * 10;
+            }
+            assert_eq!(rows, [[0, 0], [2, 20], [4, 40]]);
+
+            let empty: [[u8; 4]; 0] = [];
+            assert!(PointND::cast_slice(&empty).is_empty());
+        }
+
+    }
+
+    #[cfg(test)]
+    mod array_access {
+        use super::*;
+
+        #[test]
+        fn can_borrow_arrays_of_large_points() {
+            let mut p = PointND::<u32, 4096>::from_fn(|i| i as u32);
+            assert_eq!(p.arr().as_ptr(), p.as_ptr());
+            assert_eq!(p.arr()[4095], 4095);
+
+            p.arr_mut().iter_mut().for_each(|item| *item *= 2);
+            assert_eq!(p.arr()[4095], 8190);
+            assert_eq!(p.as_array(), p.arr());
+            assert_eq!(p.as_mut_array()[1], 2);
+        }
+
+        #[test]
+        #[cfg(feature = "alloc")]
+        fn can_convert_boxes_without_copying() {
+            use std::boxed::Box;
+            use std::vec;
+
+            // Built on the heap, as a point this large may overflow the stack
+            let arr: Box<[u64; 1 << 16]> = vec![7; 1 << 16].into_boxed_slice().try_into().unwrap();
+            let p: Box<PointND<u64, { 1 << 16 }>> = unsafe { Box::from_raw(Box::into_raw(arr) as *mut _) };
+            let ptr = p.as_ptr();
+
+            let arr = p.into_boxed_arr();
+            assert_eq!(arr.as_ptr(), ptr);
+            assert!(arr.iter().all(|item| *item == 7));
+
+            let p = Box::new(PointND::<std::string::String, 0>::from([]));
+            assert_eq!(p.into_boxed_arr().len(), 0);
+        }
+
+    }
+
+    #[cfg(test)]
+    mod as_ref_and_borrow {
+        use super::*;
+        use std::collections::HashSet;
+
+        fn sum_slice<S: AsRef<[i32]>>(s: S) -> i32 {
+            s.as_ref().iter().sum()
+        }
+
+        fn double_slice<S: AsMut<[i32]>>(mut s: S) -> S {
+            s.as_mut().iter_mut().for_each(|item| *item *= 2);
+            s
+        }
+
+        fn first_of_arr<A: AsRef<[i32; 3]>>(a: A) -> i32 {
+            a.as_ref()[0]
+        }
+
+        fn zero_last<A: AsMut<[i32; 3]>>(mut a: A) -> A {
+            a.as_mut()[2] = 0;
+            a
+        }
+
+        fn borrowed_len<B: Borrow<[i32]>>(b: B) -> usize {
+            b.borrow().len()
+        }
+
+        fn negate_all<B: BorrowMut<[i32]>>(mut b: B) -> B {
+            b.borrow_mut().iter_mut().for_each(|item| *item = -*item);
+            b
+        }
+
+        #[test]
+        fn can_pass_points_as_slices() {
+            let p = PointND::from([1, 2, 3]);
+            assert_eq!(sum_slice(p), 6);
+            assert_eq!(double_slice(p).into_arr(), [2, 4, 6]);
+
+            let mut p = p;
+            double_slice(&mut p);
+            assert_eq!(p.into_arr(), [2, 4, 6]);
+        }
+
+        #[test]
+        fn can_pass_points_as_arrays() {
+            let p = PointND::from([1, 2, 3]);
+            assert_eq!(first_of_arr(p), 1);
+            assert_eq!(zero_last(p).into_arr(), [1, 2, 0]);
+
+            let arr: &[i32; 3] = p.as_ref();
+            assert_eq!(arr, &[1, 2, 3]);
+        }
+
+        #[test]
+        fn can_pass_points_as_borrowed_slices() {
+            let p = PointND::from([1, -2, 3]);
+            assert_eq!(borrowed_len(p), 3);
+            assert_eq!(negate_all(p).into_arr(), [-1, 2, -3]);
+
+            let rows = [PointND::from([1, 2]), PointND::from([3, 4])];
+            assert_eq!(rows.concat(), [1, 2, 3, 4]);
+            assert_eq!(rows.join(&0), [1, 2, 0, 3, 4]);
+        }
+
+        #[test]
+        fn can_look_up_points_by_slice_and_array() {
+            let set: HashSet<PointND<i32, 2>> = [[0, 1], [2, 3]].map(PointND::from).into_iter().collect();
+            assert!(set.contains(&[2, 3][..]));
+            assert!(set.contains(&[0, 1]));
+            assert!(!set.contains(&[1, 0][..]));
+        }
+
+    }
+
+    #[cfg(test)]
+    mod indexing {
+        use super::*;
+
+        #[test]
+        fn can_get_slice_by_range_index() {
+            let p = PointND::from([0,1,2,3,4]);
+            let slice = &p[0..3];
+            assert_eq!(slice, [0,1,2]);
+        }
+
+        #[test]
+        #[should_panic]
+        fn cannot_get_out_of_bounds_index() {
+            let p = PointND::from([0,1,2]);
+            let _x = p[p.dims() + 1];
+        }
+
+        #[test]
+        fn can_set_value_by_index() {
+
+            let mut p = PointND::from([0,1,2]);
+
+            let new_val = 9999;
+            p[1] = new_val;
+
+            assert_eq!(p.into_arr(), [0, new_val, 2]);
+        }
+
+        #[test]
+        fn can_set_value_with_dim() {
+            let p = PointND::from([0,1,2,3,4,5]).with_dim(5, 50).with_dim(0, -1);
+            assert_eq!(p.into_arr(), [-1, 1, 2, 3, 4, 50]);
+        }
+
+        #[test]
+        #[should_panic(expected = "set dimension 3 of a PointND with 3 dimensions")]
+        fn cannot_set_out_of_bounds_with_dim() {
+            let _p = PointND::from([0,1,2]).with_dim(3, 0);
+        }
+
+    }
+
+    #[cfg(test)]
+    #[cfg(feature = "appliers")]
+    mod appliers {
+        use super::*;
+
+        #[test]
+        fn can_apply() {
+
+            let arr = [0,1,2,3];
+
+            let p = PointND::<u8, 4>
+                ::from(arr)
+                .apply(|a| a * 2);
+
+            assert_eq!(p.into_arr(), [0, 2, 4, 6]);
+        }
+
+        #[test]
+        fn can_apply_dims() {
+
+            let p = PointND::from([-2,-1,0,1,2])
+                .apply_dims(&[0, 3], |item| item - 10);
+            assert_eq!(p.into_arr(), [-12,-1, 0, -9, 2]);
+        }
+
+        #[test]
+        #[should_panic]
+        fn cannot_apply_dims_out_of_range() {
+            PointND::from([0, 1, 2]).apply_dims(&[0, 7], |item| item * 2);
+        }
+
+        #[test]
+        fn can_apply_range() {
+
+            let p = PointND::from([1, 2, 3, 4, 5])
+                .apply_range(2..=4, |item| item * 10);
+            assert_eq!(p.into_arr(), [1, 2, 30, 40, 50]);
+
+            let p = PointND::from([1, 2, 3, 4, 5])
+                .apply_range(..2, |item| -item);
+            assert_eq!(p.into_arr(), [-1, -2, 3, 4, 5]);
+
+            let p = PointND::from([1, 2, 3])
+                .apply_range(.., |item| item + 1);
+            assert_eq!(p.into_arr(), [2, 3, 4]);
+        }
+
+        #[test]
+        fn empty_ranges_apply_nothing() {
+
+            let p = PointND::from([1, 2, 3])
+                .apply_range(1..1, |_| panic!("Modifier called"))
+                .apply_range(3.., |_| panic!("Modifier called"))
+                .apply_range(5..5, |_| panic!("Modifier called"));
+            assert_eq!(p.into_arr(), [1, 2, 3]);
+
+            let p = PointND::<i32, 0>::from([])
+                .apply_range(.., |_| panic!("Modifier called"));
+            assert_eq!(p.dims(), 0);
+        }
+
+        #[test]
+        #[should_panic]
+        fn cannot_apply_range_out_of_range() {
+            PointND::from([0, 1, 2]).apply_range(1..=3, |item| item * 2);
+        }
+
+        #[test]
+        #[should_panic(expected = "dimensions from 7 of a PointND with 3 dimensions")]
+        fn cannot_apply_range_starting_out_of_range() {
+            PointND::from([1, 2, 3]).apply_range(7.., |item| item * 2);
+        }
+
+        #[test]
+        fn can_apply_vals() {
+
+            let p = PointND::from([0,1,2])
+                .apply_vals([Some(10), None, Some(20)],
+                            |a, b| {
+                        if let Some(i) = b {
+                            a + i
+                        } else {
+                            a
+                        }
+                    });
+            assert_eq!(p.into_arr(), [10, 1, 22]);
+        }
+
+        #[test]
+        fn can_apply_point() {
+
+            let p1 = PointND::from([0, 1, 2, 3]);
+            let p2 = PointND::from([0, -1, -2, -3]);
+            let p3 = p1.apply_point(p2, |a, b| a - b );
+            assert_eq!(p3.into_arr(), [0, 2, 4, 6]);
+        }
+
+        #[test]
+        fn can_apply_noclone_items() {
+
+            #[derive(Debug, Eq, PartialEq)]
+            enum X { A, B, C }
+
+            let p = PointND
+                ::from([X::A, X::B, X::C])
+                .apply(|x| {
+                    match x {
+                        X::A => X::B,
+                        X::B => X::C,
+                        X::C => X::A,
+                    }
+                });
+
+            assert_eq!(p.into_arr(), [X::B, X::C, X::A]);
+        }
+
+    }
+
+    #[cfg(test)]
+    #[cfg(feature = "var-dims")]
+    mod extenders {
+        use super::*;
+
+        #[test]
+        fn can_extend() {
+
+            let zero = PointND::<i32, 0>::from([]);
+            assert_eq!(zero.dims(), 0);
+
+            let two = zero.extend([0,1]);
+            assert_eq!(two.dims(), 2);
+            assert_eq!(two.into_arr(), [0, 1]);
+
+            let five = PointND
+                ::from([0,1,2])
+                .extend([3,4]);
+            assert_eq!(five.dims(), 5);
+            assert_eq!(five.into_arr(), [0,1,2,3,4]);
+
+            let sum = five.apply_point(PointND::from([0,1,2,3,4]), |a, b| a + b);
+            assert_eq!(sum.into_arr(), [0,2,4,6,8]);
+
+            let huge = PointND
+                ::from([0; 100])
+                .extend([1; 1_000]) as PointND<i32, 1_100>;
+            assert_eq!(huge.dims(), 1_100);
+        }
+
+        #[test]
+        fn can_extend_nothing() {
+            let arr: [i32; 0] = [];
+            let zero = PointND
+                ::from(arr)
+                .extend::<0, 0>(arr);
+            assert_eq!(zero.dims(), 0);
+        }
+
+    }
+
+    #[cfg(test)]
+    #[cfg(feature = "var-dims")]
+    #[allow(deprecated)]
+    mod retain {
+        use super::*;
+
+        #[test]
+        fn can_retain_n() {
+            let p = PointND
+                ::from([0,1,2,3])
+                .retain(3);
+
+            assert_eq!(p.dims(), 3);
+            assert_eq!(p.into_arr(), [0,1,2]);
+        }
+
+        #[test]
+        fn can_retain_zero() {
+            let p = PointND
+                ::from([0,1,2,3])
+                .retain(0);
+
+            assert_eq!(p.dims(), 0);
+            assert_eq!(p.into_arr(), [0; 0]);
+        }
+
+        #[test]
+        fn can_retain_same() {
+            let p = PointND
+                ::from([0,1,2,3])
+                .retain(4);
+
+            assert_eq!(p.dims(), 4);
+            assert_eq!(p.into_arr(), [0,1,2,3]);
+        }
+
+        #[test]
+        #[should_panic]
+        #[allow(unused_variables)]
+        fn cannot_retain_more_dimensions() {
+            let p = PointND
+                ::from([0,1,2,3])
+                .retain::<1000>(1000);
+        }
+
+    }
+
+    #[cfg(test)]
+    #[cfg(feature = "var-dims")]
+    mod contract {
+        use super::*;
+
+        #[test]
+        fn can_contract_front_and_back() {
+            let p = PointND::from([0,1,2,3,4]);
+
+            let front: PointND<_, 3> = p.contract();
+            assert_eq!(front.into_arr(), [0,1,2]);
+
+            let back: PointND<_, 3> = p.contract_back();
+            assert_eq!(back.into_arr(), [2,3,4]);
+
+            let same: PointND<_, 5> = p.contract_back();
+            assert_eq!(same, p);
+        }
+
+        #[test]
+        fn can_contract_to_zero() {
+            let p = PointND::from([0,1,2]);
+            assert_eq!(p.contract::<0>().into_arr(), [0; 0]);
+            assert_eq!(p.contract_back::<0>().into_arr(), [0; 0]);
+            assert_eq!(p.try_contract::<0>().map(|p| p.dims()), Ok(0));
+
+            let zero = PointND::<i32, 0>::from([]);
+            assert_eq!(zero.try_contract_back::<0>(), Ok(zero));
+        }
+
+        #[test]
+        fn cannot_try_contract_to_more_dimensions() {
+            let p = PointND::from([0,1,2]);
+            let err = ContractError { dims: 3, requested: 4 };
+            assert_eq!(p.try_contract::<4>(), Err(err));
+            assert_eq!(p.try_contract_back::<4>(), Err(err));
+
+            let zero = PointND::<i32, 0>::from([]);
+            assert_eq!(zero.try_contract::<1>(), Err(ContractError { dims: 0, requested: 1 }));
+        }
+
+        #[test]
+        #[should_panic]
+        fn cannot_contract_to_more_dimensions() {
+            PointND::from([0,1,2]).contract::<4>();
+        }
+
+        #[test]
+        #[should_panic]
+        fn cannot_contract_back_to_more_dimensions() {
+            PointND::from([0,1,2]).contract_back::<4>();
+        }
+
+        #[test]
+        fn can_contract_by_zero() {
+            let p: PointND<_, 4> = PointND::from([0,1,2,3]).contract_by(0);
+            assert_eq!(p.into_arr(), [0,1,2,3]);
+
+            let p: PointND<_, 4> = PointND::from([0,1,2,3]).contract_front(0);
+            assert_eq!(p.into_arr(), [0,1,2,3]);
+        }
+
+        #[test]
+        fn can_contract_by_n() {
+            let p: PointND<_, 2> = PointND::from([0,1,2,3]).contract_by(2);
+            assert_eq!(p.into_arr(), [0,1]);
+
+            let p: PointND<_, 1> = PointND::from([0,1,2,3]).contract_front(3);
+            assert_eq!(p.into_arr(), [3]);
+        }
+
+        #[test]
+        fn can_contract_by_all() {
+            let p: PointND<_, 0> = PointND::from([0,1,2,3]).contract_by(4);
+            assert_eq!(p.dims(), 0);
+
+            let p: PointND<_, 0> = PointND::from([0,1,2,3]).contract_front(4);
+            assert_eq!(p.dims(), 0);
+        }
+
+        #[test]
+        #[should_panic(expected = "remove 5 dimensions from a PointND of 4 dimensions in contract_by()")]
+        fn cannot_contract_by_more_dimensions() {
+            let _p: PointND<_, 0> = PointND::from([0,1,2,3]).contract_by(5);
+        }
+
+        #[test]
+        #[should_panic(expected = "remove 5 dimensions from a PointND of 4 dimensions in contract_front()")]
+        fn cannot_contract_front_more_dimensions() {
+            let _p: PointND<_, 0> = PointND::from([0,1,2,3]).contract_front(5);
+        }
+
+        #[test]
+        #[should_panic(expected = "into a PointND of 3 dimensions in contract_by()")]
+        fn cannot_contract_by_into_wrong_dimensions() {
+            let _p: PointND<_, 3> = PointND::from([0,1,2,3]).contract_by(2);
+        }
+
+    }
+
+    #[cfg(test)]
+    #[cfg(feature = "var-dims")]
+    mod resize {
+        use super::*;
+        use std::string::String;
+
+        #[test]
+        fn can_grow() {
+            let p: PointND<_, 3> = PointND::from([1.0, 2.0]).resize(0.5);
+            assert_eq!(p.into_arr(), [1.0, 2.0, 0.5]);
+
+            let p: PointND<_, 2> = PointND::from([]).resize(String::from("a"));
+            assert_eq!(p.into_arr(), ["a", "a"]);
+        }
+
+        #[test]
+        fn can_shrink() {
+            let p: PointND<_, 2> = PointND::from([1, 2, 3, 4]).resize(0);
+            assert_eq!(p.into_arr(), [1, 2]);
+
+            let p: PointND<_, 0> = PointND::from([1, 2]).resize(0);
+            assert_eq!(p.dims(), 0);
+        }
+
+        #[test]
+        fn resizing_to_same_dims_changes_nothing() {
+            let p: PointND<_, 3> = PointND::from([1, 2, 3]).resize(0);
+            assert_eq!(p.into_arr(), [1, 2, 3]);
+
+            let p: PointND<_, 3> = PointND::from([1, 2, 3]).resize_with(|_| unreachable!());
+            assert_eq!(p.into_arr(), [1, 2, 3]);
+        }
+
+        #[test]
+        fn can_resize_with_indices() {
+            let p: PointND<_, 4> = PointND
+                ::from([String::from("x")])
+                .resize_with(|i| std::format!("dim{}", i));
+            assert_eq!(p.into_arr(), ["x", "dim1", "dim2", "dim3"]);
+        }
+
+    }
+
+    #[cfg(test)]
+    #[cfg(feature = "var-dims")]
+    mod split_at {
+        use super::*;
+        use std::string::String;
+
+        #[test]
+        fn can_split_and_extend_back() {
+            let p = PointND::from([0,1,2,3,4,5]);
+            let (a, b): (PointND<_, 2>, PointND<_, 4>) = p.split_at();
+            assert_eq!(*a, [0,1]);
+            assert_eq!(*b, [2,3,4,5]);
+
+            let joined: PointND<_, 6> = a.extend(b.into_arr());
+            assert_eq!(joined, p);
+        }
+
+        #[test]
+        fn can_split_non_copy_items() {
+            let p = PointND::from([String::from("a"), String::from("b"), String::from("c")]);
+            let (a, b): (PointND<_, 1>, PointND<_, 2>) = p.split_at();
+            assert_eq!(a.into_arr(), ["a"]);
+            assert_eq!(b.into_arr(), ["b", "c"]);
+        }
+
+        #[test]
+        fn can_split_into_empty_points() {
+            let (a, b): (PointND<_, 0>, PointND<_, 3>) = PointND::from([0,1,2]).split_at();
+            assert_eq!(a.dims(), 0);
+            assert_eq!(b.into_arr(), [0,1,2]);
+
+            let (a, b): (PointND<_, 3>, PointND<_, 0>) = PointND::from([0,1,2]).split_at();
+            assert_eq!(a.into_arr(), [0,1,2]);
+            assert_eq!(b.dims(), 0);
+        }
+
+        #[test]
+        #[should_panic(expected = "split_at() a PointND of 3 dimensions into PointND's of 2 and 2 dimensions")]
+        fn cannot_split_into_wrong_dimensions() {
+            let (_a, _b): (PointND<_, 2>, PointND<_, 2>) = PointND::from([0,1,2]).split_at();
+        }
+
+    }
+
+    #[cfg(test)]
+    #[cfg(feature = "var-dims")]
+    mod remove_and_insert_dims {
+        use super::*;
+        use std::string::String;
+
+        #[test]
+        fn can_remove_first_middle_and_last_dims() {
+            let p: PointND<_, 3> = PointND::from([0,1,2,3]).remove_dim(0);
+            assert_eq!(p.into_arr(), [1,2,3]);
+
+            let p: PointND<_, 3> = PointND::from([0,1,2,3]).remove_dim(2);
+            assert_eq!(p.into_arr(), [0,1,3]);
+
+            let p: PointND<_, 3> = PointND::from([0,1,2,3]).remove_dim(3);
+            assert_eq!(p.into_arr(), [0,1,2]);
+
+            let p: PointND<_, 0> = PointND::from([String::from("a")]).remove_dim(0);
+            assert_eq!(p.dims(), 0);
+        }
+
+        #[test]
+        fn can_insert_first_middle_and_last_dims() {
+            let p: PointND<_, 4> = PointND::from([0,1,2]).insert_dim(0, 9);
+            assert_eq!(p.into_arr(), [9,0,1,2]);
+
+            let p: PointND<_, 4> = PointND::from([0,1,2]).insert_dim(2, 9);
+            assert_eq!(p.into_arr(), [0,1,9,2]);
+
+            let p: PointND<_, 4> = PointND::from([0,1,2]).insert_dim(3, 9);
+            assert_eq!(p.into_arr(), [0,1,2,9]);
+
+            let p: PointND<_, 1> = PointND::from([]).insert_dim(0, String::from("a"));
+            assert_eq!(p.into_arr(), ["a"]);
+        }
+
+        #[test]
+        fn removing_inserted_dim_gives_original() {
+            let p = PointND::from([0,1,2,3,4]);
+            for i in 0..p.dims() {
+                let inserted: PointND<_, 6> = p.insert_dim(i, 100);
+                let removed: PointND<_, 5> = inserted.remove_dim(i);
+                assert_eq!(removed, p);
+            }
+        }
+
+        #[test]
+        #[should_panic(expected = "remove_dim() at index 4 of a PointND of 4 dimensions")]
+        fn cannot_remove_out_of_range_dim() {
+            let _p: PointND<_, 3> = PointND::from([0,1,2,3]).remove_dim(4);
+        }
+
+        #[test]
+        #[should_panic(expected = "insert_dim() at index 4 of a PointND of 3 dimensions")]
+        fn cannot_insert_out_of_range_dim() {
+            let _p: PointND<_, 4> = PointND::from([0,1,2]).insert_dim(4, 0);
+        }
+
+        #[test]
+        #[should_panic(expected = "remove_dim() from a PointND of 4 dimensions into a PointND of 2 dimensions")]
+        fn cannot_remove_dim_into_wrong_dimensions() {
+            let _p: PointND<_, 2> = PointND::from([0,1,2,3]).remove_dim(0);
+        }
+
+        #[test]
+        #[should_panic(expected = "insert_dim() into a PointND of 5 dimensions from a PointND of 3 dimensions")]
+        fn cannot_insert_dim_into_wrong_dimensions() {
+            let _p: PointND<_, 5> = PointND::from([0,1,2]).insert_dim(0, 0);
+        }
+
+    }
+
+    #[cfg(test)]
+    #[cfg(any(feature = "x", feature = "y", feature = "z", feature = "w"))]
+    mod conv_methods {
+        use super::*;
+
+        #[cfg(test)]
+        #[cfg(any(feature = "x", feature = "y", feature = "z", feature = "w"))]
+        mod get {
+            use super::*;
+
+            #[test]
+            #[cfg(feature = "x")]
+            fn getter_for_1d_points_work() {
+                let arr = [0];
+                let p = PointND::from(arr);
+                assert_eq!(*p.x(), arr[0]);
+            }
+
+            #[test]
+            #[cfg(feature = "y")]
+            fn getters_for_2d_points_work() {
+                let arr = [0,1];
+                let p = PointND::from(arr);
+
+                assert_eq!(*p.x(), arr[0]);
+                assert_eq!(*p.y(), arr[1]);
+            }
+
+            #[test]
+            #[cfg(feature = "z")]
+            fn getters_for_3d_points_work() {
+                let arr = [0,1,2];
+                let p = PointND::from(arr);
+
+                assert_eq!(*p.x(), arr[0]);
+                assert_eq!(*p.y(), arr[1]);
+                assert_eq!(*p.z(), arr[2]);
+            }
+
+            #[test]
+            #[cfg(feature = "w")]
+            fn getters_for_4d_points_work() {
+                let arr = [0,1,2,3];
+                let p = PointND::from(arr);
+
+                assert_eq!(*p.x(), arr[0]);
+                assert_eq!(*p.y(), arr[1]);
+                assert_eq!(*p.z(), arr[2]);
+                assert_eq!(*p.w(), arr[3]);
+            }
+
+        }
+
+        #[cfg(test)]
+        #[cfg(any(feature = "x", feature = "y", feature = "z", feature = "w"))]
+        mod with {
+            use super::*;
+
+            #[test]
+            #[cfg(feature = "x")]
+            fn with_for_1d_points_work() {
+                let p = PointND::from([0]).with_x(5);
+                assert_eq!(*p.x(), 5);
+            }
+
+            #[test]
+            #[cfg(feature = "y")]
+            fn with_for_2d_points_work() {
+                let p = PointND::<_, 2>::fill(0).with_x(5).with_y(6);
+                assert_eq!(p.into_arr(), [5, 6]);
+            }
+
+            #[test]
+            #[cfg(feature = "z")]
+            fn with_for_3d_points_work() {
+                let p = PointND::<_, 3>::fill(0).with_z(7).with_x(5).with_y(6);
+                assert_eq!(p.into_arr(), [5, 6, 7]);
+            }
+
+            #[test]
+            #[cfg(feature = "w")]
+            fn with_for_4d_points_work() {
+                let p = PointND::fill(0).with_w(8).with_x(5).with_y(6).with_z(7);
+                assert_eq!(p.into_arr(), [5, 6, 7, 8]);
+            }
+
+        }
+
+        #[cfg(test)]
+        #[cfg(any(feature = "x", feature = "y", feature = "z", feature = "w"))]
+        mod get_mut {
+            use super::*;
+
+            #[test]
+            #[cfg(feature = "x")]
+            fn mut_getter_for_1d_points_work() {
+                let mut p = PointND::from([0]);
+                *p.x_mut() += 10;
+                assert_eq!(*p.x(), 10);
+            }
+
+            #[test]
+            #[cfg(feature = "y")]
+            fn mut_getters_for_2d_points_work() {
+                let mut p = PointND::from([0,1]);
+                *p.x_mut() += 10;
+                *p.y_mut() *= 5;
+
+                assert_eq!(*p.x(), 10);
+                assert_eq!(*p.y(), 5);
+            }
+
+            #[test]
+            #[cfg(feature = "z")]
+            fn mut_getters_for_3d_points_work() {
+                let mut p = PointND::from([0,1,2]);
+                *p.x_mut() += 10;
+                *p.y_mut() *= 5;
+                *p.z_mut() = 20;
+
+                assert_eq!(*p.x(), 10);
+                assert_eq!(*p.y(), 5);
+                assert_eq!(*p.z(), 20);
+            }
+
+            #[test]
+            #[cfg(feature = "w")]
+            fn mut_getters_for_4d_points_work() {
+                let mut p = PointND::from([[0; 2], [1; 2], [2; 2], [3; 2]]);
+                p.x_mut().fill(10);
+                p.y_mut()[0] = 5;
+                p.z_mut().reverse();
+                p.w_mut().swap(0, 1);
+
+                assert_eq!(*p.x(), [10, 10]);
+                assert_eq!(*p.y(), [5, 1]);
+                assert_eq!(*p.z(), [2, 2]);
+                assert_eq!(*p.w(), [3, 3]);
+            }
+
+        }
+
+        #[cfg(test)]
+        #[cfg(any(feature = "x", feature = "y", feature = "z", feature = "w"))]
+        mod set {
+            use super::*;
+
+            #[test]
+            #[cfg(feature = "x")]
+            fn setter_for_1d_points_work() {
+
+                let old_vals = [0];
+                let new_val = 4;
+                let mut p = PointND::from(old_vals);
+
+                p.set_x(new_val);
+                assert_eq!(*p.x(), new_val);
+            }
+
+            #[test]
+            #[cfg(feature = "y")]
+            fn setters_for_2d_points_work() {
+
+                let old_vals = [0,1];
+                let new_vals = [4,5];
+                let mut p = PointND::from(old_vals);
+
+                p.set_x(new_vals[0]);
+                p.set_y(new_vals[1]);
+
+                assert_eq!(*p.x(), new_vals[0]);
+                assert_eq!(*p.y(), new_vals[1]);
+            }
+
+            #[test]
+            #[cfg(feature = "z")]
+            fn setters_for_3d_points_work() {
+
+                let old_vals = [0,1,2];
+                let new_vals = [4,5,6];
+                let mut p = PointND::from(old_vals);
+
+                p.set_x(new_vals[0]);
+                p.set_y(new_vals[1]);
+                p.set_z(new_vals[2]);
+
+                assert_eq!(*p.x(), new_vals[0]);
+                assert_eq!(*p.y(), new_vals[1]);
+                assert_eq!(*p.z(), new_vals[2]);
+            }
+
+            #[test]
+            #[cfg(feature = "w")]
+            fn setters_for_4d_points_work() {
+
+                let old_vals = [0,1,2,3];
+                let new_vals = [4,5,6,7];
+                let mut p = PointND::from(old_vals);
+
+                p.set_x(new_vals[0]);
+                p.set_y(new_vals[1]);
+                p.set_z(new_vals[2]);
+                p.set_w(new_vals[3]);
+
+                assert_eq!(*p.x(), new_vals[0]);
+                assert_eq!(*p.y(), new_vals[1]);
+                assert_eq!(*p.z(), new_vals[2]);
+                assert_eq!(*p.w(), new_vals[3]);
+            }
+
+        }
+
+        #[cfg(test)]
+        #[cfg(any(feature = "x", feature = "y", feature = "z", feature = "w"))]
+        mod shift {
+            use super::*;
+
+            #[test]
+            #[cfg(feature = "x")]
+            fn can_shift_1d_points() {
+                let mut p = PointND::from([0.1]);
+                p.shift_x(1.23);
+
+                assert_eq!(p.into_arr(), [1.33]);
+            }
+
+            #[test]
+            #[cfg(feature = "y")]
+            fn can_shift_2d_points() {
+                let mut p = PointND::from([12, 345]);
+                p.shift_x(-22);
+                p.shift_y(-345);
+
+                assert_eq!(p.into_arr(), [-10, 0]);
+            }
+
+            #[test]
+            #[cfg(feature = "z")]
+            fn can_shift_3d_points() {
+                let mut p = PointND::from([42.4, 2.85, 75.01]);
+                p.shift_x(40.6);
+                p.shift_y(-2.85);
+                p.shift_z(24.99);
+
+                assert_eq!(p.into_arr(), [83.0, 0.0, 100.0]);
+            }
+
+            #[test]
+            #[cfg(feature = "w")]
+            fn can_shift_4d_points() {
+                let mut p = PointND::from([0,1,2,3]);
+                p.shift_x(10);
+                p.shift_y(-2);
+                p.shift_z(5);
+                p.shift_w(0);
+
+                assert_eq!(p.into_arr(), [10, -1, 7, 3]);
+            }
+
+        }
+
+    }
+
+    #[cfg(test)]
+    mod bounds {
+        use super::*;
+
+        const MIN: [i32; 3] = [0, -5, 10];
+        const MAX: [i32; 3] = [4, 5, 20];
+
+        fn outside(arr: [i32; 3], mode: BoundsMode) -> Option<usize> {
+            PointND::from(arr).which_outside(&PointND::from(MIN), &PointND::from(MAX), mode)
+        }
+
+        #[test]
+        fn all_inside_is_between() {
+            let p = PointND::from([2, 0, 15]);
+            for mode in [BoundsMode::Closed, BoundsMode::Open, BoundsMode::ClosedOpen, BoundsMode::OpenClosed] {
+                assert!(p.between(&PointND::from(MIN), &PointND::from(MAX), mode));
+                assert_eq!(outside(p.into_arr(), mode), None);
+            }
+        }
+
+        #[test]
+        fn can_find_each_axis_outside() {
+            assert_eq!(outside([-1, 0, 15], BoundsMode::Closed), Some(0));
+            assert_eq!(outside([2, 6, 15], BoundsMode::Closed), Some(1));
+            assert_eq!(outside([2, 0, 9], BoundsMode::Closed), Some(2));
+            // Only the first violating axis is returned
+            assert_eq!(outside([2, 60, 90], BoundsMode::Closed), Some(1));
+        }
+
+        #[test]
+        fn min_boundary_depends_on_mode() {
+            let on_min = [0, -5, 10];
+            assert_eq!(outside(on_min, BoundsMode::Closed), None);
+            assert_eq!(outside(on_min, BoundsMode::ClosedOpen), None);
+            assert_eq!(outside(on_min, BoundsMode::Open), Some(0));
+            assert_eq!(outside(on_min, BoundsMode::OpenClosed), Some(0));
+        }
+
+        #[test]
+        fn max_boundary_depends_on_mode() {
+            let on_max = [2, 5, 15];
+            assert_eq!(outside(on_max, BoundsMode::Closed), None);
+            assert_eq!(outside(on_max, BoundsMode::OpenClosed), None);
+            assert_eq!(outside(on_max, BoundsMode::Open), Some(1));
+            assert_eq!(outside(on_max, BoundsMode::ClosedOpen), Some(1));
+        }
+
+        #[test]
+        fn component_min_and_max_interleave_items() {
+            let p1 = PointND::from([1.0, -3.0, 7.5, 0.0]);
+            let p2 = PointND::from([-1.0, 2.0, 8.0, -0.5]);
+
+            assert_eq!(p1.component_min(p2).into_arr(), [-1.0, -3.0, 7.5, -0.5]);
+            assert_eq!(p1.component_max(p2).into_arr(), [1.0, 2.0, 8.0, 0.0]);
+        }
+
+        #[test]
+        fn can_get_component_min_and_max_of_non_copy_items() {
+            #[derive(Debug, PartialEq, PartialOrd)]
+            struct NoCopy(i32);
+
+            let p1 = PointND::from([NoCopy(1), NoCopy(5)]);
+            let p2 = PointND::from([NoCopy(3), NoCopy(2)]);
+            assert_eq!(p1.component_min(p2).into_arr(), [NoCopy(1), NoCopy(2)]);
+        }
+
+        #[test]
+        fn can_clamp() {
+            let lo = PointND::from([0, -5, 10, 0]);
+            let hi = PointND::from([4, 5, 20, 0]);
+
+            let p = PointND::from([-1, 6, 15, 0]).clamp(lo, hi);
+            assert_eq!(p.into_arr(), [0, 5, 15, 0]);
+
+            let p = PointND::from([7, -9, 20, 3]).clamp(lo, hi);
+            assert_eq!(p.into_arr(), [4, -5, 20, 0]);
+        }
+
+        #[test]
+        #[should_panic(expected = "at dimension 1")]
+        fn cannot_clamp_with_inverted_bounds() {
+            let lo = PointND::from([0, 10]);
+            let hi = PointND::from([10, 0]);
+            let _p = PointND::from([5, 5]).clamp(lo, hi);
+        }
+
+        #[test]
+        fn clamp_report_masks_partially_outside_points() {
+            let min = PointND::from([0, -5, 10, 0]);
+            let max = PointND::from([4, 5, 20, 0]);
+
+            let (p, mask) = PointND::from([-1, 0, 25, 0]).clamp_report(&min, &max);
+            assert_eq!(p.into_arr(), [0, 0, 20, 0]);
+            assert_eq!(mask.into_arr(), [true, false, true, false]);
+
+            let (p, mask) = PointND::from([2, 9, 10, 0]).clamp_report(&min, &max);
+            assert_eq!(p.into_arr(), [2, 5, 10, 0]);
+            assert_eq!(mask.into_arr(), [false, true, false, false]);
+        }
+
+        #[test]
+        fn clamp_report_masks_fully_inside_and_outside_points() {
+            let min = PointND::from([0.0, 0.0, 0.0]);
+            let max = PointND::from([1.0, 1.0, 1.0]);
+
+            let (p, mask) = PointND::from([0.0, 0.5, 1.0]).clamp_report(&min, &max);
+            assert_eq!(p.into_arr(), [0.0, 0.5, 1.0]);
+            assert_eq!(mask.into_arr(), [false; 3]);
+
+            let (p, mask) = PointND::from([-1.0, 2.0, 1.5]).clamp_report(&min, &max);
+            assert_eq!(p.into_arr(), [0.0, 1.0, 1.0]);
+            assert_eq!(mask.into_arr(), [true; 3]);
+        }
+
+        #[test]
+        fn can_check_if_any_clamped() {
+            let min = PointND::from([0, 0]);
+            let max = PointND::from([10, 10]);
+
+            assert!(!PointND::from([0, 10]).clamped_any(&min, &max).1);
+            assert!(PointND::from([0, 11]).clamped_any(&min, &max).1);
+        }
+
+        #[test]
+        #[should_panic]
+        fn cannot_clamp_report_with_inverted_bounds() {
+            let _p = PointND::from([5, 5]).clamp_report(&PointND::from([0, 10]), &PointND::from([10, 0]));
+        }
+
+        #[test]
+        fn zero_dimensional_points_are_always_between() {
+            let p: PointND<i32, 0> = PointND::from([]);
+            assert!(p.between(&p, &p, BoundsMode::Open));
+        }
+
+    }
+
+    #[cfg(test)]
+    mod extremes {
+        use super::*;
+
+        #[test]
+        fn can_get_argmin_and_argmax() {
+            let p = PointND::from([2.5, -1.0, 9.0, 0.0]);
+            assert_eq!(p.argmin(), Some((1, &-1.0)));
+            assert_eq!(p.argmax(), Some((2, &9.0)));
+            assert_eq!(p.min_component(), Some(&-1.0));
+            assert_eq!(p.max_component(), Some(&9.0));
+        }
+
+        #[test]
+        fn ties_resolve_to_lowest_index() {
+            let p = PointND::from([4, 1, 4, 1, 0, 0]);
+            assert_eq!(p.argmin(), Some((4, &0)));
+            assert_eq!(p.argmax(), Some((0, &4)));
+
+            let p = PointND::<_, 3>::fill(7);
+            assert_eq!(p.argmin(), Some((0, &7)));
+            assert_eq!(p.argmax(), Some((0, &7)));
+        }
+
+        #[test]
+        fn cannot_get_extremes_of_zero_dimensional_points() {
+            let p: PointND<i32, 0> = PointND::from([]);
+            assert_eq!(p.argmin(), None);
+            assert_eq!(p.argmax(), None);
+            assert_eq!(p.min_component(), None);
+            assert_eq!(p.max_component(), None);
+            assert_eq!(p.arg_min_max(), None);
+            assert_eq!(p.min_max_components(), None);
+        }
+
+        #[test]
+        fn min_max_of_single_item_is_same_item() {
+            let p = PointND::from([42]);
+            assert_eq!(p.arg_min_max(), Some((0, 0)));
+            assert_eq!(p.min_max_components(), Some((&42, &42)));
+        }
+
+        #[test]
+        fn min_max_ties_resolve_to_lowest_index() {
+            let p = PointND::from([5, 0, 5, 0]);
+            assert_eq!(p.arg_min_max(), Some((1, 0)));
+        }
+
+        #[test]
+        fn min_max_matches_two_passes() {
+            // Simple LCG, so the test is deterministic without depending on an RNG crate
+            let mut seed: u32 = 0x2545_F491;
+            let mut next = || {
+                seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (seed >> 24) as u8 % 16
+            };
+
+            for _ in 0..500 {
+                let p = PointND::from([next(), next(), next(), next(), next(), next(), next()]);
+                let (min, max) = p.arg_min_max().unwrap();
+                assert_eq!(min, p.argmin().unwrap().0);
+                assert_eq!(max, p.argmax().unwrap().0);
+                assert_eq!(p.min_max_components(), Some((p.min_component().unwrap(), p.max_component().unwrap())));
+            }
+        }
+
+    }
+
+    #[cfg(test)]
+    mod change_detection {
+        use super::*;
+        use std::vec::Vec;
+
+        #[test]
+        fn unchanged_points_have_no_diff() {
+            let p = PointND::from([1, 2, 3]);
+            assert_eq!(p.diff(&p).count(), 0);
+            assert_eq!(p.changed_mask(&p).into_arr(), [false; 3]);
+
+            let p = PointND::<u8, 0>::from([]);
+            assert_eq!(p.diff(&p).count(), 0);
+        }
+
+        #[test]
+        fn can_diff_all_changes() {
+            let previous = PointND::from(["a", "b", "c"]);
+            let current = PointND::from(["x", "y", "z"]);
+
+            let changes: Vec<_> = current.diff(&previous).collect();
+            assert_eq!(changes, [(0, &"a", &"x"), (1, &"b", &"y"), (2, &"c", &"z")]);
+            assert_eq!(current.changed_mask(&previous).into_arr(), [true; 3]);
+        }
+
+        #[test]
+        fn can_diff_single_change() {
+            let previous = PointND::<i64, 6>::from_fn(|i| i as i64);
+            let mut current = previous;
+            current[4] = -4;
+
+            let changes: Vec<_> = current.diff(&previous).collect();
+            assert_eq!(changes, [(4, &4, &-4)]);
+            assert_eq!(current.changed_mask(&previous).into_arr(), [false, false, false, false, true, false]);
+        }
+
+        #[test]
+        fn nan_items_are_always_changed() {
+            let p = PointND::from([f64::NAN, 1.0, -0.0]);
+            let q = PointND::from([f64::NAN, 1.0, 0.0]);
+
+            assert_eq!(p.diff(&p).map(|(i, _, _)| i).collect::<Vec<_>>(), [0]);
+            assert_eq!(p.changed_mask(&q).into_arr(), [true, false, false]);
+        }
+
+    }
+
+    #[cfg(test)]
+    mod searching {
+        use super::*;
+
+        #[test]
+        fn can_check_if_sorted() {
+            assert!(PointND::from([-3, 0, 0, 8]).is_sorted_components());
+            assert!(PointND::from([1.5]).is_sorted_components());
+            assert!(PointND::<u8, 0>::from([]).is_sorted_components());
+
+            assert!(!PointND::from([0, 8, 7]).is_sorted_components());
+            assert!(!PointND::from([1.0, f64::NAN, 2.0]).is_sorted_components());
+        }
 
-    pub fn x(&self) -> &T { &self[0] }
-    pub fn y(&self) -> &T { &self[1] }
-    pub fn z(&self) -> &T { &self[2] }
+        #[test]
+        fn can_binary_search_exact_hits() {
+            let p = PointND::from([2, 4, 8, 16, 32]);
+            for (i, item) in p.iter().enumerate() {
+                assert_eq!(p.binary_search_component(item), Ok(i));
+            }
+        }
 
-    pub fn set_x(&mut self, new_value: T) { self[0] = new_value; }
-    pub fn set_y(&mut self, new_value: T) { self[1] = new_value; }
-    pub fn set_z(&mut self, new_value: T) { self[2] = new_value; }
+        #[test]
+        fn can_binary_search_insertion_positions() {
+            let p = PointND::from([2, 4, 8, 16, 32]);
+            assert_eq!(p.binary_search_component(&0), Err(0));
+            assert_eq!(p.binary_search_component(&5), Err(2));
+            assert_eq!(p.binary_search_component(&100), Err(5));
+
+            let p = PointND::<i32, 0>::from([]);
+            assert_eq!(p.binary_search_component(&1), Err(0));
+        }
 
-}
-///
-/// Methods for safely getting and setting the values contained by a 4D `PointND`
-///
-/// # Enabled by features:
-///
-/// - `default`
-///
-/// - `conv_methods`
-///
-/// - `w`
-///
-#[cfg(feature = "w")]
-impl<T> PointND<T, 4>  {
+    }
 
-    pub fn x(&self) -> &T { &self[0] }
-    pub fn y(&self) -> &T { &self[1] }
-    pub fn z(&self) -> &T { &self[2] }
-    pub fn w(&self) -> &T { &self[3] }
+    #[cfg(test)]
+    mod math {
+        use super::*;
 
-    pub fn set_x(&mut self, new_value: T) { self[0] = new_value; }
-    pub fn set_y(&mut self, new_value: T) { self[1] = new_value; }
-    pub fn set_z(&mut self, new_value: T) { self[2] = new_value; }
-    pub fn set_w(&mut self, new_value: T) { self[3] = new_value; }
+        #[test]
+        fn can_dot_3d_int_points() {
+            let p1 = PointND::from([1, 2, 3]);
+            let p2 = PointND::from([-1, 0, 10]);
+            assert_eq!(p1.dot(&p2), 29);
+        }
 
-}
+        #[test]
+        fn can_dot_4d_float_points() {
+            let p1 = PointND::from([0.5, 1.0, -2.0, 4.0]);
+            let p2 = PointND::from([2.0, 0.25, 1.5, 0.5]);
+            assert_eq!(p1.dot(&p2), 0.25);
+        }
 
-// Convenience Shifters
-///
-/// Method for safely transforming the value contained by a 1D `PointND`
-///
-/// # Enabled by features:
-///
-/// - `default`
-///
-/// - `conv_methods`
-///
-/// - `x`
-/// 
-#[cfg(feature = "x")]
-impl<T> PointND<T, 1>
-    where T: AddAssign {
+        #[test]
+        fn dot_of_orthogonal_points_is_zero() {
+            let p1 = PointND::from([1, 0, 0]);
+            let p2 = PointND::from([0, 1, 0]);
+            assert_eq!(p1.dot(&p2), 0);
+
+            let p1 = PointND::from([2.0, 1.0]);
+            let p2 = PointND::from([-1.0, 2.0]);
+            assert_eq!(p1.dot(&p2), 0.0);
+        }
 
-    pub fn shift_x(&mut self, delta: T) { self[0] += delta; }
+        #[test]
+        fn dot_of_zero_dimensional_points_is_zero() {
+            let p: PointND<i32, 0> = PointND::from([]);
+            assert_eq!(p.dot(&p), 0);
+            assert_eq!(p.magnitude_squared(), 0);
+        }
 
-}
-///
-/// Methods for safely transforming the values contained by a 2D `PointND`
-///
-/// # Enabled by features:
-///
-/// - `default`
-///
-/// - `conv_methods`
-///
-/// - `y`
-///
-#[cfg(feature = "y")]
-impl<T> PointND<T, 2>
-    where T: AddAssign {
+        #[test]
+        fn can_get_component_sum_and_product() {
+            let p: PointND<i32, 0> = PointND::from([]);
+            assert_eq!(p.component_sum(), 0);
+            assert_eq!(p.component_product(), 1);
+
+            let p = PointND::from([7u64]);
+            assert_eq!(p.component_sum(), 7);
+            assert_eq!(p.component_product(), 7);
+
+            let p = PointND::from([1.5, -2.0, 0.5, 4.0, 1.0, -1.0]);
+            assert_eq!(p.component_sum(), 4.0);
+            assert_eq!(p.component_product(), 6.0);
+        }
 
-    pub fn shift_x(&mut self, delta: T) { self[0] += delta; }
-    pub fn shift_y(&mut self, delta: T) { self[1] += delta; }
+        #[test]
+        fn can_get_manhattan_distance() {
+            let p1 = PointND::from([-2, 3, 0]);
+            let p2 = PointND::from([1, -1, 0]);
+            assert_eq!(p1.manhattan_distance(&p2), 7);
+            assert_eq!(p2.manhattan_distance(&p1), 7);
+        }
 
-}
-///
-/// Methods for safely transforming the values contained by a 3D `PointND`
-///
-/// # Enabled by features:
-///
-/// - `default`
-///
-/// - `conv_methods`
-///
-/// - `z`
-///
-#[cfg(feature = "z")]
-impl<T> PointND<T, 3>
-    where T: AddAssign {
+        #[test]
+        fn can_get_chebyshev_distance() {
+            let p1 = PointND::from([-2, 3, 0]);
+            let p2 = PointND::from([1, -2, 0]);
+            assert_eq!(p1.chebyshev_distance(&p2), 5);
+            assert_eq!(p1.chebyshev_distance(&p1), 0);
+        }
 
-    pub fn shift_x(&mut self, delta: T) { self[0] += delta; }
-    pub fn shift_y(&mut self, delta: T) { self[1] += delta; }
-    pub fn shift_z(&mut self, delta: T) { self[2] += delta; }
+        #[test]
+        fn distances_between_unsigned_points_dont_underflow() {
+            // Naively computing a - b would underflow on the first item
+            let p1 = PointND::from([0u8, 200, 10]);
+            let p2 = PointND::from([255u8, 100, 10]);
+            assert_eq!(p1.chebyshev_distance(&p2), 255);
+            assert_eq!(p2.chebyshev_distance(&p1), 255);
+
+            let p1 = PointND::from([0u32, u32::MAX]);
+            let p2 = PointND::from([3u32, u32::MAX - 4]);
+            assert_eq!(p1.manhattan_distance(&p2), 7);
+            assert_eq!(p2.manhattan_distance(&p1), 7);
+        }
 
-}
-///
-/// Methods for safely transforming the values contained by a 4D `PointND`
-///
-/// # Enabled by features:
-///
-/// - `default`
-///
-/// - `conv_methods`
-///
-/// - `w`
-///
-#[cfg(feature = "w")]
-impl<T> PointND<T, 4>
-    where T: AddAssign {
+        #[test]
+        fn can_get_abs_diff() {
+            // Naively computing a - b would underflow on the first and last items
+            let p1 = PointND::from([0u8, 255, 10, 3]);
+            let p2 = PointND::from([255u8, 0, 10, 4]);
+            assert_eq!(p1.abs_diff(&p2).into_arr(), [255, 255, 0, 1]);
+            assert_eq!(p2.abs_diff(&p1).into_arr(), [255, 255, 0, 1]);
+
+            let p1 = PointND::from([-5, 3, -2]);
+            let p2 = PointND::from([5, -3, -7]);
+            assert_eq!(p1.abs_diff(&p2).into_arr(), [10, 6, 5]);
+
+            let p1 = PointND::from([-1.5, 0.25]);
+            let p2 = PointND::from([1.0, -0.25]);
+            assert_eq!(p1.abs_diff(&p2).into_arr(), [2.5, 0.5]);
+        }
+
+        #[test]
+        fn can_compare_within_per_axis_tolerances() {
+            let p1 = PointND::from([100.0, 0.25, -3.0]);
+            let p2 = PointND::from([100.8, 0.30, -3.0]);
+
+            let tolerances = PointND::from([1.0, 0.1, 0.0]);
+            assert!(p1.eq_within_point(&p2, &tolerances));
+            assert_eq!(p1.diff_exceeds(&p2, &tolerances), None);
+
+            // Passes on the first axis but fails on the second
+            let tolerances = PointND::from([1.0, 0.01, 0.0]);
+            assert!(!p1.eq_within_point(&p2, &tolerances));
+            assert_eq!(p1.diff_exceeds(&p2, &tolerances), Some(1));
+        }
+
+        #[test]
+        fn zero_tolerances_require_exact_equality() {
+            let zero = PointND::fill(0u32);
+            let p = PointND::from([5u32, 6, 7]);
+
+            assert!(p.eq_within_point(&p.clone(), &zero));
+            assert_eq!(p.diff_exceeds(&PointND::from([5, 6, 8]), &zero), Some(2));
+            assert_eq!(p.diff_exceeds(&PointND::from([4, 6, 7]), &zero), Some(0));
+        }
+
+        #[test]
+        fn nan_differences_exceed_tolerances() {
+            let p1 = PointND::from([1.0, f64::NAN]);
+            let p2 = PointND::from([1.0, 1.0]);
+            assert_eq!(p1.diff_exceeds(&p2, &PointND::fill(f64::INFINITY)), Some(1));
+        }
+
+        #[test]
+        fn distances_are_consistent_with_abs_diff() {
+            let p1 = PointND::from([3u16, 900, 0, 41]);
+            let p2 = PointND::from([10u16, 2, 60_000, 41]);
+            let diff = p1.abs_diff(&p2);
+
+            assert_eq!(p1.manhattan_distance(&p2), diff.iter().sum::<u16>());
+            assert_eq!(p1.chebyshev_distance(&p2), *diff.max_component().unwrap());
+        }
+
+        #[test]
+        fn distances_between_zero_dimensional_points_are_zero() {
+            let p: PointND<u8, 0> = PointND::from([]);
+            assert_eq!(p.manhattan_distance(&p), 0);
+            assert_eq!(p.chebyshev_distance(&p), 0);
+        }
+
+        #[test]
+        fn can_get_magnitude_squared() {
+            let p = PointND::from([1, -2, 2]);
+            assert_eq!(p.magnitude_squared(), 9);
+
+            let p = PointND::from([0.5, 0.5, 0.5, 0.5]);
+            assert_eq!(p.magnitude_squared(), 1.0);
+        }
+
+    }
+
+    #[cfg(test)]
+    mod swizzles {
+        use super::*;
+        use std::string::String;
+
+        #[test]
+        #[cfg(feature = "y")]
+        fn can_swizzle_2d() {
+            let p = PointND::from([1, 2]);
+            assert_eq!(p.xy(), p);
+            assert_eq!(p.yx().into_arr(), [2, 1]);
+        }
+
+        #[test]
+        #[cfg(feature = "z")]
+        fn can_swizzle_3d() {
+            let p = PointND::from(['x', 'y', 'z']);
+            assert_eq!(p.xy().into_arr(), ['x', 'y']);
+            assert_eq!(p.zx().into_arr(), ['z', 'x']);
+            assert_eq!(p.yz().into_arr(), ['y', 'z']);
+            assert_eq!(p.xyz(), p);
+            assert_eq!(p.zyx().into_arr(), ['z', 'y', 'x']);
+        }
+
+        #[test]
+        #[cfg(feature = "w")]
+        fn can_swizzle_4d() {
+            let p = PointND::from(['x', 'y', 'z', 'w']);
+            assert_eq!(p.xw().into_arr(), ['x', 'w']);
+            assert_eq!(p.wz().into_arr(), ['w', 'z']);
+            assert_eq!(p.xyz().into_arr(), ['x', 'y', 'z']);
+            assert_eq!(p.xyzw(), p);
+            assert_eq!(p.wzyx().into_arr(), ['w', 'z', 'y', 'x']);
+        }
+
+        #[test]
+        fn can_swizzle_any_dims() {
+            let p = PointND::from([0, 1, 2, 3, 4, 5]);
+            assert_eq!(p.swizzle([5, 3, 1]).into_arr(), [5, 3, 1]);
+            assert_eq!(p.swizzle([2; 8]).into_arr(), [2; 8]);
+            assert_eq!(p.swizzle([]).dims(), 0);
+        }
+
+        #[test]
+        #[should_panic(expected = "select dimension 6 of a PointND with 6 dimensions")]
+        fn cannot_swizzle_out_of_bounds() {
+            let p = PointND::from([0, 1, 2, 3, 4, 5]);
+            let _ = p.swizzle([0, 6]);
+        }
+
+        #[test]
+        fn can_select_repeated_dims() {
+            let p = PointND::from([1.0, 2.0, 3.0]);
+            assert_eq!(p.select([1, 1, 0]).into_arr(), [2.0, 2.0, 1.0]);
+            assert_eq!(p.select([2, 0, 1, 2, 0, 1]).into_arr(), [3.0, 1.0, 2.0, 3.0, 1.0, 2.0]);
+        }
+
+        #[test]
+        #[should_panic(expected = "select dimension 3 of a PointND with 3 dimensions")]
+        fn cannot_select_out_of_bounds() {
+            let _ = PointND::from([0, 1, 2]).select([3]);
+        }
+
+        #[test]
+        fn can_permute() {
+            let p = PointND::from([String::from("a"), String::from("b"), String::from("c")]);
+            assert_eq!(p.permute([1, 2, 0]).into_arr(), ["b", "c", "a"]);
+            assert_eq!(PointND::from([0, 1]).permute([0, 1]).into_arr(), [0, 1]);
+        }
+
+        #[test]
+        #[should_panic(expected = "with dimension 2 repeated")]
+        fn cannot_permute_repeated_dims() {
+            let _ = PointND::from([0, 1, 2, 3]).permute([2, 1, 2, 0]);
+        }
+
+        #[test]
+        #[should_panic(expected = "permute dimension 4 of a PointND with 4 dimensions")]
+        fn cannot_permute_out_of_bounds() {
+            let _ = PointND::from([0, 1, 2, 3]).permute([0, 1, 2, 4]);
+        }
+
+    }
+
+    #[cfg(test)]
+    mod reordering {
+        use super::*;
+
+        #[test]
+        fn can_swap_dims() {
+            let mut p = PointND::from([0, 1, 2, 3]);
+            p.swap_dims(1, 3);
+            assert_eq!(p.into_arr(), [0, 3, 2, 1]);
+        }
+
+        #[test]
+        fn swapping_same_dim_changes_nothing() {
+            let mut p = PointND::from([0, 1, 2]);
+            p.swap_dims(1, 1);
+            assert_eq!(p.into_arr(), [0, 1, 2]);
+        }
+
+        #[test]
+        #[should_panic(expected = "swap dimensions 0 and 3 of a PointND with 3 dimensions")]
+        fn cannot_swap_out_of_bounds_dims() {
+            PointND::from([0, 1, 2]).swap_dims(0, 3);
+        }
+
+        #[test]
+        fn can_reverse() {
+            let mut p = PointND::from([0, 1, 2, 3, 4]);
+            p.reverse();
+            assert_eq!(p.into_arr(), [4, 3, 2, 1, 0]);
+            assert_eq!(p.reversed().into_arr(), [0, 1, 2, 3, 4]);
+            assert_eq!(PointND::<i32, 0>::from([]).reversed().dims(), 0);
+        }
+
+        #[test]
+        fn can_rotate_dims() {
+            let mut p = PointND::from([0, 1, 2, 3, 4]);
+            p.rotate_dims_left(2);
+            assert_eq!(p.into_arr(), [2, 3, 4, 0, 1]);
+            p.rotate_dims_right(2);
+            assert_eq!(p.into_arr(), [0, 1, 2, 3, 4]);
+        }
+
+        #[test]
+        fn can_sort() {
+            let p = PointND::from([3, -1, 2, -1, 0]);
+            assert_eq!(p.argsort(), [1, 3, 4, 2, 0]);
+            assert_eq!(p.sorted().into_arr(), [-1, -1, 0, 2, 3]);
+            assert_eq!(p.permute(p.argsort()), p.sorted());
+            assert_eq!(PointND::<u8, 0>::from([]).argsort(), [0usize; 0]);
+        }
+
+        #[test]
+        fn sorting_is_stable() {
+            // Compares by the first item only
+            #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+            struct Keyed(i32, char);
+            impl PartialOrd for Keyed {
+                fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> { Some(self.cmp(other)) }
+            }
+            impl Ord for Keyed {
+                fn cmp(&self, other: &Self) -> core::cmp::Ordering { self.0.cmp(&other.0) }
+            }
+
+            let p = PointND::from([Keyed(1, 'a'), Keyed(0, 'b'), Keyed(1, 'c'), Keyed(0, 'd')]);
+            assert_eq!(p.sorted().into_arr().map(|k| k.1), ['b', 'd', 'a', 'c']);
+        }
+
+        #[test]
+        fn clamp_rank_matches_template_order() {
+            let p = PointND::from([4, 8, 1, 6, 3]);
+            let template = PointND::from([5, -20, 90, 30, 10]);
+            let ranked = p.clamp_rank(&template);
+
+            // Is a permutation of self
+            assert_eq!(ranked.sorted(), p.sorted());
+            // Has the same ordering pattern as template
+            assert_eq!(ranked.argsort(), template.argsort());
+            assert_eq!(ranked.into_arr(), [3, 1, 8, 6, 4]);
+        }
+
+        #[test]
+        fn clamp_rank_breaks_ties_by_dimension() {
+            let p = PointND::from([30, 10, 20, 40]);
+            let template = PointND::from([7, 7, 7, 0]);
+            assert_eq!(p.clamp_rank(&template).into_arr(), [20, 30, 40, 10]);
+
+            // Ties in self are indistinguishable, so only template ties matter
+            let p = PointND::from([1, 1, 2]);
+            assert_eq!(p.clamp_rank(&PointND::from([2, 0, 1])).into_arr(), [2, 1, 1]);
+        }
+
+        #[test]
+        fn rotating_by_more_than_dims_wraps() {
+            let mut p = PointND::from([0, 1, 2]);
+            p.rotate_dims_left(7);
+            assert_eq!(p.into_arr(), [1, 2, 0]);
+            p.rotate_dims_right(3);
+            assert_eq!(p.into_arr(), [1, 2, 0]);
+
+            let mut p = PointND::<i32, 0>::from([]);
+            p.rotate_dims_left(5);
+            p.rotate_dims_right(5);
+        }
+
+    }
+
+    #[cfg(test)]
+    mod debug {
+        use super::*;
+        use std::format;
+
+        #[test]
+        fn can_debug_2d_points() {
+            let p = PointND::from([1.0, 2.5]);
+            assert_eq!(format!("{:?}", p), "PointND([1.0, 2.5])");
+            assert_eq!(format!("{:#?}", p), "PointND {\n    x: 1.0,\n    y: 2.5,\n}");
+        }
+
+        #[test]
+        fn can_debug_4d_points() {
+            let p = PointND::from([0, -1, 2, -3]);
+            assert_eq!(format!("{:?}", p), "PointND([0, -1, 2, -3])");
+            assert_eq!(
+                format!("{:#?}", p),
+                "PointND {\n    x: 0,\n    y: -1,\n    z: 2,\n    w: -3,\n}"
+            );
+        }
+
+        #[test]
+        fn can_debug_6d_points() {
+            let p = PointND::from([0, 1, 2, 3, 4, 5]);
+            assert_eq!(format!("{:?}", p), "PointND([0, 1, 2, 3, 4, 5])");
+            assert_eq!(
+                format!("{:#?}", p),
+                "PointND {\n    dim0: 0,\n    dim1: 1,\n    dim2: 2,\n    \
+                 dim3: 3,\n    dim4: 4,\n    dim5: 5,\n}"
+            );
+        }
+
+        #[test]
+        fn can_debug_zero_dimensional_points() {
+            let p: PointND<i32, 0> = PointND::from([]);
+            assert_eq!(format!("{:?}", p), "PointND([])");
+            assert_eq!(format!("{:#?}", p), "PointND {}");
+        }
+
+    }
+
+    #[cfg(test)]
+    mod ordering {
+        use super::*;
+        use core::cmp::Ordering;
+        use std::collections::BTreeSet;
+        use std::vec::Vec;
+
+        #[test]
+        fn points_are_ordered_lexicographically() {
+            assert!(PointND::from([1, 9]) < PointND::from([2, 0]));
+            assert!(PointND::from([2, 0]) > PointND::from([1, 9]));
+            assert_eq!(PointND::from([1, 9]).cmp(&PointND::from([2, 0])), Ordering::Less);
+        }
+
+        #[test]
+        fn equal_prefixes_compare_later_dimensions() {
+            assert!(PointND::from([4, 4, 1]) < PointND::from([4, 4, 2]));
+            assert_eq!(PointND::from([4, 4, 4]).cmp(&PointND::from([4, 4, 4])), Ordering::Equal);
+            assert_eq!(PointND::<u8, 0>::from([]).cmp(&PointND::from([])), Ordering::Equal);
+        }
+
+        #[test]
+        fn ordering_matches_arrays() {
+            let arrs = [[0, 5], [3, -1], [0, -5], [3, 2], [-7, 100]];
+            for a in arrs {
+                for b in arrs {
+                    assert_eq!(PointND::from(a).cmp(&PointND::from(b)), a.cmp(&b));
+                }
+            }
+
+            let a = [1.0, f64::NAN];
+            let b = [1.0, 0.0];
+            assert_eq!(PointND::from(a).partial_cmp(&PointND::from(b)), None);
+            assert_eq!(PointND::from([0.0, f64::NAN]).partial_cmp(&PointND::from(b)), Some(Ordering::Less));
+        }
+
+        #[test]
+        fn can_use_points_in_ordered_sets() {
+            let set: BTreeSet<_> = [[2, 0], [1, 9], [1, -3], [2, 0]].map(PointND::from).into_iter().collect();
+            let sorted: Vec<_> = set.into_iter().map(PointND::into_arr).collect();
+            assert_eq!(sorted, [[1, -3], [1, 9], [2, 0]]);
+        }
+
+    }
+
+    #[cfg(test)]
+    mod hash_and_eq {
+        use super::*;
+        use std::collections::HashMap;
+        use std::collections::hash_map::DefaultHasher;
+        use std::vec::Vec;
+
+        // Records every call made to the hasher
+        #[derive(Default)]
+        struct RecordingHasher {
+            writes: Vec<Vec<u8>>,
+        }
+
+        impl Hasher for RecordingHasher {
+            fn finish(&self) -> u64 {
+                0
+            }
+            fn write(&mut self, bytes: &[u8]) {
+                self.writes.push(Vec::from(bytes));
+            }
+        }
+
+        fn hash_of<H: Hash>(item: &H) -> u64 {
+            let mut state = DefaultHasher::new();
+            item.hash(&mut state);
+            state.finish()
+        }
 
-    pub fn shift_x(&mut self, delta: T) { self[0] += delta; }
-    pub fn shift_y(&mut self, delta: T) { self[1] += delta; }
-    pub fn shift_z(&mut self, delta: T) { self[2] += delta; }
-    pub fn shift_w(&mut self, delta: T) { self[3] += delta; }
+        fn bytes(seed: u8) -> [u8; 4096] {
+            let mut arr = [0; 4096];
+            for (i, item) in arr.iter_mut().enumerate() {
+                *item = (i as u8).wrapping_mul(31).wrapping_add(seed);
+            }
+            arr
+        }
 
-}
+        #[test]
+        fn byte_points_are_hashed_in_one_write() {
+            let arr = bytes(7);
+            let mut state = RecordingHasher::default();
+            PointND::from(arr).hash(&mut state);
+            assert_eq!(state.writes.len(), 2);
+            assert_eq!(state.writes[0], 4096usize.to_ne_bytes());
+            assert_eq!(state.writes[1], arr);
+
+            let mut state = RecordingHasher::default();
+            PointND::from(arr.map(|item| item as i8)).hash(&mut state);
+            assert_eq!(state.writes.len(), 2);
+            assert_eq!(state.writes[1], arr);
+        }
 
+        #[test]
+        fn hashes_match_naive_hashes() {
+            let arr = bytes(3);
+            let mut naive = DefaultHasher::new();
+            naive.write_usize(arr.len());
+            for item in arr.iter() {
+                naive.write_u8(*item);
+            }
+            assert_eq!(hash_of(&PointND::from(arr)), naive.finish());
 
-impl<T, const N: usize> From<[T; N]> for PointND<T, N> {
+            let arr = [1.5f32.to_bits(), 2, u32::MAX];
+            let mut naive = DefaultHasher::new();
+            naive.write_usize(arr.len());
+            for item in arr.iter() {
+                item.hash(&mut naive);
+            }
+            assert_eq!(hash_of(&PointND::from(arr)), naive.finish());
+        }
 
-    fn from(array: [T; N]) -> Self {
-        PointND(array)
-    }
+        #[test]
+        fn equal_points_hash_equally() {
+            let p1 = PointND::from(bytes(1));
+            let p2 = PointND::from(bytes(1));
+            let p3 = PointND::from(bytes(2));
+
+            assert_eq!(p1, p2);
+            assert_eq!(hash_of(&p1), hash_of(&p2));
+            assert_ne!(p1, p3);
+            assert_ne!(hash_of(&p1), hash_of(&p3));
+        }
 
-}
+        #[test]
+        fn hashes_match_array_hashes() {
+            assert_eq!(hash_of(&PointND::from([1, -2, 3])), hash_of(&[1, -2, 3]));
+            assert_eq!(hash_of(&PointND::from(bytes(9))), hash_of(&bytes(9)));
+            assert_eq!(hash_of(&PointND::<i32, 0>::from([])), hash_of(&[0i32; 0]));
+        }
 
-impl<T, const N: usize> From<PointND<T, N>> for [T; N] {
+        #[test]
+        fn can_use_points_as_map_keys() {
+            let mut map = HashMap::new();
+            map.insert(PointND::from([0, 0, 0]), "origin");
+            map.insert(PointND::from([1, -2, 3]), "a");
+            map.insert(PointND::from([-1, 2, -3]), "b");
+            map.insert(PointND::from([1, -2, 3]), "c");
+
+            assert_eq!(map.len(), 3);
+            assert_eq!(map.get(&PointND::from([0, 0, 0])), Some(&"origin"));
+            assert_eq!(map.get(&PointND::from([1, -2, 3])), Some(&"c"));
+            assert_eq!(map.get(&PointND::from([-1, 2, -3])), Some(&"b"));
+            assert_eq!(map.get(&PointND::from([3, -2, 1])), None);
+
+            let mut map = HashMap::new();
+            map.insert(PointND::<i32, 0>::from([]), 1);
+            map.insert(PointND::from([]), 2);
+            assert_eq!(map.len(), 1);
+            assert_eq!(map.get(&PointND::from([])), Some(&2));
+        }
+
+        #[test]
+        fn equality_matches_naive_equality() {
+            let naive_eq = |a: &[u8; 4096], b: &[u8; 4096]| a.iter().zip(b.iter()).all(|(a, b)| a == b);
+
+            let base = bytes(5);
+            for i in [0, 1, 2047, 4095] {
+                let mut changed = base;
+                changed[i] ^= 1;
+
+                let (p1, p2) = (PointND::from(base), PointND::from(changed));
+                assert_eq!(p1 == p2, naive_eq(&base, &changed));
+                assert_eq!(p1 == p1, naive_eq(&base, &base));
+            }
+        }
 
-    fn from(point: PointND<T, N>) -> Self {
-        point.into_arr()
     }
 
-}
+    #[cfg(test)]
+    #[cfg(feature = "conv_methods")]
+    mod widen_and_narrow {
+        use super::*;
+        use std::string::{String, ToString};
 
-impl<T, const N: usize> TryFrom<&[T]> for PointND<T, N>
-    where T: Copy {
+        #[test]
+        fn can_widen_each_size() {
+            assert_eq!(PointND::from([0]).widened(1).into_arr(), [0, 1]);
+            assert_eq!(PointND::from([0, 1]).widened(2).into_arr(), [0, 1, 2]);
+            assert_eq!(PointND::from([0, 1, 2]).widened(3).into_arr(), [0, 1, 2, 3]);
+        }
 
-    type Error = TryFromSliceError;
-    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        #[test]
+        fn can_narrow_each_size() {
+            assert_eq!(PointND::from([0, 1]).narrowed(), (PointND::from([0]), 1));
+            assert_eq!(PointND::from([0, 1, 2]).narrowed(), (PointND::from([0, 1]), 2));
+            assert_eq!(PointND::from([0, 1, 2, 3]).narrowed(), (PointND::from([0, 1, 2]), 3));
+        }
 
-        let res: Result<[T; N], _> = slice.try_into();
-        match res {
-            Ok(arr) => Ok( PointND(arr) ),
-            Err(err) => Err( err )
+        #[test]
+        fn can_widen_and_narrow_non_copy_items() {
+            let p = PointND::from(["x", "y"].map(|s| s.to_string()));
+            let p = p.widened("z".to_string()).widened(String::from("w"));
+
+            let (p, w) = p.narrowed();
+            assert_eq!(w, "w");
+            let (p, z) = p.narrowed();
+            assert_eq!(z, "z");
+            assert_eq!(p.into_arr(), ["x", "y"]);
         }
-    }
 
-}
+        #[test]
+        #[cfg(feature = "var-dims")]
+        fn matches_extend_and_contract() {
+            let p = PointND::from([1.5, -2.0]);
+            assert_eq!(p.widened(1.0), p.extend::<1, 3>([1.0]));
 
+            let p = PointND::from([1, 2, 3, 4]);
+            assert_eq!(p.narrowed().0, p.contract_by::<3>(1));
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+            let p = PointND::from([7u8, 8, 9]);
+            assert_eq!(p.narrowed().0, p.contract_by::<2>(1));
+            assert_eq!(p.widened(10), p.extend::<1, 4>([10]));
+        }
+
+    }
 
     #[cfg(test)]
-    mod iterating {
+    #[cfg(any(feature = "y", feature = "z"))]
+    mod cross {
         use super::*;
 
         #[test]
-        fn can_iter() {
-
-            let arr = [0, 1, 2, 3];
-
-            let p = PointND::<u8, 4>::from_slice(&arr);
-            for (i, item) in p.iter().enumerate() {
-                assert_eq!(arr[i], *item);
-            }
+        #[cfg(feature = "z")]
+        fn cross_of_int_basis_vectors_work() {
+            let x = PointND::from([1, 0, 0]);
+            let y = PointND::from([0, 1, 0]);
+            let z = PointND::from([0, 0, 1]);
+
+            assert_eq!(x.cross(y), z);
+            assert_eq!(y.cross(z), x);
+            assert_eq!(z.cross(x), y);
+        }
 
-            let mut p = PointND::<u8, 4>::from_slice(&arr);
-            for item in p.iter_mut() {
-                *item = 10;
-            }
+        #[test]
+        #[cfg(feature = "z")]
+        fn cross_of_float_basis_vectors_work() {
+            let x = PointND::from([1.0, 0.0, 0.0]);
+            let y = PointND::from([0.0, 1.0, 0.0]);
+            let z = PointND::from([0.0, 0.0, 1.0]);
+
+            assert_eq!(x.cross(y), z);
+            assert_eq!(y.cross(z).into_arr(), [1.0, 0.0, 0.0]);
+        }
 
-            for i in p.into_iter() {
-                assert_eq!(i, 10u8);
-            }
+        #[test]
+        #[cfg(feature = "z")]
+        fn cross_is_anti_commutative() {
+            let a = PointND::from([2, -3, 7]);
+            let b = PointND::from([-1, 4, 5]);
+
+            let ab = a.cross(b);
+            let ba = b.cross(a);
+            assert_eq!(ab.into_arr(), [-43, -17, 5]);
+            assert_eq!(ba.into_arr(), [43, 17, -5]);
+        }
 
+        #[test]
+        #[cfg(feature = "y")]
+        fn can_cross_2d_points() {
+            let a = PointND::from([2.0, 1.0]);
+            let b = PointND::from([1.0, 3.0]);
+            assert_eq!(a.cross_2d(&b), 5.0);
+            assert_eq!(b.cross_2d(&a), -5.0);
+
+            // Collinear points
+            let c = PointND::from([4.0, 2.0]);
+            assert_eq!(a.cross_2d(&c), 0.0);
         }
 
     }
 
     #[cfg(test)]
-    mod constructors {
+    #[cfg(feature = "z")]
+    mod axis_conventions {
         use super::*;
 
-        // The from() constructor is under tests::from_and_into
+        #[test]
+        fn landmarks_map_to_z_up() {
+            // Right, up and towards the viewer
+            assert_eq!(PointND::from([1, 0, 0]).to_z_up().into_arr(), [1, 0, 0]);
+            assert_eq!(PointND::from([0, 1, 0]).to_z_up().into_arr(), [0, 0, 1]);
+            assert_eq!(PointND::from([0, 0, 1]).to_z_up().into_arr(), [0, -1, 0]);
+        }
 
         #[test]
-        fn from_slice_works() {
-            let arr = [0.0, 0.1, 0.2];
-            let p = PointND::<f64, 3>::from_slice(&arr);
-            for i in 0..p.dims() {
-                assert_eq!(arr[i], p[i]);
-            }
+        fn landmarks_map_to_y_up() {
+            // Right, away from the viewer and up
+            assert_eq!(PointND::from([1, 0, 0]).to_y_up().into_arr(), [1, 0, 0]);
+            assert_eq!(PointND::from([0, 1, 0]).to_y_up().into_arr(), [0, 0, -1]);
+            assert_eq!(PointND::from([0, 0, 1]).to_y_up().into_arr(), [0, 1, 0]);
         }
 
         #[test]
-        fn fill_works() {
-            let fill_val = 21u8;
-            let p = PointND::<u8, 5>::fill(fill_val);
-            for i in p.into_iter() {
-                assert_eq!(i, fill_val);
-            }
+        fn conversions_round_trip() {
+            let p = PointND::from([1.5, -2.0, 3.25]);
+            assert_eq!(p.to_z_up().to_y_up(), p);
+            assert_eq!(p.to_y_up().to_z_up(), p);
+            assert_eq!(p.flip_handedness().flip_handedness(), p);
+        }
+
+        #[test]
+        fn conversions_preserve_handedness() {
+            let x = PointND::from([1, 0, 0]);
+            let y = PointND::from([0, 1, 0]);
+            let z = x.to_z_up().cross(y.to_z_up());
+            assert_eq!(z, x.cross(y).to_z_up());
+
+            // The cross product of the flipped X and Y axes no longer gives the flipped Z axis
+            let z = x.flip_handedness().cross(y.flip_handedness());
+            assert_eq!(z.into_arr(), [0, 0, 1]);
+            assert_eq!(x.cross(y).flip_handedness().into_arr(), [0, 0, -1]);
         }
 
     }
 
     #[cfg(test)]
-    mod indexing {
+    mod gather_and_scatter {
         use super::*;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::string::String;
 
         #[test]
-        fn can_get_slice_by_range_index() {
-            let p = PointND::from([0,1,2,3,4]);
-            let slice = &p[0..3];
-            assert_eq!(slice, [0,1,2]);
+        fn can_gather() {
+            let p = PointND::from([String::from("a"), String::from("b"), String::from("c")]);
+
+            let gathered = p.gather(&PointND::from([2, 0, 2, 1]));
+            assert_eq!(gathered.into_arr(), ["c", "a", "c", "b"]);
+
+            let identity = p.gather(&PointND::from([0, 1, 2]));
+            assert_eq!(identity, p);
+
+            assert_eq!(p.gather(&PointND::from([])).dims(), 0);
         }
 
         #[test]
-        #[should_panic]
-        fn cannot_get_out_of_bounds_index() {
-            let p = PointND::from([0,1,2]);
-            let _x = p[p.dims() + 1];
+        fn can_scatter() {
+            let mut p = PointND::from([0, 0, 0]);
+            p.scatter(&PointND::from([0, 1, 2]), PointND::from([4, 5, 6]));
+            assert_eq!(p.into_arr(), [4, 5, 6]);
+
+            // Later values win
+            p.scatter(&PointND::from([1, 1, 1]), PointND::from([7, 8, 9]));
+            assert_eq!(p.into_arr(), [4, 9, 6]);
         }
 
         #[test]
-        fn can_set_value_by_index() {
+        fn scatter_reverses_gather() {
+            let p = PointND::from([1.5, -2.0, 3.0, 8.0]);
+            let indices = PointND::from([3, 0, 2, 1]);
 
-            let mut p = PointND::from([0,1,2]);
+            let mut scattered = PointND::fill(0.0);
+            scattered.scatter(&indices, p.gather(&indices));
+            assert_eq!(scattered, p);
+        }
 
-            let new_val = 9999;
-            p[1] = new_val;
+        #[test]
+        #[should_panic]
+        fn cannot_gather_out_of_bounds() {
+            PointND::from([1, 2]).gather(&PointND::from([0, 2]));
+        }
 
-            assert_eq!(p.into_arr(), [0, new_val, 2]);
+        #[test]
+        fn cannot_scatter_out_of_bounds() {
+            let mut p = PointND::from([1, 2, 3]);
+            let res = catch_unwind(AssertUnwindSafe(|| {
+                p.scatter(&PointND::from([0, 3]), PointND::from([10, 20]));
+            }));
+
+            assert!(res.is_err());
+            assert_eq!(p.into_arr(), [1, 2, 3]);
         }
 
     }
 
     #[cfg(test)]
-    #[cfg(feature = "appliers")]
-    mod appliers {
+    mod views {
         use super::*;
 
         #[test]
-        fn can_apply() {
+        #[cfg(feature = "z")]
+        fn can_view_subsets_of_dims() {
+            let p = PointND::from([0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+            let view = p.view::<3>(2);
+
+            assert_eq!((*view.x(), *view.y(), *view.z()), (2.0, 3.0, 4.0));
+            assert_eq!(view.iter().sum::<f64>(), 9.0);
+            assert_eq!(view.as_ptr(), p[2..].as_ptr());
+            assert_eq!(view.dot(&PointND::from([1.0, 1.0, 1.0])), 9.0);
+            #[cfg(feature = "float-math")]
+            assert_eq!(view.magnitude(), 29.0f64.sqrt());
+        }
 
-            let arr = [0,1,2,3];
+        #[test]
+        fn can_view_edges_of_points() {
+            let p = PointND::from([1, 2, 3]);
+            assert_eq!(p.view::<3>(0), &p);
+            assert_eq!(p.view::<1>(2).into_arr(), [3]);
+            assert_eq!(p.view::<0>(3).dims(), 0);
+        }
 
-            let p = PointND::<u8, 4>
-                ::from(arr)
-                .apply(|a| a * 2);
+        #[test]
+        #[should_panic]
+        fn cannot_view_past_end() {
+            PointND::from([1, 2, 3]).view::<2>(2);
+        }
 
-            assert_eq!(p.into_arr(), [0, 2, 4, 6]);
+        #[test]
+        #[should_panic]
+        fn cannot_view_from_past_end() {
+            PointND::from([1, 2, 3]).view::<0>(4);
+        }
+
+    }
+
+    #[cfg(test)]
+    mod default_and_copy {
+        use super::*;
+        use std::string::String;
+
+        fn sum(p: PointND<f32, 3>) -> f32 {
+            p.iter().sum()
         }
 
         #[test]
-        fn can_apply_dims() {
+        fn can_use_copy_points_after_moving() {
+            let p = PointND::from([1.0f32, 2.0, 3.0]);
+            assert_eq!(sum(p), 6.0);
+            assert_eq!(sum(p), 6.0);
 
-            let p = PointND::from([-2,-1,0,1,2])
-                .apply_dims(&[0, 3], |item| item - 10);
-            assert_eq!(p.into_arr(), [-12,-1, 0, -9, 2]);
+            let arr = p.into_arr();
+            assert_eq!(arr, *p);
         }
 
         #[test]
-        fn can_apply_vals() {
+        fn structs_of_copy_points_can_be_copy() {
+            #[derive(Clone, Copy)]
+            struct Segment {
+                start: PointND<i32, 2>,
+                end: PointND<i32, 2>,
+            }
 
-            let p = PointND::from([0,1,2])
-                .apply_vals([Some(10), None, Some(20)],
-                            |a, b| {
-                        if let Some(i) = b {
-                            a + i
-                        } else {
-                            a
-                        }
-                    });
-            assert_eq!(p.into_arr(), [10, 1, 22]);
+            let a = Segment { start: PointND::from([0, 0]), end: PointND::from([1, 1]) };
+            let b = a;
+            assert_eq!(a.start, b.start);
+            assert_eq!(a.end.into_arr(), [1, 1]);
         }
 
         #[test]
-        fn can_apply_point() {
+        fn default_items_are_default() {
+            assert_eq!(PointND::<i32, 3>::default().into_arr(), [0; 3]);
+            assert_eq!(PointND::<f64, 40>::default().into_arr(), [0.0; 40]);
+            assert_eq!(PointND::<String, 2>::default().into_arr(), [String::new(), String::new()]);
+            assert_eq!(PointND::<u8, 0>::default().dims(), 0);
+        }
 
-            let p1 = PointND::from([0, 1, 2, 3]);
-            let p2 = PointND::from([0, -1, -2, -3]);
-            let p3 = p1.apply_point(p2, |a, b| a - b );
-            assert_eq!(p3.into_arr(), [0, 2, 4, 6]);
+    }
+
+    #[cfg(test)]
+    mod from_and_into {
+        use super::*;
+        use std::string::String;
+
+        #[test]
+        fn from_array_works() {
+            let p = PointND::from([0,1,2]);
+            assert_eq!(p.dims(), 3);
+
+            let p: PointND<i32, 4> = [22; 4].into();
+            assert_eq!(p.into_arr(), [22; 4]);
         }
 
         #[test]
-        fn can_apply_noclone_items() {
+        fn into_array_works() {
+            let arr: [i32; 3] = PointND::fill(10).into();
+            assert_eq!(arr, [10, 10, 10]);
+        }
 
-            #[derive(Debug, Eq, PartialEq)]
-            enum X { A, B, C }
+        #[test]
+        fn from_array_of_refs_works() {
+            let values = [String::from("x"), String::from("y"), String::from("z")];
+            let view: PointND<&String, 2> = [&values[2], &values[0]].into();
+            assert_eq!(view.into_arr(), [&values[2], &values[0]]);
+        }
 
-            let p = PointND
-                ::from([X::A, X::B, X::C])
-                .apply(|x| {
-                    match x {
-                        X::A => X::B,
-                        X::B => X::C,
-                        X::C => X::A,
-                    }
-                });
+        #[test]
+        fn can_clone_from_refs() {
+            let values = [String::from("x"), String::from("y"), String::from("z")];
+            let p = PointND::from([&values[1], &values[1], &values[0]]).cloned();
+            assert_eq!(p.into_arr(), ["y", "y", "x"]);
+        }
 
-            assert_eq!(p.into_arr(), [X::B, X::C, X::A]);
+        #[test]
+        #[cfg(feature = "x")]
+        fn tuple_1d_round_trip_works() {
+            let p: PointND<_, 1> = (7,).into();
+            assert_eq!(p.into_arr(), [7]);
+            assert_eq!(<(i32,)>::from(PointND::from((7,))), (7,));
         }
 
-    }
+        #[test]
+        #[cfg(feature = "y")]
+        fn tuple_2d_round_trip_works() {
+            let p: PointND<_, 2> = (1.5, -2.0).into();
+            assert_eq!(p.into_arr(), [1.5, -2.0]);
+            assert_eq!(<(f64, f64)>::from(PointND::from((1.5, -2.0))), (1.5, -2.0));
+        }
 
-    #[cfg(test)]
-    #[cfg(feature = "var-dims")]
-    mod extenders {
-        use super::*;
+        #[test]
+        #[cfg(feature = "z")]
+        fn tuple_3d_round_trip_works() {
+            let p: PointND<_, 3> = ('a', 'b', 'c').into();
+            assert_eq!(p.into_arr(), ['a', 'b', 'c']);
+            assert_eq!(<(char, char, char)>::from(PointND::from(('a', 'b', 'c'))), ('a', 'b', 'c'));
+        }
 
         #[test]
-        fn can_extend() {
+        #[cfg(feature = "w")]
+        fn tuple_4d_round_trip_works() {
+            let p: PointND<_, 4> = (0u8, 1, 2, 3).into();
+            assert_eq!(p.into_arr(), [0, 1, 2, 3]);
+            assert_eq!(<(u8, u8, u8, u8)>::from(PointND::from((0, 1, 2, 3))), (0, 1, 2, 3));
+        }
 
-            let zero = PointND::<i32, 0>::from([]);
-            assert_eq!(zero.dims(), 0);
+    }
 
-            let two = zero.clone().extend([0,1]);
-            assert_eq!(two.dims(), 2);
-            assert_eq!(two.into_arr(), [0, 1]);
+    #[cfg(test)]
+    mod early_exit {
+        use super::*;
 
-            let five = PointND
-                ::from([0,1,2])
-                .extend([3,4]);
-            assert_eq!(five.dims(), 5);
-            assert_eq!(five.clone().into_arr(), [0,1,2,3,4]);
+        #[test]
+        fn try_for_each_stops_at_first_error() {
+            let p = PointND::from([1, 2, -3, 4, -5]);
+
+            let mut visited = 0;
+            let res = p.try_for_each(|i, item| {
+                visited += 1;
+                if *item < 0 { Err((i, *item)) } else { Ok(()) }
+            });
+            assert_eq!(res, Err((2, -3)));
+            assert_eq!(visited, 3);
+        }
 
-            let sum = five.apply_point(PointND::from([0,1,2,3,4]), |a, b| a + b);
-            assert_eq!(sum.into_arr(), [0,2,4,6,8]);
+        #[test]
+        fn try_for_each_visits_every_item_in_order() {
+            let p = PointND::from([10, 20, 30]);
+
+            let mut indices = [usize::MAX; 3];
+            let res: Result<(), ()> = p.try_for_each(|i, item| {
+                indices[i] = *item as usize / 10 - 1;
+                Ok(())
+            });
+            assert_eq!(res, Ok(()));
+            assert_eq!(indices, [0, 1, 2]);
+        }
 
-            let huge = PointND
-                ::from([0; 100])
-                .extend([1; 1_000]) as PointND<i32, 1_100>;
-            assert_eq!(huge.dims(), 1_100);
+        #[test]
+        fn position_stops_at_first_match() {
+            let p = PointND::from([3, 8, 9, 10]);
+
+            let mut visited = 0;
+            let index = p.position(|item| {
+                visited += 1;
+                item % 2 == 0
+            });
+            assert_eq!(index, Some(1));
+            assert_eq!(visited, 2);
         }
 
         #[test]
-        fn can_extend_nothing() {
-            let arr: [i32; 0] = [];
-            let zero = PointND
-                ::from(arr)
-                .extend::<0, 0>(arr);
-            assert_eq!(zero.dims(), 0);
+        fn position_finds_nothing() {
+            let p = PointND::from([1, 3, 5]);
+            assert_eq!(p.position(|item| *item > 5), None);
+
+            let p: PointND<i32, 0> = PointND::from([]);
+            assert_eq!(p.position(|_| true), None);
+            assert_eq!(p.try_for_each(|_, _| Err(())), Ok(()));
         }
 
     }
 
     #[cfg(test)]
-    #[cfg(feature = "var-dims")]
-    mod retain {
+    mod in_place_math {
         use super::*;
 
         #[test]
-        fn can_retain_n() {
-            let p = PointND
-                ::from([0,1,2,3])
-                .retain(3);
+        #[cfg(feature = "appliers")]
+        fn in_place_integration_matches_apply_point() {
+            let dt = PointND::<f64, 16>::fill(0.125);
+            let accel = PointND::<f64, 16>::from_fn(|i| i as f64 - 7.5);
+            let drag = PointND::<f64, 16>::fill(0.99);
+
+            let mut pos = PointND::<f64, 16>::from_fn(|i| i as f64 * 0.5);
+            let mut vel = PointND::<f64, 16>::zero();
+            let (mut pos_applied, mut vel_applied) = (pos, vel);
+
+            for _ in 0..200 {
+                let mut step = accel;
+                step.mul_assign_point(&dt);
+                vel.add_assign_point(&step);
+                vel.mul_assign_point(&drag);
+                let mut moved = vel;
+                moved.mul_assign_point(&dt);
+                pos.add_assign_point(&moved);
+
+                let step = accel.apply_point(dt, |a, b| a * b);
+                vel_applied = vel_applied.apply_point(step, |a, b| a + b).apply_point(drag, |a, b| a * b);
+                let moved = vel_applied.apply_point(dt, |a, b| a * b);
+                pos_applied = pos_applied.apply_point(moved, |a, b| a + b);
+            }
 
-            assert_eq!(p.dims(), 3);
-            assert_eq!(p.into_arr(), [0,1,2]);
+            assert_eq!(pos, pos_applied);
+            assert_eq!(vel, vel_applied);
         }
 
         #[test]
-        fn can_retain_zero() {
-            let p = PointND
-                ::from([0,1,2,3])
-                .retain(0);
+        fn can_shift_points_of_any_dims() {
+            let mut p = PointND::<i32, 7>::from_fn(|i| i as i32);
+            p.shift_all(-3);
+            assert_eq!(p.into_arr(), [-3, -2, -1, 0, 1, 2, 3]);
 
-            assert_eq!(p.dims(), 0);
-            assert_eq!(p.into_arr(), []);
+            p.shift_by(&[3, 2, 1, 0, -1, -2, -3]);
+            assert_eq!(p.into_arr(), [0; 7]);
+
+            let mut p = PointND::<u8, 0>::from([]);
+            p.shift_all(1);
+            p.shift_by(&[]);
+        }
+
+        #[test]
+        fn can_sub_and_div_in_place() {
+            let mut p = PointND::from([10, 20, 30]);
+            p.sub_assign_point(&PointND::from([1, 2, 3]));
+            assert_eq!(p.into_arr(), [9, 18, 27]);
+            p.div_assign_point(&PointND::from([3, 6, 9]));
+            assert_eq!(p.into_arr(), [3, 3, 3]);
         }
 
         #[test]
-        fn can_retain_same() {
-            let p = PointND
-                ::from([0,1,2,3])
-                .retain(4);
+        fn can_accumulate_clone_items() {
+            // Clone but not Copy
+            #[derive(Clone, Debug, PartialEq)]
+            struct Meters(i32);
+            impl AddAssign for Meters {
+                fn add_assign(&mut self, other: Self) { self.0 += other.0; }
+            }
 
-            assert_eq!(p.dims(), 4);
-            assert_eq!(p.into_arr(), [0,1,2,3]);
+            let mut p = PointND::from([Meters(1), Meters(2)]);
+            let step = PointND::from([Meters(10), Meters(-10)]);
+            p.add_assign_point(&step);
+            p.add_assign_point(&step);
+            assert_eq!(p.into_arr(), [Meters(21), Meters(-18)]);
         }
 
         #[test]
         #[should_panic]
-        #[allow(unused_variables)]
-        fn cannot_retain_more_dimensions() {
-            let p = PointND
-                ::from([0,1,2,3])
-                .retain::<1000>(1000);
+        fn cannot_div_in_place_by_zero() {
+            let mut p = PointND::from([1, 2]);
+            p.div_assign_point(&PointND::from([1, 0]));
         }
 
     }
 
     #[cfg(test)]
-    #[cfg(any(feature = "x", feature = "y", feature = "z", feature = "w"))]
-    mod conv_methods {
+    mod unit_conversion {
         use super::*;
 
-        #[cfg(test)]
-        #[cfg(any(feature = "x", feature = "y", feature = "z", feature = "w"))]
-        mod get {
-            use super::*;
-
-            #[test]
-            #[cfg(feature = "x")]
-            fn getter_for_1d_points_work() {
-                let arr = [0];
-                let p = PointND::from(arr);
-                assert_eq!(*p.x(), arr[0]);
+        #[test]
+        fn can_round_trip_unit_conversions() {
+            // Meters to feet, meters to millimeters, radians to degrees with a heading offset
+            let scale = PointND::from([3.28084, 1000.0, 57.29577951308232]);
+            let offset = PointND::from([0.0, -25.0, 90.0]);
+
+            for arr in [[1.0, 2.0, 0.5], [-300.25, 0.0, -3.0], [1e-6, 1e6, 7.5]] {
+                let p = PointND::<f64, 3>::from(arr);
+                let converted = p.convert_units(&scale, &offset);
+                let back = converted.convert_units_inv(&scale, &offset);
+                assert!(back.eq_within_point(&p, &PointND::fill(1e-9)), "{:?} != {:?}", back, p);
             }
+        }
 
-            #[test]
-            #[cfg(feature = "y")]
-            fn getters_for_2d_points_work() {
-                let arr = [0,1];
-                let p = PointND::from(arr);
+        #[test]
+        fn conversions_scale_before_offsetting() {
+            let p = PointND::from([1, 2]);
+            let converted = p.convert_units(&PointND::from([10, -1]), &PointND::from([3, 3]));
+            assert_eq!(converted.into_arr(), [13, 1]);
+            assert_eq!(converted.convert_units_inv(&PointND::from([10, -1]), &PointND::from([3, 3])), p);
+        }
 
-                assert_eq!(*p.x(), arr[0]);
-                assert_eq!(*p.y(), arr[1]);
-            }
+        #[test]
+        fn cannot_invert_zero_scales() {
+            let p = PointND::from([1.0, 2.0, 3.0]);
+            let offset = PointND::fill(1.0);
+
+            assert_eq!(p.try_convert_units_inv(&PointND::from([1.0, 0.0, 2.0]), &offset), None);
+            assert_eq!(p.try_convert_units_inv(&PointND::from([1.0, -0.0, 2.0]), &offset), None);
+            assert_eq!(
+                p.try_convert_units_inv(&PointND::from([1.0, 0.5, 2.0]), &offset),
+                Some(PointND::from([0.0, 2.0, 1.0]))
+            );
+
+            let p = PointND::<i32, 0>::from([]);
+            assert_eq!(p.try_convert_units_inv(&p, &p), Some(p));
+        }
 
-            #[test]
-            #[cfg(feature = "z")]
-            fn getters_for_3d_points_work() {
-                let arr = [0,1,2];
-                let p = PointND::from(arr);
+        #[test]
+        #[should_panic]
+        fn cannot_invert_zero_integer_scales() {
+            let p = PointND::from([1, 2]);
+            p.convert_units_inv(&PointND::from([1, 0]), &PointND::from([0, 0]));
+        }
 
-                assert_eq!(*p.x(), arr[0]);
-                assert_eq!(*p.y(), arr[1]);
-                assert_eq!(*p.z(), arr[2]);
-            }
+    }
 
-            #[test]
-            #[cfg(feature = "w")]
-            fn getters_for_4d_points_work() {
-                let arr = [0,1,2,3];
-                let p = PointND::from(arr);
+    #[cfg(test)]
+    mod aggregates {
+        use super::*;
+        use std::vec::Vec;
 
-                assert_eq!(*p.x(), arr[0]);
-                assert_eq!(*p.y(), arr[1]);
-                assert_eq!(*p.z(), arr[2]);
-                assert_eq!(*p.w(), arr[3]);
-            }
+        #[test]
+        fn can_get_centroid() {
+            let points = [[-2.0, 4.0, 1.0], [6.0, -8.0, 1.0], [-1.0, 1.0, -5.0]].map(PointND::from);
+            let centroid = PointND::centroid(points).unwrap();
+            assert_eq!(centroid.into_arr(), [1.0, -1.0, -1.0]);
 
+            let single = PointND::centroid([PointND::from([-3.5, 2.0])]).unwrap();
+            assert_eq!(single.into_arr(), [-3.5, 2.0]);
         }
 
-        #[cfg(test)]
-        #[cfg(any(feature = "x", feature = "y", feature = "z", feature = "w"))]
-        mod set {
-            use super::*;
-
-            #[test]
-            #[cfg(feature = "x")]
-            fn setter_for_1d_points_work() {
+        #[test]
+        fn can_get_centroid_of_more_points_than_items_can_count() {
+            let points = std::vec![PointND::<u8, 1>::from([0]); 300];
+            assert_eq!(PointND::centroid(points).unwrap().into_arr(), [0]);
+        }
 
-                let old_vals = [0];
-                let new_val = 4;
-                let mut p = PointND::from(old_vals);
+        #[test]
+        fn can_get_bounding_box_across_quadrants() {
+            // One point in each quadrant, so no single point holds both extremes
+            let points = Vec::from([
+                PointND::from([2, 7]),
+                PointND::from([-5, 3]),
+                PointND::from([-1, -6]),
+                PointND::from([4, -2]),
+            ]);
+            let (min, max) = PointND::bounding_box(points).unwrap();
+            assert_eq!(min.into_arr(), [-5, -6]);
+            assert_eq!(max.into_arr(), [4, 7]);
+        }
 
-                p.set_x(new_val);
-                assert_eq!(*p.x(), new_val);
-            }
+        #[test]
+        fn single_points_give_degenerate_boxes() {
+            let (min, max) = PointND::bounding_box([PointND::from([-1.5, -2.5])]).unwrap();
+            assert_eq!(min, max);
+            assert_eq!(min.into_arr(), [-1.5, -2.5]);
+        }
 
-            #[test]
-            #[cfg(feature = "y")]
-            fn setters_for_2d_points_work() {
+        #[test]
+        fn no_points_give_nothing() {
+            assert_eq!(PointND::<f64, 2>::centroid([]), None);
+            assert_eq!(PointND::<i32, 3>::bounding_box(Vec::new()), None);
+        }
 
-                let old_vals = [0,1];
-                let new_vals = [4,5];
-                let mut p = PointND::from(old_vals);
+    }
 
-                p.set_x(new_vals[0]);
-                p.set_y(new_vals[1]);
+    #[cfg(test)]
+    mod casting {
+        use super::*;
+        use core::num::TryFromIntError;
 
-                assert_eq!(*p.x(), new_vals[0]);
-                assert_eq!(*p.y(), new_vals[1]);
-            }
+        #[test]
+        fn can_widen_items() {
+            let p = PointND::from([i8::MIN, 0, i8::MAX]);
+            let p: PointND<i64, 3> = p.cast();
+            assert_eq!(p.into_arr(), [-128, 0, 127]);
 
-            #[test]
-            #[cfg(feature = "z")]
-            fn setters_for_3d_points_work() {
+            let p: PointND<f64, 2> = PointND::from([u32::MAX, 1]).cast();
+            assert_eq!(p.into_arr(), [4294967295.0, 1.0]);
+        }
 
-                let old_vals = [0,1,2];
-                let new_vals = [4,5,6];
-                let mut p = PointND::from(old_vals);
+        #[test]
+        fn can_narrow_items_in_range() {
+            let p: Result<PointND<u8, 3>, TryFromIntError> = PointND::from([0i64, 128, 255]).try_cast();
+            assert_eq!(p.unwrap().into_arr(), [0, 128, 255]);
+        }
 
-                p.set_x(new_vals[0]);
-                p.set_y(new_vals[1]);
-                p.set_z(new_vals[2]);
+        #[test]
+        fn cannot_narrow_items_out_of_range() {
+            let p: Result<PointND<u8, 3>, _> = PointND::from([0i64, 256, 1]).try_cast();
+            assert!(p.is_err());
+            let p: Result<PointND<u8, 3>, _> = PointND::from([-1i64, 0, 1]).try_cast();
+            assert!(p.is_err());
+        }
 
-                assert_eq!(*p.x(), new_vals[0]);
-                assert_eq!(*p.y(), new_vals[1]);
-                assert_eq!(*p.z(), new_vals[2]);
+        #[test]
+        fn try_cast_stops_at_first_failure() {
+            use core::sync::atomic::{AtomicUsize, Ordering};
+
+            static CONVERTED: AtomicUsize = AtomicUsize::new(0);
+
+            struct Counted(i32);
+            impl TryFrom<i32> for Counted {
+                type Error = i32;
+                fn try_from(value: i32) -> Result<Self, i32> {
+                    CONVERTED.fetch_add(1, Ordering::Relaxed);
+                    if value < 0 { Err(value) } else { Ok(Counted(value)) }
+                }
             }
 
-            #[test]
-            #[cfg(feature = "w")]
-            fn setters_for_4d_points_work() {
-
-                let old_vals = [0,1,2,3];
-                let new_vals = [4,5,6,7];
-                let mut p = PointND::from(old_vals);
-
-                p.set_x(new_vals[0]);
-                p.set_y(new_vals[1]);
-                p.set_z(new_vals[2]);
-                p.set_w(new_vals[3]);
-
-                assert_eq!(*p.x(), new_vals[0]);
-                assert_eq!(*p.y(), new_vals[1]);
-                assert_eq!(*p.z(), new_vals[2]);
-                assert_eq!(*p.w(), new_vals[3]);
-            }
+            let p: Result<PointND<Counted, 5>, i32> = PointND::from([1, 2, -3, -4, 5]).try_cast();
+            assert_eq!(p.err(), Some(-3));
+            assert_eq!(CONVERTED.load(Ordering::Relaxed), 3);
 
+            let p: Result<PointND<Counted, 2>, i32> = PointND::from([1, 2]).try_cast();
+            assert_eq!(p.unwrap().into_arr().map(|c| c.0), [1, 2]);
         }
 
-        #[cfg(test)]
-        #[cfg(any(feature = "x", feature = "y", feature = "z", feature = "w"))]
-        mod shift {
-            use super::*;
+        #[test]
+        #[cfg(feature = "num-traits")]
+        fn can_cast_lossily() {
+            let p: PointND<i16, 5> = PointND::from([2.7f64, -2.7, f64::NAN, 1e10, -1e10]).as_cast();
+            assert_eq!(p.into_arr(), [2, -2, 0, i16::MAX, i16::MIN]);
 
-            #[test]
-            #[cfg(feature = "x")]
-            fn can_shift_1d_points() {
-                let mut p = PointND::from([0.1]);
-                p.shift_x(1.23);
+            let p: PointND<u8, 2> = PointND::from([256i32, -1]).as_cast();
+            assert_eq!(p.into_arr(), [0, 255]);
+        }
 
-                assert_eq!(p.into_arr(), [1.33]);
-            }
+    }
 
-            #[test]
-            #[cfg(feature = "y")]
-            fn can_shift_2d_points() {
-                let mut p = PointND::from([12, 345]);
-                p.shift_x(-22);
-                p.shift_y(-345);
+    #[cfg(test)]
+    mod zipping {
+        use super::*;
+        use std::string::String;
 
-                assert_eq!(p.into_arr(), [-10, 0]);
-            }
+        #[test]
+        fn can_zip() {
+            let p = PointND::from([1, 2, 3]).zip(PointND::from(['a', 'b', 'c']));
+            assert_eq!(p.into_arr(), [(1, 'a'), (2, 'b'), (3, 'c')]);
+        }
 
-            #[test]
-            #[cfg(feature = "z")]
-            fn can_shift_3d_points() {
-                let mut p = PointND::from([42.4, 2.85, 75.01]);
-                p.shift_x(40.6);
-                p.shift_y(-2.85);
-                p.shift_z(24.99);
+        #[test]
+        fn can_unzip() {
+            let (a, b) = PointND::from([(1, 'a'), (2, 'b')]).unzip();
+            assert_eq!(a.into_arr(), [1, 2]);
+            assert_eq!(b.into_arr(), ['a', 'b']);
+        }
 
-                assert_eq!(p.into_arr(), [83.0, 0.0, 100.0]);
-            }
+        #[test]
+        fn can_round_trip_non_clone_items() {
+            struct NoClone(u8);
 
-            #[test]
-            #[cfg(feature = "w")]
-            fn can_shift_4d_points() {
-                let mut p = PointND::from([0,1,2,3]);
-                p.shift_x(10);
-                p.shift_y(-2);
-                p.shift_z(5);
-                p.shift_w(0);
+            let a = PointND::from([String::from("x"), String::from("y")]);
+            let b = PointND::from([NoClone(1), NoClone(2)]);
 
-                assert_eq!(p.into_arr(), [10, -1, 7, 3]);
-            }
+            let (a, b) = a.zip(b).unzip();
+            assert_eq!(a.into_arr(), ["x", "y"]);
+            assert_eq!(b.into_arr().map(|n| n.0), [1, 2]);
+        }
 
+        #[test]
+        fn can_zip_zero_dimensional_points() {
+            let p = PointND::<i32, 0>::from([]).zip(PointND::<u8, 0>::from([]));
+            let (a, b) = p.unzip();
+            assert_eq!((a.dims(), b.dims()), (0, 0));
         }
 
     }
 
     #[cfg(test)]
-    mod from_and_into {
+    mod arrays_of_points {
         use super::*;
+        use std::string::String;
+
+        fn quad() -> [PointND<i32, 2>; 4] {
+            PointND::from_nested([[0, 0], [2, 0], [2, 3], [0, 3]])
+        }
 
         #[test]
-        fn from_array_works() {
-            let p = PointND::from([0,1,2]);
-            assert_eq!(p.dims(), 3);
+        fn can_round_trip_nested() {
+            let nested = PointND::into_nested(quad());
+            assert_eq!(nested, [[0, 0], [2, 0], [2, 3], [0, 3]]);
+            assert_eq!(PointND::from_nested(nested), quad());
+        }
 
-            let p: PointND<i32, 4> = [22; 4].into();
-            assert_eq!(p.into_arr(), [22; 4]);
+        #[test]
+        fn can_round_trip_flat() {
+            let flat: PointND<_, 8> = PointND::flatten_array(quad());
+            assert_eq!(*flat, [0, 0, 2, 0, 2, 3, 0, 3]);
+
+            let points: [PointND<_, 2>; 4] = PointND::unflatten_array(flat);
+            assert_eq!(points, quad());
         }
 
         #[test]
-        fn into_array_works() {
-            let arr: [i32; 3] = PointND::fill(10).into();
-            assert_eq!(arr, [10, 10, 10]);
+        fn can_flatten_non_copy_items() {
+            let points = PointND::from_nested([[String::from("a")], [String::from("b")]]);
+            let flat: PointND<_, 2> = PointND::flatten_array(points);
+            assert_eq!(flat.into_arr(), ["a", "b"]);
+        }
+
+        #[test]
+        fn can_flatten_empty_arrays() {
+            let flat: PointND<i32, 0> = PointND::<i32, 3>::flatten_array([]);
+            assert_eq!(flat.dims(), 0);
+
+            let points: [PointND<i32, 0>; 5] = PointND::unflatten_array(PointND::from([]));
+            assert_eq!(points.len(), 5);
+        }
+
+        #[test]
+        #[should_panic(expected = "flatten 4 PointND's of 2 dimensions into a PointND of 7 dimensions")]
+        fn cannot_flatten_into_wrong_dimensions() {
+            let _flat: PointND<_, 7> = PointND::flatten_array(quad());
+        }
+
+        #[test]
+        #[should_panic(expected = "unflatten a PointND of 8 dimensions into 3 PointND's of 2 dimensions")]
+        fn cannot_unflatten_into_wrong_dimensions() {
+            let flat: PointND<_, 8> = PointND::flatten_array(quad());
+            let _points: [PointND<_, 2>; 3] = PointND::unflatten_array(flat);
         }
 
     }
@@ -1388,8 +7295,10 @@ mod tests {
     #[cfg(test)]
     mod try_from_and_try_into {
         use super::*;
+        use std::string::String;
 
         #[test]
+        #[allow(clippy::unnecessary_fallible_conversions)]
         fn can_try_from_array() {
             let arr = [0,1,2,3,4,5];
             let p: Result<PointND<_, 6>, _> = arr.try_into();
@@ -1410,6 +7319,115 @@ mod tests {
             assert!(p.is_err());
         }
 
+        #[test]
+        fn can_try_from_mut_slice_of_same_len() {
+            let mut buf = [String::from("a"), String::from("b")];
+            let p: PointND<_, 2> = (&mut buf[..]).try_into().unwrap();
+            assert_eq!(p.into_arr(), ["a", "b"]);
+            assert_eq!(buf, ["a", "b"]);
+        }
+
+        #[test]
+        fn cannot_try_from_mut_slice_of_different_length() {
+            let mut buf = [0, 1, 2];
+            let p: Result<PointND<_, 2>, _> = (&mut buf[..]).try_into();
+            assert!(p.is_err());
+
+            let p: Result<PointND<_, 4>, _> = PointND::try_from(&mut buf[..]);
+            assert!(p.is_err());
+        }
+
+    }
+
+    #[cfg(test)]
+    mod fallible {
+        use super::*;
+
+        #[test]
+        fn try_methods_return_index_out_of_bounds() {
+            let oob = |index, dims| Error::IndexOutOfBounds { index, dims };
+
+            assert_eq!(PointND::<u8, 3>::try_unit_axis(3), Err(oob(3, 3)));
+            assert_eq!(PointND::from([1, 2]).try_with_dim(5, 0), Err(oob(5, 2)));
+            assert_eq!(PointND::from([1, 2, 3]).try_view::<3>(1).map(|v| v.into_arr()), Err(oob(3, 3)));
+            assert_eq!(PointND::from([1, 2]).try_swap_dims(2, 0), Err(oob(2, 2)));
+        }
+
+        #[test]
+        fn try_methods_succeed_in_bounds() {
+            assert_eq!(PointND::<u8, 3>::try_unit_axis(0), Ok(PointND::from([1, 0, 0])));
+            assert_eq!(PointND::from([1, 2]).try_with_dim(1, 0), Ok(PointND::from([1, 0])));
+            assert_eq!(PointND::from([1, 2, 3]).try_view::<0>(3).map(|v| v.dims()), Ok(0));
+
+            let mut p = PointND::from([1, 2]);
+            assert_eq!(p.try_swap_dims(1, 0), Ok(()));
+            assert_eq!(p.into_arr(), [2, 1]);
+        }
+
+        #[test]
+        #[cfg(feature = "appliers")]
+        fn can_try_apply_dims() {
+            let p = PointND::from([1, 2, 3]);
+            assert_eq!(p.try_apply_dims(&[1], |item| -item), Ok(PointND::from([1, -2, 3])));
+            assert_eq!(p.try_apply_dims(&[1, 3], |item| -item), Err(Error::IndexOutOfBounds { index: 3, dims: 3 }));
+        }
+
+        #[test]
+        #[cfg(feature = "var-dims")]
+        fn can_propagate_specific_errors() {
+            fn shrink(items: &[i32]) -> Result<PointND<i32, 2>, Error> {
+                let p: PointND<i32, 3> = PointND::try_from_slice(items)?;
+                Ok(p.try_contract()?)
+            }
+            fn grow(p: PointND<i32, 2>) -> Result<PointND<i32, 3>, Error> {
+                Ok(p.try_contract()?)
+            }
+
+            assert_eq!(shrink(&[1, 2, 3]), Ok(PointND::from([1, 2])));
+            assert_eq!(shrink(&[1, 2]), Err(Error::DimensionMismatch { expected: 3, found: 2 }));
+            assert_eq!(grow(PointND::from([1, 2])), Err(Error::TooManyDimensions { dims: 2, requested: 3 }));
+        }
+
+    }
+
+    #[cfg(test)]
+    mod const_fns {
+        use super::*;
+
+        const ORIGIN: PointND<i32, 3> = PointND::new([0, 0, 0]);
+        const CORNERS: [PointND<u8, 2>; 4] = [
+            PointND::new([0, 0]),
+            PointND::new([0, 1]),
+            PointND::new([1, 0]),
+            PointND::new([1, 1]),
+        ];
+        static DIMS: usize = ORIGIN.dims();
+        const ARR: [i32; 3] = ORIGIN.into_arr();
+        const ARR_REF: &[u8; 2] = CORNERS[3].arr();
+
+        #[test]
+        fn can_use_const_constructors_and_accessors() {
+            assert_eq!(ORIGIN, PointND::from([0, 0, 0]));
+            assert_eq!(CORNERS[2].into_arr(), [1, 0]);
+            assert_eq!(DIMS, 3);
+            assert_eq!(ARR, [0, 0, 0]);
+            assert_eq!(ARR_REF, &[1, 1]);
+        }
+
+        #[test]
+        #[cfg(all(feature = "x", feature = "y", feature = "z", feature = "w"))]
+        fn can_use_const_getters() {
+            const P1: PointND<i64, 1> = PointND::new([7]);
+            const P4: PointND<i64, 4> = PointND::new([1, 2, 3, 4]);
+            const X: i64 = *P1.x();
+            const W: i64 = *P4.w();
+            const SUM: i64 = *P4.x() + *P4.y() + *P4.z();
+            const Y: u8 = *CORNERS[1].y();
+            const Z: i32 = *ORIGIN.z();
+
+            assert_eq!((X, W, SUM, Y, Z), (7, 4, 6, 1, 0));
+        }
+
     }
 
 }
\ No newline at end of file
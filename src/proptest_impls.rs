@@ -0,0 +1,97 @@
+use proptest::arbitrary::Arbitrary;
+use proptest::strategy::{Map, Strategy};
+
+use crate::PointND;
+
+// Note to Developers:
+// - Points are generated by mapping proptest's array strategy, rather than regenerating the
+//   whole array, so that failing cases are shrunk one item at a time
+
+///
+/// Generates points with each item generated independently by the `Arbitrary` impl of `T`
+///
+/// When shrinking, each item is shrunk separately (_e.g._ - towards zero for numbers), so a
+/// failing case is minimised one dimension at a time
+///
+/// ```
+/// # use point_nd::PointND;
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     fn dot_with_self_is_non_negative(p in any::<PointND<i8, 6>>()) {
+///         let p = PointND::from(p.into_arr().map(i32::from));
+///         prop_assert!(p.dot(&p) >= 0);
+///     }
+/// }
+/// # dot_with_self_is_non_negative();
+/// ```
+///
+/// # Enabled by features:
+///
+/// - `proptest`
+///
+impl<T, const N: usize> Arbitrary for PointND<T, N>
+    where T: Arbitrary {
+
+    type Parameters = T::Parameters;
+    type Strategy = Map<<[T; N] as Arbitrary>::Strategy, fn([T; N]) -> Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        <[T; N]>::arbitrary_with(args).prop_map(PointND::from)
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use proptest::test_runner::{TestError, TestRunner};
+
+    #[test]
+    fn failing_points_are_shrunk_by_item() {
+        let mut runner = TestRunner::deterministic();
+        let result = runner.run(&any::<PointND<i32, 6>>(), |p| {
+            prop_assert!(p[3] <= 100);
+            Ok(())
+        });
+
+        match result {
+            Err(TestError::Fail(_, p)) => assert_eq!(p.into_arr(), [0, 0, 0, 101, 0, 0]),
+            other => panic!("expected a failing case, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn can_generate_zero_dimensional_points() {
+        let mut runner = TestRunner::deterministic();
+        let result = runner.run(&any::<PointND<u8, 0>>(), |p| {
+            prop_assert_eq!(p.dims(), 0);
+            Ok(())
+        });
+        assert!(result.is_ok());
+    }
+
+    #[cfg(feature = "var-dims")]
+    proptest! {
+
+        #[test]
+        fn extending_then_contracting_round_trips(p in any::<PointND<i64, 4>>(), values in any::<[i64; 3]>()) {
+            let extended: PointND<i64, 7> = p.extend(values);
+            prop_assert_eq!(&extended[4..], &values[..]);
+
+            let contracted: PointND<i64, 4> = extended.contract_by(3);
+            prop_assert_eq!(contracted, p);
+        }
+
+        #[test]
+        fn contracting_front_leaves_extended_values(p in any::<PointND<f32, 2>>(), values in any::<[f32; 5]>()) {
+            let extended: PointND<f32, 7> = p.extend(values);
+            let contracted: PointND<f32, 5> = extended.contract_front(2);
+            prop_assert_eq!(contracted.into_arr().map(f32::to_bits), values.map(f32::to_bits));
+        }
+
+    }
+
+}
@@ -0,0 +1,129 @@
+use rand::Rng;
+use rand::distributions::{Distribution, Standard};
+use rand::distributions::uniform::SampleUniform;
+
+use crate::PointND;
+
+///
+/// Samples each item of the point from `Standard`, allowing points to be created with `rng.gen()`
+///
+/// ```
+/// # use point_nd::PointND;
+/// use rand::{rngs::SmallRng, Rng, SeedableRng};
+///
+/// let mut rng = SmallRng::seed_from_u64(42);
+/// let p: PointND<f32, 3> = rng.gen();
+/// assert!(p.iter().all(|item| (0.0..1.0).contains(item)));
+/// ```
+///
+/// # Enabled by features:
+///
+/// - `rand`
+///
+impl<T, const N: usize> Distribution<PointND<T, N>> for Standard
+    where Standard: Distribution<T> {
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PointND<T, N> {
+        PointND::from_fn(|_| self.sample(rng))
+    }
+
+}
+
+///
+/// Method for randomly sampling `PointND`'s of any items
+///
+/// # Enabled by features:
+///
+/// - `rand`
+///
+impl<T, const N: usize> PointND<T, N>
+    where T: SampleUniform + PartialOrd + Clone {
+
+    ///
+    /// Returns a new point with each item sampled uniformly from the range `lo[i]..hi[i]`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// use rand::{rngs::SmallRng, SeedableRng};
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(42);
+    /// let lo = PointND::from([0, 10, -5]);
+    /// let hi = PointND::from([1, 20, 5]);
+    ///
+    /// let p = PointND::random_in_box(&mut rng, &lo, &hi);
+    /// assert_eq!(p[0], 0);
+    /// assert!((10..20).contains(&p[1]));
+    /// assert!((-5..5).contains(&p[2]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - If any item in `lo` is greater than or equal to the respective item in `hi`
+    ///
+    pub fn random_in_box(rng: &mut impl Rng, lo: &Self, hi: &Self) -> Self {
+        if let Some(i) = lo.iter().zip(hi.iter()).position(|(l, h)| l >= h) {
+            panic!("Attempted to call random_in_box() with an empty range at dimension {}", i);
+        }
+        PointND::from_fn(|i| rng.gen_range(lo[i].clone()..hi[i].clone()))
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn can_generate_points() {
+        let mut rng = StdRng::seed_from_u64(793);
+
+        let p: PointND<f64, 4> = rng.gen();
+        assert!(p.iter().all(|item| (0.0..1.0).contains(item)));
+
+        let p: PointND<bool, 64> = rng.gen();
+        assert!(p.contains(&true) && p.contains(&false));
+
+        let p: PointND<u8, 0> = rng.gen();
+        assert_eq!(p.dims(), 0);
+    }
+
+    #[test]
+    fn random_points_are_within_box() {
+        let mut rng = StdRng::seed_from_u64(793);
+        let lo = PointND::from([-1.0, 0.0, 100.0]);
+        let hi = PointND::from([1.0, 1e-6, 100.5]);
+
+        for _ in 0..1000 {
+            let p = PointND::random_in_box(&mut rng, &lo, &hi);
+            for i in 0..3 {
+                assert!(lo[i] <= p[i] && p[i] < hi[i], "{:?}", p);
+            }
+        }
+    }
+
+    #[test]
+    fn random_points_cover_box() {
+        let mut rng = StdRng::seed_from_u64(793);
+        let lo = PointND::from([0u8, 250]);
+        let hi = PointND::from([4u8, 252]);
+
+        let mut seen = [[false; 2]; 4];
+        for _ in 0..200 {
+            let p = PointND::random_in_box(&mut rng, &lo, &hi);
+            seen[p[0] as usize][(p[1] - 250) as usize] = true;
+        }
+        assert!(seen.iter().flatten().all(|s| *s));
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_sample_empty_box() {
+        let mut rng = StdRng::seed_from_u64(793);
+        let p = PointND::from([1, 2]);
+        PointND::random_in_box(&mut rng, &p, &PointND::from([2, 2]));
+    }
+
+}
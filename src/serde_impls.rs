@@ -0,0 +1,248 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{Deserialize, Deserializer, Error, IgnoredAny, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+
+use crate::{PointND, NonZeroPoint, FinitePoint};
+use crate::error::{NonFiniteError, ZeroPointError};
+use crate::utils::PartialArray;
+
+// Note to Developers:
+// - Points are (de)serialized as tuples, the same as serde does for arrays. Self describing
+//   formats such as JSON write these as plain sequences (e.g. [1.0, 2.0, 3.0])
+// - The impls are written by hand as serde only implements its traits for arrays of up to
+//   32 items
+
+///
+/// Writes the items of the point as a sequence of exactly `N` items
+///
+/// # Enabled by features:
+///
+/// - `serde`
+///
+impl<T, const N: usize> Serialize for PointND<T, N>
+    where T: Serialize {
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for item in self.iter() {
+            tuple.serialize_element(item)?;
+        }
+        tuple.end()
+    }
+
+}
+
+///
+/// Reads a point from a sequence of exactly `N` items
+///
+/// Sequences with fewer or more items give an error stating the number of items expected
+///
+/// # Enabled by features:
+///
+/// - `serde`
+///
+impl<'de, T, const N: usize> Deserialize<'de> for PointND<T, N>
+    where T: Deserialize<'de> {
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_tuple(N, PointVisitor(PhantomData))
+    }
+
+}
+
+struct PointVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T, const N: usize> Visitor<'de> for PointVisitor<T, N>
+    where T: Deserialize<'de> {
+
+    type Value = PointND<T, N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence of {} items for a PointND of {} dimensions", N, N)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut arr = PartialArray::<T, N>::new();
+        for found in 0..N {
+            match seq.next_element()? {
+                Some(item) => arr.push(item),
+                None => return Err(A::Error::invalid_length(found, &self)),
+            }
+        }
+
+        // Counts any extra items so the error states how many were found
+        let mut found = N;
+        while seq.next_element::<IgnoredAny>()?.is_some() {
+            found += 1;
+        }
+        if found != N {
+            return Err(A::Error::invalid_length(found, &self));
+        }
+
+        Ok(PointND::from(arr.into_inner("deserialize")))
+    }
+
+}
+
+///
+/// Writes the contained point, the same as a `PointND` is written
+///
+/// # Enabled by features:
+///
+/// - `serde`
+///
+impl<T, const N: usize> Serialize for NonZeroPoint<T, N>
+    where T: Serialize {
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+
+}
+
+///
+/// Reads a `PointND`, giving an error if all of its items are zero
+///
+/// # Enabled by features:
+///
+/// - `serde`
+///
+impl<'de, T, const N: usize> Deserialize<'de> for NonZeroPoint<T, N>
+    where T: Deserialize<'de>,
+          NonZeroPoint<T, N>: TryFrom<PointND<T, N>, Error = ZeroPointError> {
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let point = PointND::deserialize(deserializer)?;
+        NonZeroPoint::try_from(point).map_err(D::Error::custom)
+    }
+
+}
+
+///
+/// Writes the contained point, the same as a `PointND` is written
+///
+/// # Enabled by features:
+///
+/// - `serde`
+///
+impl<T, const N: usize> Serialize for FinitePoint<T, N>
+    where T: Serialize {
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+
+}
+
+///
+/// Reads a `PointND`, giving an error if any of its items are `NaN` or infinite
+///
+/// Note that JSON cannot represent `NaN` or infinite floats, but other formats can
+///
+/// # Enabled by features:
+///
+/// - `serde`
+///
+impl<'de, T, const N: usize> Deserialize<'de> for FinitePoint<T, N>
+    where T: Deserialize<'de>,
+          FinitePoint<T, N>: TryFrom<PointND<T, N>, Error = NonFiniteError> {
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let point = PointND::deserialize(deserializer)?;
+        FinitePoint::try_from(point).map_err(D::Error::custom)
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::{String, ToString};
+
+    #[test]
+    fn can_round_trip_json() {
+        let p = PointND::from([1.0, 2.5, -3.0]);
+        let json = serde_json::to_string(&p).unwrap();
+        assert_eq!(json, "[1.0,2.5,-3.0]");
+        assert_eq!(serde_json::from_str::<PointND<f64, 3>>(&json).unwrap(), p);
+
+        let p = PointND::<u8, 0>::from([]);
+        let json = serde_json::to_string(&p).unwrap();
+        assert_eq!(json, "[]");
+        assert_eq!(serde_json::from_str::<PointND<u8, 0>>(&json).unwrap(), p);
+    }
+
+    #[test]
+    fn can_round_trip_more_than_32_dims() {
+        let p = PointND::<i64, 40>::from_fn(|i| i as i64 * -3);
+        let json = serde_json::to_string(&p).unwrap();
+        assert_eq!(serde_json::from_str::<PointND<i64, 40>>(&json).unwrap(), p);
+
+        let p = PointND::<String, 40>::from_fn(|i| i.to_string());
+        let json = serde_json::to_string(&p).unwrap();
+        assert_eq!(serde_json::from_str::<PointND<String, 40>>(&json).unwrap(), p);
+    }
+
+    #[test]
+    fn cannot_deserialize_too_few_items() {
+        let err = serde_json::from_str::<PointND<f64, 3>>("[1.0, 2.0]").unwrap_err();
+        assert!(err.to_string().starts_with(
+            "invalid length 2, expected a sequence of 3 items for a PointND of 3 dimensions"
+        ), "{}", err);
+    }
+
+    #[test]
+    fn cannot_deserialize_too_many_items() {
+        let err = serde_json::from_str::<PointND<f64, 3>>("[1.0, 2.0, 3.0, 4.0, 5.0]").unwrap_err();
+        assert!(err.to_string().starts_with(
+            "invalid length 5, expected a sequence of 3 items for a PointND of 3 dimensions"
+        ), "{}", err);
+
+        let err = serde_json::from_str::<PointND<f64, 0>>("[1.0]").unwrap_err();
+        assert!(err.to_string().starts_with("invalid length 1"), "{}", err);
+    }
+
+    #[test]
+    fn cannot_deserialize_invalid_items() {
+        assert!(serde_json::from_str::<PointND<u8, 2>>("[1, -1]").is_err());
+        assert!(serde_json::from_str::<PointND<u8, 2>>("{\"x\": 1}").is_err());
+    }
+
+    #[test]
+    fn can_round_trip_guarded_points() {
+        let p = NonZeroPoint::try_from(PointND::from([0, -3, 0])).unwrap();
+        let json = serde_json::to_string(&p).unwrap();
+        assert_eq!(json, "[0,-3,0]");
+        assert_eq!(serde_json::from_str::<NonZeroPoint<i32, 3>>(&json).unwrap(), p);
+
+        let p = FinitePoint::try_from(PointND::from([1.5, -2.0])).unwrap();
+        let json = serde_json::to_string(&p).unwrap();
+        assert_eq!(json, "[1.5,-2.0]");
+        assert_eq!(serde_json::from_str::<FinitePoint<f64, 2>>(&json).unwrap(), p);
+    }
+
+    #[test]
+    fn cannot_deserialize_zero_non_zero_points() {
+        let err = serde_json::from_str::<NonZeroPoint<i32, 3>>("[0, 0, 0]").unwrap_err();
+        assert!(err.to_string().starts_with(&ZeroPointError.to_string()), "{}", err);
+
+        let err = serde_json::from_str::<NonZeroPoint<f32, 2>>("[-0.0, 0.0]").unwrap_err();
+        assert!(err.to_string().starts_with(&ZeroPointError.to_string()), "{}", err);
+
+        // Errors in the point itself are still reported
+        let err = serde_json::from_str::<NonZeroPoint<i32, 3>>("[1, 2]").unwrap_err();
+        assert!(err.to_string().starts_with("invalid length 2"), "{}", err);
+    }
+
+    #[test]
+    fn cannot_deserialize_non_finite_points() {
+        // JSON has no NaN or infinity, but values too large for an f32 parse as infinite
+        let err = serde_json::from_str::<FinitePoint<f32, 3>>("[1.0, 2.0, 1e300]").unwrap_err();
+        assert!(err.to_string().starts_with(&NonFiniteError { index: 2 }.to_string()), "{}", err);
+    }
+
+}
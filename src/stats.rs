@@ -0,0 +1,187 @@
+use core::ops::Add;
+
+use crate::PointND;
+
+/**
+Accumulates componentwise statistics over a stream of `PointND`'s in a single pass.
+
+Each call to `push()` updates the running minimum, maximum and sum of the points seen so far,
+so statistics over any number of points can be computed without collecting them.
+
+```
+# use point_nd::{PointND, PointStats};
+let mut stats = PointStats::<f64, 2>::new();
+stats.push(&PointND::from([1.0, 8.0]));
+stats.push(&PointND::from([3.0, -2.0]));
+
+assert_eq!(stats.count(), 2);
+assert_eq!(**stats.min().unwrap(), [1.0, -2.0]);
+assert_eq!(**stats.max().unwrap(), [3.0, 8.0]);
+assert_eq!(stats.mean().unwrap().into_arr(), [2.0, 3.0]);
+```
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PointStats<T, const N: usize> {
+    min: Option<PointND<T, N>>,
+    max: Option<PointND<T, N>>,
+    sum: PointND<T, N>,
+    count: usize,
+}
+
+impl<T, const N: usize> PointStats<T, N>
+    where T: Default {
+
+    /// Returns a new `PointStats` which has seen no points
+    pub fn new() -> Self {
+        PointStats {
+            min: None,
+            max: None,
+            sum: PointND::zero(),
+            count: 0,
+        }
+    }
+
+}
+
+impl<T, const N: usize> Default for PointStats<T, N>
+    where T: Default {
+
+    fn default() -> Self {
+        PointStats::new()
+    }
+
+}
+
+impl<T, const N: usize> PointStats<T, N>
+    where T: PartialOrd + Add<Output = T> + Copy {
+
+    ///
+    /// Updates the statistics with the values of `point`
+    ///
+    /// As with `component_min()` and `component_max()` in `PointND`, items which cannot be
+    /// compared (_e.g._ - `NaN`) are only kept if they were pushed first
+    ///
+    pub fn push(&mut self, point: &PointND<T, N>) {
+        self.min = Some(match self.min.take() {
+            Some(min) => min.component_min(*point),
+            None => *point,
+        });
+        self.max = Some(match self.max.take() {
+            Some(max) => max.component_max(*point),
+            None => *point,
+        });
+        for (total, item) in self.sum.iter_mut().zip(point.iter()) {
+            *total = *total + *item;
+        }
+        self.count += 1;
+    }
+
+}
+
+impl<T, const N: usize> PointStats<T, N> {
+
+    /// Returns the componentwise minimum of the pushed points, or `None` if none were pushed
+    pub fn min(&self) -> Option<&PointND<T, N>> {
+        self.min.as_ref()
+    }
+
+    /// Returns the componentwise maximum of the pushed points, or `None` if none were pushed
+    pub fn max(&self) -> Option<&PointND<T, N>> {
+        self.max.as_ref()
+    }
+
+    /// Returns the componentwise sum of the pushed points, being zero if none were pushed
+    pub fn sum(&self) -> &PointND<T, N> {
+        &self.sum
+    }
+
+    /// Returns the number of points pushed
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+}
+
+// Implements the mean for statistics of floating point numbers
+macro_rules! impl_float_stats {
+    ($($t:ty),*) => {$(
+
+        impl<const N: usize> PointStats<$t, N> {
+
+            ///
+            /// Returns the componentwise mean (_a.k.a_ - centroid) of the pushed points, or
+            /// `None` if none were pushed
+            ///
+            pub fn mean(&self) -> Option<PointND<$t, N>> {
+                if self.count == 0 {
+                    return None;
+                }
+                let count = self.count as $t;
+                Some(PointND::from_fn(|i| self.sum[i] / count))
+            }
+
+        }
+
+    )*};
+}
+
+impl_float_stats!(f32, f64);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POINTS: [[f64; 3]; 5] = [
+        [1.0, -4.0, 0.5],
+        [3.0, 2.0, 0.5],
+        [-2.0, 6.0, 1.5],
+        [0.0, 0.0, -2.5],
+        [8.0, 1.0, 5.0],
+    ];
+
+    #[test]
+    fn empty_stats_have_nothing() {
+        let stats = PointStats::<f32, 2>::new();
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.min(), None);
+        assert_eq!(stats.max(), None);
+        assert_eq!(stats.mean(), None);
+        assert_eq!(**stats.sum(), [0.0; 2]);
+        assert_eq!(stats, PointStats::default());
+    }
+
+    #[test]
+    fn streamed_stats_match_batch_results() {
+        let mut stats = PointStats::new();
+        for point in POINTS {
+            stats.push(&PointND::from(point));
+        }
+
+        let points = POINTS.map(PointND::from);
+        let min = points.iter().cloned().reduce(PointND::component_min).unwrap();
+        let max = points.iter().cloned().reduce(PointND::component_max).unwrap();
+        let centroid = PointND::<f64, 3>::from_fn(|i| {
+            points.iter().map(|p| p[i]).sum::<f64>() / points.len() as f64
+        });
+
+        assert_eq!(stats.count(), 5);
+        assert_eq!(stats.min(), Some(&min));
+        assert_eq!(stats.max(), Some(&max));
+        assert_eq!(stats.mean(), Some(centroid));
+        assert_eq!(**stats.min().unwrap(), [-2.0, -4.0, -2.5]);
+        assert_eq!(**stats.max().unwrap(), [8.0, 6.0, 5.0]);
+    }
+
+    #[test]
+    fn can_stream_integers() {
+        let mut stats = PointStats::new();
+        stats.push(&PointND::from([3, 9]));
+        stats.push(&PointND::from([-1, 4]));
+
+        assert_eq!(**stats.sum(), [2, 13]);
+        assert_eq!(**stats.min().unwrap(), [-1, 4]);
+        assert_eq!(**stats.max().unwrap(), [3, 9]);
+    }
+
+}
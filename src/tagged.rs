@@ -0,0 +1,233 @@
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Deref, DerefMut};
+
+use crate::PointND;
+#[cfg(feature = "appliers")]
+use crate::utils::{ApplyFn, ApplyPointFn};
+
+/**
+A `PointND` tagged with the coordinate space `S` it belongs to.
+
+The tag is a zero sized marker type which only exists at compile time. Points tagged with
+different spaces cannot be mixed together without an explicit call to `cast_space()`, which
+prevents mistakes like translating a world space point by a screen space offset.
+
+As `TaggedPoint` dereferences to a `PointND`, all of its read and write accessors are available.
+
+```
+# use point_nd::{PointND, TaggedPoint};
+struct WorldSpace;
+struct ScreenSpace;
+
+let pos: TaggedPoint<f32, 2, WorldSpace> = TaggedPoint::from([1.0, 2.0]);
+let offset = TaggedPoint::<f32, 2, WorldSpace>::from([0.5, 0.5]);
+
+// Points in the same space can be used together
+let moved = pos.translate(&offset);
+assert_eq!(*moved.x(), 1.5);
+
+// Explicitly converting to another space
+let on_screen: TaggedPoint<f32, 2, ScreenSpace> = moved.cast_space();
+```
+
+Points in different spaces cannot:
+
+```compile_fail
+# use point_nd::{PointND, TaggedPoint};
+struct WorldSpace;
+struct ScreenSpace;
+
+let pos: TaggedPoint<f32, 2, WorldSpace> = TaggedPoint::from([1.0, 2.0]);
+let offset: TaggedPoint<f32, 2, ScreenSpace> = TaggedPoint::from([0.5, 0.5]);
+
+// ERROR: Expected a point in WorldSpace, found one in ScreenSpace
+let moved = pos.translate(&offset);
+```
+ */
+pub struct TaggedPoint<T, const N: usize, S> {
+    point: PointND<T, N>,
+    // A function pointer is used so that the auto traits and
+    //  variance of the tagged point don't depend on the tag
+    space: PhantomData<fn() -> S>,
+}
+
+impl<T, const N: usize, S> TaggedPoint<T, N, S> {
+
+    /// Returns a new `TaggedPoint` in the space `S`
+    pub fn new(point: PointND<T, N>) -> Self {
+        TaggedPoint { point, space: PhantomData }
+    }
+
+    /// Consumes `self`, returning the untagged point
+    pub fn into_point(self) -> PointND<T, N> {
+        self.point
+    }
+
+    ///
+    /// Consumes `self`, returning the same point tagged with another space
+    ///
+    /// This performs no transformation of the values contained by the point
+    ///
+    pub fn cast_space<S2>(self) -> TaggedPoint<T, N, S2> {
+        TaggedPoint::new(self.point)
+    }
+
+    ///
+    /// Consumes `self` and returns a new point in the same space with `delta` added to each item
+    ///
+    /// The `delta` must be in the same space as `self`
+    ///
+    pub fn translate(self, delta: &TaggedPoint<T, N, S>) -> Self
+        where T: Add<Output = T> + Copy {
+
+        let mut arr = self.point.into_arr();
+        for (item, d) in arr.iter_mut().zip(delta.iter()) {
+            *item = *item + *d;
+        }
+        TaggedPoint::from(arr)
+    }
+
+    ///
+    /// Equivalent to `apply()` in `PointND`, returning a point in the same space
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `default`
+    ///
+    /// - `appliers`
+    ///
+    #[cfg(feature = "appliers")]
+    pub fn apply<U>(self, modifier: ApplyFn<T, U>) -> TaggedPoint<U, N, S> {
+        TaggedPoint::new(self.point.apply(modifier))
+    }
+
+    ///
+    /// Equivalent to `apply_point()` in `PointND`, except that `other` must be in the same
+    /// space as `self`
+    ///
+    /// # Enabled by features:
+    ///
+    /// - `default`
+    ///
+    /// - `appliers`
+    ///
+    #[cfg(feature = "appliers")]
+    pub fn apply_point<U, V>(
+        self,
+        other: TaggedPoint<V, N, S>,
+        modifier: ApplyPointFn<T, U, V>
+    ) -> TaggedPoint<U, N, S> {
+        TaggedPoint::new(self.point.apply_point(other.point, modifier))
+    }
+
+}
+
+impl<T, const N: usize, S> Deref for TaggedPoint<T, N, S> {
+
+    type Target = PointND<T, N>;
+    fn deref(&self) -> &Self::Target {
+        &self.point
+    }
+
+}
+
+impl<T, const N: usize, S> DerefMut for TaggedPoint<T, N, S> {
+
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.point
+    }
+
+}
+
+impl<T, const N: usize, S> From<PointND<T, N>> for TaggedPoint<T, N, S> {
+
+    fn from(point: PointND<T, N>) -> Self {
+        TaggedPoint::new(point)
+    }
+
+}
+
+impl<T, const N: usize, S> From<[T; N]> for TaggedPoint<T, N, S> {
+
+    fn from(array: [T; N]) -> Self {
+        TaggedPoint::new(PointND::from(array))
+    }
+
+}
+
+// Implemented manually as deriving would require the tag to implement these traits too
+impl<T: Clone, const N: usize, S> Clone for TaggedPoint<T, N, S> {
+
+    fn clone(&self) -> Self {
+        TaggedPoint::new(self.point.clone())
+    }
+
+}
+
+impl<T: Copy, const N: usize, S> Copy for TaggedPoint<T, N, S> {}
+
+impl<T: PartialEq, const N: usize, S> PartialEq for TaggedPoint<T, N, S> {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.point == other.point
+    }
+
+}
+
+impl<T: Eq, const N: usize, S> Eq for TaggedPoint<T, N, S> {}
+
+impl<T: fmt::Debug, const N: usize, S> fmt::Debug for TaggedPoint<T, N, S> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TaggedPoint")
+            .field(&core::any::type_name::<S>())
+            .field(&self.point)
+            .finish()
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct World;
+    struct Screen;
+
+    #[test]
+    fn can_access_point_methods() {
+        let mut p: TaggedPoint<i32, 3, World> = TaggedPoint::from([0, 1, 2]);
+        assert_eq!(p.dims(), 3);
+        assert_eq!(p[2], 2);
+
+        p[0] = 10;
+        assert_eq!(p.into_point().into_arr(), [10, 1, 2]);
+    }
+
+    #[test]
+    fn can_translate_in_same_space() {
+        let p: TaggedPoint<i32, 2, World> = TaggedPoint::from([1, 2]);
+        let delta = TaggedPoint::from([10, -10]);
+        assert_eq!(p.translate(&delta), TaggedPoint::from([11, -8]));
+    }
+
+    #[test]
+    #[cfg(feature = "appliers")]
+    fn can_apply_in_same_space() {
+        let p1: TaggedPoint<i32, 2, Screen> = TaggedPoint::from([1, 2]);
+        let p2: TaggedPoint<i32, 2, Screen> = TaggedPoint::from([3, 4]);
+
+        let p = p1.apply_point(p2, |a, b| a * b).apply(|a| a as f32);
+        assert_eq!(p.into_point().into_arr(), [3.0, 8.0]);
+    }
+
+    #[test]
+    fn can_cast_space() {
+        let world: TaggedPoint<i32, 2, World> = TaggedPoint::from([5, 6]);
+        let screen: TaggedPoint<i32, 2, Screen> = world.cast_space();
+        assert_eq!(world.into_point(), screen.into_point());
+    }
+
+}
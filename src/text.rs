@@ -0,0 +1,383 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::PointND;
+use crate::error::ParsePointError;
+use crate::utils::PartialArray;
+
+// Note to Developers:
+// - Items are written with their Debug representation as, for floats, this is the
+//   shortest representation which parses back into exactly the same value
+
+///
+/// Writes the items of `point` to `f`, separated by `sep`
+///
+fn write_items<T, W, const N: usize>(point: &PointND<T, N>, f: &mut W, sep: &str) -> fmt::Result
+    where T: fmt::Debug, W: fmt::Write {
+
+    for (i, item) in point.iter().enumerate() {
+        if i > 0 {
+            f.write_str(sep)?;
+        }
+        write!(f, "{:?}", item)?;
+    }
+    Ok(())
+}
+
+///
+/// Parses exactly `N` items from `items`
+///
+pub(crate) fn parse_items<'a, T, I, const N: usize>(items: I) -> Result<[T; N], ParsePointError<T::Err>>
+    where T: FromStr, I: Iterator<Item = &'a str> {
+
+    let mut arr = PartialArray::<T, N>::new();
+    let mut found = 0;
+
+    for item in items {
+        if found < N {
+            match item.trim().parse() {
+                Ok(value) => arr.push(value),
+                Err(error) => return Err(ParsePointError::InvalidItem { index: found, error }),
+            }
+        }
+        found += 1;
+    }
+
+    if found != N {
+        return Err(ParsePointError::WrongDims { expected: N, found });
+    }
+    Ok(arr.into_inner("parse_items"))
+}
+
+impl<T, const N: usize> PointND<T, N> {
+
+    ///
+    /// Writes the items of `self` to `f` as comma separated values
+    ///
+    /// Items are written using their `Debug` representation. For `f32` and `f64` items this is
+    /// the shortest representation which parses back into exactly the same value, meaning that
+    /// no precision is lost when parsing the output with `parse_csv()`
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::from([1.5, 2.0, -3.25]);
+    ///
+    /// let mut csv = String::new();
+    /// p.format_csv(&mut csv).unwrap();
+    /// assert_eq!(csv, "1.5,2.0,-3.25");
+    /// ```
+    ///
+    pub fn format_csv(&self, f: &mut impl fmt::Write) -> fmt::Result
+        where T: fmt::Debug {
+        write_items(self, f, ",")
+    }
+
+    ///
+    /// Returns a new `PointND` parsed from comma separated values, such as those written
+    /// by `format_csv()`
+    ///
+    /// Whitespace surrounding each value is ignored. An empty string is parsed as a zero
+    /// dimensional point
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let p = PointND::<f64, 3>::parse_csv("1.5, 2.0, -3.25e-4").unwrap();
+    /// assert_eq!(p.into_arr(), [1.5, 2.0, -3.25e-4]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - `ParsePointError::WrongDims` if the string does not contain exactly `N` values
+    ///
+    /// - `ParsePointError::InvalidItem` if a value could not be parsed
+    ///
+    pub fn parse_csv(s: &str) -> Result<Self, ParsePointError<T::Err>>
+        where T: FromStr {
+
+        // Splitting an empty string would otherwise give a single empty value
+        let arr = if s.trim().is_empty() {
+            parse_items(core::iter::empty())?
+        } else {
+            parse_items(s.split(','))?
+        };
+        Ok(PointND::from(arr))
+    }
+
+}
+
+///
+/// Method for writing 2D `PointND`'s in the Well-Known Text format
+///
+impl<T> PointND<T, 2>
+    where T: fmt::Debug {
+
+    ///
+    /// Writes `self` to `f` in the Well-Known Text (WKT) format
+    ///
+    /// Items are written using their `Debug` representation (see `format_csv()`)
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let mut wkt = String::new();
+    /// PointND::from([1.5, -2.0]).format_wkt(&mut wkt).unwrap();
+    /// assert_eq!(wkt, "POINT (1.5 -2.0)");
+    /// ```
+    ///
+    pub fn format_wkt(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        f.write_str("POINT (")?;
+        write_items(self, f, " ")?;
+        f.write_str(")")
+    }
+
+}
+
+///
+/// Method for writing 3D `PointND`'s in the Well-Known Text format
+///
+impl<T> PointND<T, 3>
+    where T: fmt::Debug {
+
+    ///
+    /// Writes `self` to `f` in the Well-Known Text (WKT) format
+    ///
+    /// Items are written using their `Debug` representation (see `format_csv()`). The `Z`
+    /// dimension marker is omitted, as is done by many WKT writers
+    ///
+    /// ```
+    /// # use point_nd::PointND;
+    /// let mut wkt = String::new();
+    /// PointND::from([1.5, 2.0, 3.25]).format_wkt(&mut wkt).unwrap();
+    /// assert_eq!(wkt, "POINT (1.5 2.0 3.25)");
+    /// ```
+    ///
+    pub fn format_wkt(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        f.write_str("POINT (")?;
+        write_items(self, f, " ")?;
+        f.write_str(")")
+    }
+
+}
+
+///
+/// Writes the items of the point in parentheses, separated by commas (_e.g._ - `(1, 2, 3)`)
+///
+/// Formatting flags such as width and precision are applied to each item. Zero dimensional
+/// points are written as `()` and one dimensional points without a trailing comma, as `(5)`
+///
+/// ```
+/// # use point_nd::PointND;
+/// let p = PointND::from([1.0, 2.5, -3.125]);
+/// assert_eq!(format!("{}", p), "(1, 2.5, -3.125)");
+/// assert_eq!(format!("{:.2}", p), "(1.00, 2.50, -3.12)");
+/// ```
+///
+impl<T, const N: usize> fmt::Display for PointND<T, N>
+    where T: fmt::Display {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(")?;
+        for (i, item) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            item.fmt(f)?;
+        }
+        f.write_str(")")
+    }
+
+}
+
+///
+/// Parses a point from values separated by commas or whitespace, optionally surrounded by
+/// parentheses or square brackets, such as `"1,2,3"`, `"(1, 2, 3)"` or `"[1 2 3]"`
+///
+/// This accepts the output of the `Display` impl. Whitespace around values is ignored and a
+/// single trailing comma is allowed (_e.g._ - `"(5,)"`). Empty strings, `"()"` and `"[]"` are
+/// parsed as zero dimensional points
+///
+/// ```
+/// # use point_nd::PointND;
+/// let p: PointND<i32, 3> = "(1, -2, 3)".parse().unwrap();
+/// assert_eq!(p.into_arr(), [1, -2, 3]);
+///
+/// let p: PointND<f64, 2> = "[0.5 1e3]".parse().unwrap();
+/// assert_eq!(p.into_arr(), [0.5, 1000.0]);
+/// ```
+///
+/// # Errors
+///
+/// - `ParsePointError::WrongDims` if the string does not contain exactly `N` values
+///
+/// - `ParsePointError::InvalidItem` if a value could not be parsed
+///
+impl<T, const N: usize> FromStr for PointND<T, N>
+    where T: FromStr {
+
+    type Err = ParsePointError<T::Err>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+
+        let s = s.trim();
+        let s = match (s.chars().next(), s.chars().last()) {
+            (Some('('), Some(')')) | (Some('['), Some(']')) if s.len() >= 2 => &s[1..s.len() - 1],
+            _ => s,
+        };
+
+        let arr = if s.contains(',') {
+            let s = s.trim_end();
+            parse_items(s.strip_suffix(',').unwrap_or(s).split(','))?
+        } else {
+            parse_items(s.split_whitespace())?
+        };
+        Ok(PointND::from(arr))
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::format;
+    use std::string::String;
+
+    fn csv<T: fmt::Debug, const N: usize>(p: &PointND<T, N>) -> String {
+        let mut s = String::new();
+        p.format_csv(&mut s).unwrap();
+        s
+    }
+
+    #[test]
+    fn can_format_csv() {
+        assert_eq!(csv(&PointND::from([1, -2, 3])), "1,-2,3");
+        assert_eq!(csv(&PointND::from([0.1])), "0.1");
+        assert_eq!(csv(&PointND::<i32, 0>::from([])), "");
+    }
+
+    #[test]
+    fn can_round_trip_csv_floats() {
+        let p = PointND::from([0.1 + 0.2, -1.0 / 3.0, 1e-300, -2.5e18, f64::MAX, f64::MIN_POSITIVE]);
+        let parsed = PointND::<f64, 6>::parse_csv(&csv(&p)).unwrap();
+        assert_eq!(parsed, p);
+
+        let p = PointND::from([core::f32::consts::PI, -7.0e-12, 16777217.0]);
+        let parsed = PointND::<f32, 3>::parse_csv(&csv(&p)).unwrap();
+        assert_eq!(parsed, p);
+    }
+
+    #[test]
+    fn can_parse_csv_with_exponents_and_whitespace() {
+        let p = PointND::<f64, 3>::parse_csv(" -1E3 ,2.5e-2,\t+4 ").unwrap();
+        assert_eq!(p.into_arr(), [-1000.0, 0.025, 4.0]);
+    }
+
+    #[test]
+    fn can_parse_empty_csv() {
+        let p = PointND::<i32, 0>::parse_csv("").unwrap();
+        assert_eq!(p.dims(), 0);
+    }
+
+    #[test]
+    fn cannot_parse_csv_with_wrong_dims() {
+        let err = PointND::<i32, 3>::parse_csv("1,2").unwrap_err();
+        assert_eq!(err, ParsePointError::WrongDims { expected: 3, found: 2 });
+
+        let err = PointND::<i32, 3>::parse_csv("1,2,3,4,5").unwrap_err();
+        assert_eq!(err, ParsePointError::WrongDims { expected: 3, found: 5 });
+    }
+
+    #[test]
+    fn cannot_parse_csv_with_invalid_items() {
+        let err = PointND::<i32, 3>::parse_csv("1,two,3").unwrap_err();
+        assert!(matches!(err, ParsePointError::InvalidItem { index: 1, .. }));
+    }
+
+    #[test]
+    fn can_display_points() {
+        assert_eq!(format!("{}", PointND::from([1, 2, 3])), "(1, 2, 3)");
+        assert_eq!(format!("{}", PointND::from([-5])), "(-5)");
+        assert_eq!(format!("{}", PointND::<f64, 0>::from([])), "()");
+        assert_eq!(format!("{}", PointND::from(["a", "b"])), "(a, b)");
+    }
+
+    #[test]
+    fn display_flags_apply_to_each_item() {
+        let p = PointND::from([1.0, -0.5, 2.0 / 3.0]);
+        assert_eq!(format!("{:.2}", p), "(1.00, -0.50, 0.67)");
+        assert_eq!(format!("{:+.1}", p), "(+1.0, -0.5, +0.7)");
+
+        let p = PointND::from([7, -12]);
+        assert_eq!(format!("{:>4}", p), "(   7,  -12)");
+        assert_eq!(format!("{:03}", PointND::from([5])), "(005)");
+        assert_eq!(format!("{:.3}", PointND::<f32, 0>::from([])), "()");
+    }
+
+    #[test]
+    fn can_parse_from_str() {
+        let expected = [1, 2, 3];
+        for s in ["1,2,3", " 1 , 2 , 3 ", "(1, 2, 3)", "[1,2,3]", "[1 2 3]", "( 1\t2\n3 )", "1 2 3"] {
+            let p: PointND<i32, 3> = s.parse().unwrap();
+            assert_eq!(p.into_arr(), expected, "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn display_round_trips_from_str() {
+        let p = PointND::from([0.1 + 0.2, -1.0 / 3.0, 1e-300, f64::MAX]);
+        assert_eq!(format!("{}", p).parse::<PointND<f64, 4>>().unwrap(), p);
+
+        let p = PointND::from([-7i64]);
+        assert_eq!(format!("{}", p).parse::<PointND<i64, 1>>().unwrap(), p);
+
+        let p = PointND::<u8, 0>::from([]);
+        assert_eq!(format!("{}", p).parse::<PointND<u8, 0>>().unwrap(), p);
+    }
+
+    #[test]
+    fn can_parse_trailing_separators() {
+        assert_eq!("(5,)".parse::<PointND<i32, 1>>().unwrap().into_arr(), [5]);
+        assert_eq!("1, 2, ".parse::<PointND<i32, 2>>().unwrap().into_arr(), [1, 2]);
+
+        // Only one trailing comma is allowed
+        let err = "1,2,,".parse::<PointND<i32, 2>>().unwrap_err();
+        assert_eq!(err, ParsePointError::WrongDims { expected: 2, found: 3 });
+
+        let err = "1,,2".parse::<PointND<i32, 3>>().unwrap_err();
+        assert!(matches!(err, ParsePointError::InvalidItem { index: 1, .. }));
+    }
+
+    #[test]
+    fn can_parse_zero_dimensional_strs() {
+        for s in ["()", "[]", "", "  ( ) "] {
+            let p: PointND<f32, 0> = s.parse().unwrap();
+            assert_eq!(p.dims(), 0);
+        }
+    }
+
+    #[test]
+    fn cannot_parse_invalid_strs() {
+        let err = "(1, 2)".parse::<PointND<i32, 3>>().unwrap_err();
+        assert_eq!(err, ParsePointError::WrongDims { expected: 3, found: 2 });
+
+        let err = "[1 2 3 4]".parse::<PointND<i32, 3>>().unwrap_err();
+        assert_eq!(err, ParsePointError::WrongDims { expected: 3, found: 4 });
+
+        let err = "(1, x, 3)".parse::<PointND<i32, 3>>().unwrap_err();
+        assert!(matches!(err, ParsePointError::InvalidItem { index: 1, .. }));
+
+        // Mismatched brackets are left as part of the values
+        let err = "(1, 2]".parse::<PointND<i32, 2>>().unwrap_err();
+        assert!(matches!(err, ParsePointError::InvalidItem { index: 0, .. }));
+    }
+
+    #[test]
+    fn can_format_wkt() {
+        let mut s = String::new();
+        PointND::from([-1.0, 2.5e-8]).format_wkt(&mut s).unwrap();
+        assert_eq!(s, "POINT (-1.0 2.5e-8)");
+
+        let mut s = String::new();
+        PointND::from([1, 2, 3]).format_wkt(&mut s).unwrap();
+        assert_eq!(s, "POINT (1 2 3)");
+    }
+
+}
@@ -1,28 +1,139 @@
+use core::mem::MaybeUninit;
+use core::ptr;
 
-#[cfg(any(feature = "appliers", feature = "var-dims"))]
-use arrayvec::ArrayVec;
+///
+/// Fixed capacity array builder used to assemble arrays of a length different to the
+/// point they are built from
+///
+/// Items are pushed one at a time into a `MaybeUninit<[T; N]>`. If the builder is dropped
+/// before being filled (_e.g._ - a modifier closure panicked halfway through an `apply()`),
+/// only the items which were already pushed are dropped, so nothing leaks and nothing is
+/// dropped twice.
+///
+/// Callers must ensure the builder is completely filled before calling `into_inner()`
+///
+pub(crate) struct PartialArray<T, const N: usize> {
+    arr: MaybeUninit<[T; N]>,
+    len: usize,
+}
+
+impl<T, const N: usize> PartialArray<T, N> {
+
+    pub(crate) fn new() -> Self {
+        PartialArray {
+            arr: MaybeUninit::uninit(),
+            len: 0,
+        }
+    }
+
+    /// Writes `item` to the first uninitialised slot of the array
+    ///
+    /// # Panics
+    ///
+    /// - If the array is already full
+    pub(crate) fn push(&mut self, item: T) {
+        if self.len >= N {
+            panic!("Attempted to push more than {} items into a PartialArray", N);
+        }
+
+        // SAFETY: self.len < N, so the write is in bounds and does not
+        //  overwrite (and so leak) an initialised item
+        unsafe {
+            self.arr.as_mut_ptr().cast::<T>().add(self.len).write(item);
+        }
+        self.len += 1;
+    }
+
+    ///
+    /// Consumes `self`, returning the contained array
+    ///
+    /// The `method_name` is used to give a meaningful panic message
+    ///
+    /// # Panics
+    ///
+    /// - If the array has not been completely filled
+    ///
+    pub(crate) fn into_inner(self, method_name: &str) -> [T; N] {
+        if self.len != N {
+            panic!(
+                "Couldn't convert PartialArray into array in {}() method. \
+                 This operation should never have panicked. Please contact \
+                 the maintainers of PointND if troubles persist",
+                method_name
+            );
+        }
+
+        // Stops the Drop impl from dropping the items we are moving out
+        let this = core::mem::ManuallyDrop::new(self);
+        // SAFETY: All N items have been initialised and, as `this` will never
+        //  be dropped, the array is only ever read (and so owned) once
+        unsafe { ptr::read(this.arr.as_ptr()) }
+    }
+
+}
+
+impl<T, const N: usize> Drop for PartialArray<T, N> {
+
+    fn drop(&mut self) {
+        // SAFETY: Only the first self.len items have been initialised
+        unsafe {
+            let init = ptr::slice_from_raw_parts_mut(self.arr.as_mut_ptr().cast::<T>(), self.len);
+            ptr::drop_in_place(init);
+        }
+    }
+
+}
 
 ///
-/// Forces an ArrayVec to return it's contained array
+/// Consumes `a` and `b`, returning an array of `f` applied to each of their respective items
 ///
-/// For use ONLY within the apply, extend and contract methods as their constant
-/// generics ensure that ArrayVec's are always filled with initialised values
+/// The `method_name` is used to give a meaningful panic message
 ///
-#[cfg(any(feature = "appliers", feature = "var-dims"))]
-pub(crate) fn arrvec_into_inner<T, const N: usize>(arrvec: ArrayVec<T, N>, method_name: &str) -> [T; N] {
-    match arrvec.into_inner() {
-        Ok(arr) => arr,
-        _ => panic!(
-            "Couldn't convert ArrayVec into array in {}() method. \
-             This operation should never have panicked. Please contact \
-             the maintainers of PointND if troubles persist",
-             method_name
-        )
+pub(crate) fn zip_with<T, const N: usize>(
+    a: [T; N],
+    b: [T; N],
+    f: fn(T, T) -> T,
+    method_name: &str
+) -> [T; N] {
+    let mut arr = PartialArray::<T, N>::new();
+    for (a, b) in a.into_iter().zip(b) {
+        arr.push(f(a, b));
     }
+    arr.into_inner(method_name)
 }
 
-#[cfg(any(feature = "appliers", feature = "var-dims"))]
-pub const ARRVEC_CAP: usize = u32::MAX as usize;
+///
+/// Whether the minimum and maximum bounds passed to `between()` and `which_outside()`
+/// in `PointND`'s are included in the range
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum BoundsMode {
+    /// Both bounds are included (`min <= item <= max`)
+    Closed,
+    /// Neither bound is included (`min < item < max`)
+    Open,
+    /// Only the minimum is included (`min <= item < max`), as is usual for tile grids
+    ClosedOpen,
+    /// Only the maximum is included (`min < item <= max`)
+    OpenClosed,
+}
+
+impl BoundsMode {
+
+    /// Returns `true` if `item` is within `min` and `max` under this mode
+    pub(crate) fn contains<T: PartialOrd>(self, item: &T, min: &T, max: &T) -> bool {
+        let above_min = match self {
+            BoundsMode::Closed | BoundsMode::ClosedOpen => item >= min,
+            BoundsMode::Open   | BoundsMode::OpenClosed => item > min,
+        };
+        let below_max = match self {
+            BoundsMode::Closed | BoundsMode::OpenClosed => item <= max,
+            BoundsMode::Open   | BoundsMode::ClosedOpen => item < max,
+        };
+        above_min && below_max
+    }
+
+}
 
 /// Function pointer type to pass to  `apply()` in `PointND`'s
 #[cfg(feature = "appliers")]
@@ -47,5 +158,3 @@ pub type ApplyValsFn<T, U, V>  = fn(T, V) -> U;
 ///
 #[cfg(feature = "appliers")]
 pub type ApplyPointFn<T, U, V> = ApplyValsFn<T, U, V>;
-
-
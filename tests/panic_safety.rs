@@ -0,0 +1,285 @@
+#![cfg(any(feature = "appliers", feature = "var-dims"))]
+
+use point_nd::PointND;
+use core::cell::Cell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+const DIMS: usize = 7;
+
+// Counts how many times each guard has been dropped and how many clones were made
+#[derive(Default)]
+struct Tracker {
+    drops: [Cell<usize>; DIMS * 2],
+    clones: Cell<usize>,
+}
+
+impl Tracker {
+
+    fn guards<const N: usize>(&self, first_id: usize) -> [Guard<'_>; N] {
+        core::array::from_fn(|i| Guard { id: first_id + i, tracker: self })
+    }
+
+    fn assert_dropped_once(&self, total: usize) {
+        for (id, cell) in self.drops.iter().enumerate().take(total) {
+            assert_eq!(cell.get(), 1, "Guard {} was dropped {} times", id, cell.get());
+        }
+        assert_eq!(self.clones.get(), 0, "Guards were cloned {} times", self.clones.get());
+    }
+
+}
+
+struct Guard<'a> {
+    id: usize,
+    tracker: &'a Tracker,
+}
+
+impl<'a> Clone for Guard<'a> {
+    fn clone(&self) -> Self {
+        self.tracker.clones.set(self.tracker.clones.get() + 1);
+        Guard { id: self.id, tracker: self.tracker }
+    }
+}
+
+impl<'a> Drop for Guard<'a> {
+    fn drop(&mut self) {
+        let cell = &self.tracker.drops[self.id];
+        cell.set(cell.get() + 1);
+    }
+}
+
+// Dimensions to panic at, being the first, middle and last
+const PANIC_AT: [usize; 3] = [0, DIMS / 2, DIMS - 1];
+
+fn run<F: FnOnce()>(f: F) {
+    let res = catch_unwind(AssertUnwindSafe(f));
+    assert!(res.is_err(), "Expected the closure to panic");
+}
+
+#[cfg(feature = "appliers")]
+mod appliers {
+    use super::*;
+
+    // As appliers only accept function pointers, the dimension to
+    //  panic at can't be captured and has to be written literally
+    macro_rules! panic_at {
+        ($at:expr) => {
+            |g: Guard| if g.id == $at { panic!("Panicked at {}", $at) } else { g }
+        };
+    }
+
+    fn apply_panicking_at(p: PointND<Guard, DIMS>, at: usize) {
+        let _ = match at {
+            0 => p.apply(panic_at!(0)),
+            3 => p.apply(panic_at!(3)),
+            6 => p.apply(panic_at!(6)),
+            _ => unreachable!(),
+        };
+    }
+
+    #[test]
+    fn apply_drops_every_item_once() {
+        for at in PANIC_AT {
+            let tracker = Tracker::default();
+            let p = PointND::from(tracker.guards::<DIMS>(0));
+
+            run(|| apply_panicking_at(p, at));
+            tracker.assert_dropped_once(DIMS);
+        }
+    }
+
+    #[test]
+    fn apply_dims_drops_every_item_once() {
+        for at in PANIC_AT {
+            let tracker = Tracker::default();
+            let p = PointND::from(tracker.guards::<DIMS>(0));
+
+            run(|| {
+                let _ = match at {
+                    0 => p.apply_dims(&[0, 2], panic_at!(0)),
+                    3 => p.apply_dims(&[0, 3], panic_at!(3)),
+                    6 => p.apply_dims(&[0, 6], panic_at!(6)),
+                    _ => unreachable!(),
+                };
+            });
+            tracker.assert_dropped_once(DIMS);
+        }
+    }
+
+    #[test]
+    fn apply_vals_drops_every_item_once() {
+        for at in PANIC_AT {
+            let tracker = Tracker::default();
+            let p = PointND::from(tracker.guards::<DIMS>(0));
+            let vals = tracker.guards::<DIMS>(DIMS);
+
+            run(|| {
+                let _ = match at {
+                    0 => p.apply_vals(vals, |a, b| if a.id == 0 { panic!() } else { drop(b); a }),
+                    3 => p.apply_vals(vals, |a, b| if a.id == 3 { panic!() } else { drop(b); a }),
+                    6 => p.apply_vals(vals, |a, b| if a.id == 6 { panic!() } else { drop(b); a }),
+                    _ => unreachable!(),
+                };
+            });
+            tracker.assert_dropped_once(DIMS * 2);
+        }
+    }
+
+    #[test]
+    fn apply_point_drops_every_item_once() {
+        for at in PANIC_AT {
+            let tracker = Tracker::default();
+            let p1 = PointND::from(tracker.guards::<DIMS>(0));
+            let p2 = PointND::from(tracker.guards::<DIMS>(DIMS));
+
+            run(|| {
+                let _ = match at {
+                    0 => p1.apply_point(p2, |a, b| if b.id == DIMS     { panic!() } else { drop(a); b }),
+                    3 => p1.apply_point(p2, |a, b| if b.id == DIMS + 3 { panic!() } else { drop(a); b }),
+                    6 => p1.apply_point(p2, |a, b| if b.id == DIMS + 6 { panic!() } else { drop(a); b }),
+                    _ => unreachable!(),
+                };
+            });
+            tracker.assert_dropped_once(DIMS * 2);
+        }
+    }
+
+    #[test]
+    fn appliers_drop_nothing_when_not_panicking() {
+        let tracker = Tracker::default();
+        let p = PointND::from(tracker.guards::<DIMS>(0))
+            .apply(|g| g)
+            .apply_dims(&[1, 2], |g| g);
+
+        assert!(tracker.drops.iter().all(|cell| cell.get() == 0));
+        drop(p);
+        tracker.assert_dropped_once(DIMS);
+    }
+
+    #[test]
+    #[cfg(feature = "var-dims")]
+    #[allow(deprecated)]
+    fn items_passed_through_extend_and_retain_drop_once() {
+        for at in PANIC_AT {
+            let tracker = Tracker::default();
+            let p: PointND<_, DIMS> = PointND
+                ::from(tracker.guards::<3>(0))
+                .extend(tracker.guards::<4>(3));
+
+            run(|| apply_panicking_at(p, at));
+            tracker.assert_dropped_once(DIMS);
+
+            let tracker = Tracker::default();
+            let p: PointND<_, DIMS> = PointND
+                ::from(tracker.guards::<{ DIMS * 2 }>(0))
+                .retain(DIMS);
+
+            run(|| apply_panicking_at(p, at));
+            tracker.assert_dropped_once(DIMS * 2);
+        }
+    }
+
+}
+
+#[cfg(feature = "var-dims")]
+mod var_dims {
+    use super::*;
+
+    #[test]
+    fn extend_drops_every_item_once_on_panic() {
+        let tracker = Tracker::default();
+        let p = PointND::from(tracker.guards::<3>(0));
+        let vals = tracker.guards::<4>(3);
+
+        // Panics as the dimensions of the returned point are incorrect
+        run(|| { let _: PointND<_, 100> = p.extend(vals); });
+        tracker.assert_dropped_once(DIMS);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn retain_drops_every_item_once_on_panic() {
+        let tracker = Tracker::default();
+        let p = PointND::from(tracker.guards::<DIMS>(0));
+
+        // Panics as a point cannot be contracted to more dimensions than it had
+        run(|| { let _: PointND<_, 100> = p.retain(100); });
+        tracker.assert_dropped_once(DIMS);
+    }
+
+    #[test]
+    fn resize_with_drops_every_item_once_on_panic() {
+        for at in PANIC_AT {
+            let tracker = Tracker::default();
+            let p = PointND::from(tracker.guards::<3>(0));
+            let mut pads = tracker.guards::<4>(3).map(Some);
+
+            // Pads are taken by index, panicking once the dimension is reached
+            run(|| {
+                let _: PointND<_, DIMS> = p.resize_with(|i| {
+                    if i == at.max(3) { panic!("Panicked at {}", i) }
+                    pads[i - 3].take().unwrap()
+                });
+            });
+            drop(pads);
+            tracker.assert_dropped_once(DIMS);
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn retain_drops_discarded_items_once() {
+        let tracker = Tracker::default();
+        let p: PointND<_, 3> = PointND::from(tracker.guards::<DIMS>(0)).retain(3);
+
+        let dropped: usize = tracker.drops.iter().map(Cell::get).sum();
+        assert_eq!(dropped, DIMS - 3);
+        drop(p);
+        tracker.assert_dropped_once(DIMS);
+    }
+
+    #[test]
+    fn extend_drops_nothing_early() {
+        let tracker = Tracker::default();
+        let p: PointND<_, DIMS> = PointND
+            ::from(tracker.guards::<3>(0))
+            .extend(tracker.guards::<4>(3));
+
+        assert!(tracker.drops.iter().all(|cell| cell.get() == 0));
+        drop(p);
+        tracker.assert_dropped_once(DIMS);
+    }
+
+    #[test]
+    fn contract_by_drops_discarded_items_once() {
+        let tracker = Tracker::default();
+        let p: PointND<_, 3> = PointND::from(tracker.guards::<DIMS>(0)).contract_by(4);
+
+        let drops: Vec<usize> = tracker.drops.iter().take(DIMS).map(Cell::get).collect();
+        assert_eq!(drops, [0, 0, 0, 1, 1, 1, 1]);
+        drop(p);
+        tracker.assert_dropped_once(DIMS);
+    }
+
+    #[test]
+    fn contract_front_drops_discarded_items_once() {
+        let tracker = Tracker::default();
+        let p: PointND<_, 3> = PointND::from(tracker.guards::<DIMS>(0)).contract_front(4);
+
+        assert_eq!(p[0].id, 4);
+        let drops: Vec<usize> = tracker.drops.iter().take(DIMS).map(Cell::get).collect();
+        assert_eq!(drops, [1, 1, 1, 1, 0, 0, 0]);
+        drop(p);
+        tracker.assert_dropped_once(DIMS);
+    }
+
+    #[test]
+    fn contract_drops_every_item_once_on_panic() {
+        let tracker = Tracker::default();
+        let p = PointND::from(tracker.guards::<DIMS>(0));
+
+        // Panics as a point cannot be contracted by more dimensions than it has
+        run(|| { let _: PointND<_, 0> = p.contract_by(DIMS + 1); });
+        tracker.assert_dropped_once(DIMS);
+    }
+
+}